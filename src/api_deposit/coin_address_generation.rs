@@ -1,57 +1,56 @@
 use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
 use reqwest::{Response, Url};
 
-use crate::request::RequestWithQuery;
-
 #[allow(unused_imports)]
 use super::{
-    super::constant::{URL_DEPOSITS_GENERATE_COIN_ADDRESS, URL_SERVER},
+    super::constant::URL_DEPOSITS_GENERATE_COIN_ADDRESS,
     super::response::{
         CoinAddressGen, CoinAddressGenFirstResponse, CoinAddressGenResponse,
         CoinAddressGenSecondaryResponse, ResponseError,
     },
 };
+use crate::client::UpbitClient;
 
 impl CoinAddressGen {
     pub async fn generate_deposit_address(
+        client: &UpbitClient,
         currency: &str,
         net_type: &str,
     ) -> Result<Self, ResponseError> {
-        let res = Self::request(currency, net_type).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+        let res = Self::request(client, currency, net_type).await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         serde_json::from_str(&res_serialized)
             .map(|x: CoinAddressGenResponse| -> CoinAddressGen { Self { response: x } })
             .map_err(crate::response::response_error_from_json)
     }
 
-    async fn request(currency: &str, net_type: &str) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_DEPOSITS_GENERATE_COIN_ADDRESS}"))
-            .map_err(crate::response::response_error_internal_url_parse_error)?;
-        let token_string = Self::set_token_with_query(url.as_str())?;
+    async fn request(
+        client: &UpbitClient,
+        currency: &str,
+        net_type: &str,
+    ) -> Result<Response, ResponseError> {
+        let mut url = Url::parse(&format!(
+            "{}{URL_DEPOSITS_GENERATE_COIN_ADDRESS}",
+            client.server_url
+        ))
+        .map_err(crate::response::response_error_internal_url_parse_error)?;
+        let token_string = client.set_token_with_query(url.as_str())?;
 
         url.query_pairs_mut()
             .append_pair("currency", currency)
             .append_pair("net_type", net_type);
 
-        reqwest::Client::new()
-            .post(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(CONTENT_TYPE, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        client
+            .send(
+                client
+                    .http
+                    .post(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(CONTENT_TYPE, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -63,16 +62,22 @@ mod tests {
 
     use crate::response::CoinAddressGen;
 
+    use crate::client::UpbitClient;
+
     #[tokio::test]
     async fn test_generate_deposit_address() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
-        let res = CoinAddressGen::request("ETH", "ETH").await.unwrap();
+        let res = CoinAddressGen::request(&client, "ETH", "ETH")
+            .await
+            .unwrap();
         let res_serialized = res.text().await.unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -93,20 +98,16 @@ mod tests {
         let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
 
         if !missing_keys.is_empty() {
-            println!("[test_generate_deposit_address] Missing keys: {missing_keys:?}");
-            assert!(false);
+            panic!("[test_generate_deposit_address] Missing keys: {missing_keys:?}");
         } else {
             println!("[test_generate_deposit_address] No keys are missing");
         }
 
         if !extra_keys.is_empty() {
-            println!("[test_generate_deposit_address] Extra keys: {extra_keys:?}");
-            assert!(false);
+            panic!("[test_generate_deposit_address] Extra keys: {extra_keys:?}");
         } else {
             println!("[test_generate_deposit_address] No extra keys found.");
         }
-
-        assert!(true);
     }
 
     fn compare_keys(
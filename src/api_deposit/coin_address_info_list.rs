@@ -1,27 +1,18 @@
 use reqwest::header::{ACCEPT, AUTHORIZATION};
 use reqwest::{Response, Url};
 
-use crate::request::Request;
-
 use super::{
-    super::constant::{URL_DEPOSITS_COIN_ADDRESSES, URL_SERVER},
+    super::constant::URL_DEPOSITS_COIN_ADDRESSES,
     super::response::{CoinAddressResponse, ResponseError},
 };
+use crate::client::UpbitClient;
 
 impl CoinAddressResponse {
-    pub async fn get_coin_address_info_list() -> Result<Vec<Self>, ResponseError> {
-        let res = Self::request_list().await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+    pub async fn get_coin_address_info_list(
+        client: &UpbitClient,
+    ) -> Result<Vec<Self>, ResponseError> {
+        let res = Self::request_list(client).await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         serde_json::from_str(&res_serialized)
             .map(|x: Vec<Self>| {
@@ -37,18 +28,23 @@ impl CoinAddressResponse {
             .map_err(crate::response::response_error_from_json)
     }
 
-    async fn request_list() -> Result<Response, ResponseError> {
-        let url = Url::parse(&format!("{URL_SERVER}{URL_DEPOSITS_COIN_ADDRESSES}"))
-            .map_err(crate::response::response_error_internal_url_parse_error)?;
-        let token_string = Self::set_token()?;
-
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+    async fn request_list(client: &UpbitClient) -> Result<Response, ResponseError> {
+        let url = Url::parse(&format!(
+            "{}{URL_DEPOSITS_COIN_ADDRESSES}",
+            client.server_url
+        ))
+        .map_err(crate::response::response_error_internal_url_parse_error)?;
+        let token_string = client.set_token()?;
+
+        client
+            .send(
+                client
+                    .http
+                    .get(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -60,12 +56,16 @@ mod tests {
 
     use crate::response::CoinAddressResponse;
 
+    use crate::client::UpbitClient;
+
     #[tokio::test]
     async fn test_get_deposit_list() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
-        let res = CoinAddressResponse::request_list().await.unwrap();
+        let res = CoinAddressResponse::request_list(&client).await.unwrap();
         let res_serialized = res
             .text()
             .await
@@ -73,7 +73,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -97,26 +97,22 @@ mod tests {
                     compare_keys(item, &expected_structure, &format!("item[{index}]."));
 
                 if !missing_keys.is_empty() {
-                    println!(
+                    panic!(
                         "[test_get_deposit_list] Missing keys in item[{index}]: {missing_keys:?}"
                     );
-                    assert!(false);
                 } else {
                     println!("[test_get_deposit_list] No keys are missing in item[{index}]");
                 }
 
                 if !extra_keys.is_empty() {
-                    println!("[test_get_deposit_list] Extra keys in item[{index}]: {extra_keys:?}");
-                    assert!(false);
+                    panic!("[test_get_deposit_list] Extra keys in item[{index}]: {extra_keys:?}");
                 } else {
                     println!("[test_get_deposit_list] No extra keys found in item[{index}]",);
                 }
             }
         } else {
-            assert!(false, "Expected an array of objects in the response");
+            panic!("Expected an array of objects in the response");
         }
-
-        assert!(true);
     }
 
     fn compare_keys(
@@ -128,12 +124,12 @@ mod tests {
         let mut extra_keys = Vec::new();
 
         if let Some(actual_map) = json.as_object() {
-            for (key, _) in expected {
+            for key in expected.keys() {
                 if !actual_map.contains_key(*key) {
                     missing_keys.push(format!("{path}{key}"));
                 }
             }
-            for (key, _) in actual_map {
+            for key in actual_map.keys() {
                 if !expected.contains_key(key.as_str()) {
                     extra_keys.push(format!("{path}{key}"));
                 }
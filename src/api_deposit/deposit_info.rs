@@ -1,128 +1,106 @@
 use reqwest::header::{ACCEPT, AUTHORIZATION};
 use reqwest::{Response, Url};
 
-use crate::request::RequestWithQuery;
 use crate::response::{TransactionInfo, TransactionInfoSource};
 
-use super::{
-    super::constant::{URL_DEPOSIT, URL_SERVER},
-    super::response::ResponseError,
-};
+use super::{super::constant::URL_DEPOSIT, super::response::ResponseError};
+use crate::client::UpbitClient;
 
 impl TransactionInfo {
     pub async fn get_deposit_info_by_currency(
+        client: &UpbitClient,
         currency: &str,
     ) -> Result<Self, ResponseError> {
-        let res = Self::request_deposit_by_currency(currency).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+        let res = Self::request_deposit_by_currency(client, currency).await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         Self::deserialize_order_status_response(&res_serialized)
     }
 
     pub async fn get_deposit_info_by_uuid(
+        client: &UpbitClient,
         uuid: &str,
     ) -> Result<Self, ResponseError> {
-        let res = Self::request_deposit_by_uuid(uuid).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+        let res = Self::request_deposit_by_uuid(client, uuid).await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         Self::deserialize_order_status_response(&res_serialized)
     }
 
     pub async fn get_deposit_info_by_txid(
+        client: &UpbitClient,
         txid: &str,
     ) -> Result<Self, ResponseError> {
-        let res = Self::request_deposit_by_txid(txid).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+        let res = Self::request_deposit_by_txid(client, txid).await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         Self::deserialize_order_status_response(&res_serialized)
     }
 
     async fn request_deposit_by_currency(
-        currency: &str
+        client: &UpbitClient,
+        currency: &str,
     ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_DEPOSIT}"))
+        let mut url = Url::parse(&format!("{}{URL_DEPOSIT}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
         url.query_pairs_mut().append_pair("currency", currency);
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
+        let token_string = client.set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        client
+            .send(
+                client
+                    .http
+                    .get(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     async fn request_deposit_by_uuid(
+        client: &UpbitClient,
         uuid: &str,
     ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_DEPOSIT}"))
+        let mut url = Url::parse(&format!("{}{URL_DEPOSIT}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
         url.query_pairs_mut().append_pair("uuid", uuid);
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
+        let token_string = client.set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        client
+            .send(
+                client
+                    .http
+                    .get(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
-
     }
 
     async fn request_deposit_by_txid(
+        client: &UpbitClient,
         txid: &str,
     ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_DEPOSIT}"))
+        let mut url = Url::parse(&format!("{}{URL_DEPOSIT}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
         url.query_pairs_mut().append_pair("txid", txid);
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
+        let token_string = client.set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        client
+            .send(
+                client
+                    .http
+                    .get(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     fn deserialize_order_status_response(res_serialized: &str) -> Result<Self, ResponseError> {
@@ -165,12 +143,16 @@ mod tests {
 
     use crate::response::TransactionInfo;
 
+    use crate::client::UpbitClient;
+
     #[tokio::test]
     async fn test_get_deposit_info() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
-        let res = TransactionInfo::request_deposit_by_currency("KRW-ETH")
+        let res = TransactionInfo::request_deposit_by_currency(&client, "KRW-ETH")
             .await
             .unwrap();
         let res_serialized = res
@@ -180,7 +162,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -208,20 +190,16 @@ mod tests {
         let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
 
         if !missing_keys.is_empty() {
-            println!("[test_get_deposit_info] Missing keys: {missing_keys:?}");
-            assert!(false);
+            panic!("[test_get_deposit_info] Missing keys: {missing_keys:?}");
         } else {
             println!("[test_get_deposit_info] No keys are missing");
         }
 
         if !extra_keys.is_empty() {
-            println!("[test_get_deposit_info] Extra keys: {extra_keys:?}");
-            assert!(false);
+            panic!("[test_get_deposit_info] Extra keys: {extra_keys:?}");
         } else {
             println!("[test_get_deposit_info] No extra keys found.");
         }
-
-        assert!(true);
     }
 
     fn compare_keys(
@@ -1,19 +1,21 @@
 use std::str::FromStr;
 
-use crate::request::RequestWithQuery;
 use reqwest::{
     header::{ACCEPT, AUTHORIZATION},
     Response, Url,
 };
 
 use super::{
-    super::constant::{OrderBy, URL_DEPOSITS, URL_SERVER},
+    super::constant::{OrderBy, URL_DEPOSITS},
     super::response::{ResponseError, TransactionInfo, TransactionInfoSource},
     DepositState,
 };
+use crate::client::UpbitClient;
 
 impl TransactionInfo {
+    #[allow(clippy::too_many_arguments)]
     pub async fn get_deposit_list(
+        client: &UpbitClient,
         currency: &str,
         state: DepositState,
         uuids: Option<&[&str]>,
@@ -22,19 +24,11 @@ impl TransactionInfo {
         page: u32,
         order_by: OrderBy,
     ) -> Result<Vec<Self>, ResponseError> {
-        let res = Self::request_deposit_list(currency, state, uuids, txids, limit, page, order_by)
-            .await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+        let res = Self::request_deposit_list(
+            client, currency, state, uuids, txids, limit, page, order_by,
+        )
+        .await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         serde_json::from_str(&res_serialized)
             .map(|x: Vec<TransactionInfoSource>| {
@@ -70,7 +64,9 @@ impl TransactionInfo {
             .map_err(crate::response::response_error_from_json)
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn request_deposit_list(
+        client: &UpbitClient,
         currency: &str,
         state: DepositState,
         uuids: Option<&[&str]>,
@@ -79,7 +75,7 @@ impl TransactionInfo {
         page: u32,
         order_by: OrderBy,
     ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_DEPOSITS}"))
+        let mut url = Url::parse(&format!("{}{URL_DEPOSITS}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
         url.query_pairs_mut()
@@ -110,15 +106,17 @@ impl TransactionInfo {
             url_modified.as_str().to_string()
         };
 
-        let token_string = Self::set_token_with_query(&url_modified)?;
+        let token_string = client.set_token_with_query(&url_modified)?;
 
-        reqwest::Client::new()
-            .get(url_modified)
-            .header(ACCEPT, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        client
+            .send(
+                client
+                    .http
+                    .get(url_modified)
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -133,12 +131,17 @@ mod tests {
     use crate::constant::OrderBy;
     use crate::response::TransactionInfo;
 
+    use crate::client::UpbitClient;
+
     #[tokio::test]
     async fn test_get_deposit_list() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
         let res = TransactionInfo::request_deposit_list(
+            &client,
             "KRW",
             DepositState::Accepted,
             None,
@@ -156,7 +159,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -187,26 +190,22 @@ mod tests {
                     compare_keys(item, &expected_structure, &format!("item[{index}]."));
 
                 if !missing_keys.is_empty() {
-                    println!(
+                    panic!(
                         "[test_get_deposit_list] Missing keys in item[{index}]: {missing_keys:?}"
                     );
-                    assert!(false);
                 } else {
                     println!("[test_get_deposit_list] No keys are missing in item[{index}]");
                 }
 
                 if !extra_keys.is_empty() {
-                    println!("[test_get_deposit_list] Extra keys in item[{index}]: {extra_keys:?}");
-                    assert!(false);
+                    panic!("[test_get_deposit_list] Extra keys in item[{index}]: {extra_keys:?}");
                 } else {
                     println!("[test_get_deposit_list] No extra keys found in item[{index}]");
                 }
             }
         } else {
-            assert!(false, "Expected an array of objects in the response");
+            panic!("Expected an array of objects in the response");
         }
-
-        assert!(true);
     }
 
     fn compare_keys(
@@ -218,12 +217,12 @@ mod tests {
         let mut extra_keys = Vec::new();
 
         if let Some(actual_map) = json.as_object() {
-            for (key, _) in expected {
+            for key in expected.keys() {
                 if !actual_map.contains_key(*key) {
                     missing_keys.push(format!("{path}{key}"));
                 }
             }
-            for (key, _) in actual_map {
+            for key in actual_map.keys() {
                 if !expected.contains_key(key.as_str()) {
                     extra_keys.push(format!("{path}{key}"));
                 }
@@ -1,31 +1,23 @@
+use crate::number::Number;
 use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
 use reqwest::{Response, Url};
 
 use crate::constant::TwoFactorType;
 
 use super::super::{
-    constant::{URL_DEPOSITS_KRW, URL_SERVER},
-    request::RequestWithQuery,
+    constant::URL_DEPOSITS_KRW,
     response::{ResponseError, TransactionInfo, TransactionInfoSource},
 };
+use crate::client::UpbitClient;
 
 impl TransactionInfo {
     pub async fn deposit_krw(
-        amount: f64,
+        client: &UpbitClient,
+        amount: Number,
         two_factor_type: TwoFactorType,
     ) -> Result<Self, ResponseError> {
-        let res = Self::request_deposit_krw(amount, two_factor_type).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+        let res = Self::request_deposit_krw(client, amount, two_factor_type).await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         serde_json::from_str(&res_serialized)
             .map(|x: TransactionInfoSource| Self {
@@ -58,26 +50,29 @@ impl TransactionInfo {
     }
 
     async fn request_deposit_krw(
-        amount: f64,
+        client: &UpbitClient,
+        amount: Number,
         two_factor_type: TwoFactorType,
     ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_DEPOSITS_KRW}"))
+        let mut url = Url::parse(&format!("{}{URL_DEPOSITS_KRW}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
         url.query_pairs_mut()
-            .append_pair("amount", &format!("{amount}"))
+            .append_pair("amount", &crate::number::to_wire(amount))
             .append_pair("two_factor_type", &two_factor_type.to_string());
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
-
-        reqwest::Client::new()
-            .post(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(CONTENT_TYPE, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        let token_string = client.set_token_with_query(url.as_str())?;
+
+        client
+            .send(
+                client
+                    .http
+                    .post(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(CONTENT_TYPE, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -89,14 +84,23 @@ mod tests {
 
     use crate::{constant::TwoFactorType, response::TransactionInfo};
 
+    use crate::client::UpbitClient;
+    use crate::number::Number;
+
     #[tokio::test]
     async fn test_deposit_krw() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = TransactionInfo::request_deposit_krw(10000.0, TwoFactorType::Naver)
-            .await
-            .unwrap();
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
+
+        let res = TransactionInfo::request_deposit_krw(
+            &client,
+            Number::from(10000),
+            TwoFactorType::Naver,
+        )
+        .await
+        .unwrap();
         let res_serialized = res
             .text()
             .await
@@ -104,7 +108,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -132,20 +136,16 @@ mod tests {
         let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
 
         if !missing_keys.is_empty() {
-            println!("[test_deposit_krw] Missing keys: {missing_keys:?}");
-            assert!(false);
+            panic!("[test_deposit_krw] Missing keys: {missing_keys:?}");
         } else {
             println!("[test_deposit_krw] No keys are missing");
         }
 
         if !extra_keys.is_empty() {
-            println!("[test_deposit_krw] Extra keys: {extra_keys:?}");
-            assert!(false);
+            panic!("[test_deposit_krw] Extra keys: {extra_keys:?}");
         } else {
             println!("[test_deposit_krw] No extra keys found.");
         }
-
-        assert!(true);
     }
 
     fn compare_keys(
@@ -1,13 +1,13 @@
+use crate::number::Number;
 use std::fmt::Display;
+use std::str::FromStr;
 
 use crate::{
-    constant::{OrderBy, TwoFactorType},
+    client::UpbitClient,
+    constant::{OrderBy, ParseEnumError, TwoFactorType},
     response::{CoinAddressGen, CoinAddressResponse, ResponseError, TransactionInfo},
 };
 
-#[cfg(feature = "sqlx-type")]
-use sqlx::Type;
-
 mod coin_address_generation;
 mod coin_address_info;
 mod coin_address_info_list;
@@ -16,7 +16,7 @@ mod deposit_info_list;
 mod deposit_krw;
 
 /// List of kind of Deposit state
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum DepositState {
     /// ???
     Done,
@@ -36,6 +36,8 @@ pub enum DepositState {
     Refunded,
     /// ???
     Failed,
+    /// value unknown to this version of the crate
+    Unknown(String),
 }
 
 impl Display for DepositState {
@@ -50,27 +52,47 @@ impl Display for DepositState {
             Self::Refunding => write!(f, "REFUNDING"),
             Self::Refunded => write!(f, "REFUNDED"),
             Self::Failed => write!(f, "FAILED"),
+            DepositState::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
 
-impl From<&str> for DepositState {
-    fn from(value: &str) -> Self {
-        match value {
-            "DONE" => Self::Done,
-            "PROCESSING" => Self::Processing,
-            "ACCEPTED" => Self::Accepted,
-            "CANCELLED" => Self::Canceled,
-            "REJECTED" => Self::Rejected,
-            "TRAVEL_RULE_SUSPECTED" => Self::TravelRuleSuspected,
-            "REFUNDING" => Self::Refunding,
-            "REFUNDED" => Self::Refunded,
-            "FAILED" => Self::Failed,
-            a @ _ => panic!("Unexpected value: {}", a),
+impl FromStr for DepositState {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "DONE" => Ok(Self::Done),
+            "PROCESSING" => Ok(Self::Processing),
+            "ACCEPTED" => Ok(Self::Accepted),
+            "CANCELLED" => Ok(Self::Canceled),
+            "REJECTED" => Ok(Self::Rejected),
+            "TRAVEL_RULE_SUSPECTED" => Ok(Self::TravelRuleSuspected),
+            "REFUNDING" => Ok(Self::Refunding),
+            "REFUNDED" => Ok(Self::Refunded),
+            "FAILED" => Ok(Self::Failed),
+            a => Err(ParseEnumError::new("DepositState", a)),
         }
     }
 }
 
+impl TryFrom<&str> for DepositState {
+    type Error = ParseEnumError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl DepositState {
+    /// Parse `value`, keeping values unknown to this version of the crate as [`DepositState::Unknown`]
+    pub fn parse_or_unknown(value: &str) -> Self {
+        value
+            .parse()
+            .unwrap_or_else(|_| Self::Unknown(value.to_owned()))
+    }
+}
+
 /// 입금 기록을 조회한다. (inquiry the records of deposits.)
 ///
 /// # Example
@@ -170,7 +192,9 @@ pub async fn get_deposit_info_list(
     page: u32,
     order_by: OrderBy,
 ) -> Result<Vec<TransactionInfo>, ResponseError> {
-    TransactionInfo::get_deposit_list(currency, state, uuids, txids, limit, page, order_by).await
+    UpbitClient::default()
+        .get_deposit_info_list(currency, state, uuids, txids, limit, page, order_by)
+        .await
 }
 
 /// 개별 입금 조회.
@@ -219,19 +243,17 @@ pub async fn get_deposit_info_list(
 pub async fn get_deposit_info_by_currency(
     currency: &str,
 ) -> Result<TransactionInfo, ResponseError> {
-    TransactionInfo::get_deposit_info_by_currency(currency).await
+    UpbitClient::default()
+        .get_deposit_info_by_currency(currency)
+        .await
 }
 
-pub async fn get_deposit_info_by_uuid(
-    uuid: &str,
-) -> Result<TransactionInfo, ResponseError> {
-    TransactionInfo::get_deposit_info_by_uuid(uuid).await
+pub async fn get_deposit_info_by_uuid(uuid: &str) -> Result<TransactionInfo, ResponseError> {
+    UpbitClient::default().get_deposit_info_by_uuid(uuid).await
 }
 
-pub async fn get_deposit_info_by_txid(
-    txid: &str,
-) -> Result<TransactionInfo, ResponseError> {
-    TransactionInfo::get_deposit_info_by_txid(txid).await
+pub async fn get_deposit_info_by_txid(txid: &str) -> Result<TransactionInfo, ResponseError> {
+    UpbitClient::default().get_deposit_info_by_txid(txid).await
 }
 
 /// 원화를 입금한다.
@@ -275,10 +297,12 @@ pub async fn get_deposit_info_by_txid(
 /// | fee | 입금 수수료 | NumberString |
 /// | transaction_type | 입금 유형 | String |
 pub async fn deposit_krw(
-    amount: f64,
+    amount: Number,
     two_factor_type: TwoFactorType,
 ) -> Result<TransactionInfo, ResponseError> {
-    TransactionInfo::deposit_krw(amount, two_factor_type).await
+    UpbitClient::default()
+        .deposit_krw(amount, two_factor_type)
+        .await
 }
 
 /// 개별 입금 주소 조회
@@ -310,7 +334,9 @@ pub async fn get_coin_address_info(
     currency: &str,
     net_type: &str,
 ) -> Result<CoinAddressResponse, ResponseError> {
-    CoinAddressResponse::get_coin_address_info(currency, net_type).await
+    UpbitClient::default()
+        .get_coin_address_info(currency, net_type)
+        .await
 }
 
 /// 전체 입금 주소 조회
@@ -339,7 +365,7 @@ pub async fn get_coin_address_info(
 /// | deposit_address | 입금 주소 | String |
 /// | secondary_address | 2차 입금 주소 | String |
 pub async fn get_coin_address_info_list() -> Result<Vec<CoinAddressResponse>, ResponseError> {
-    CoinAddressResponse::get_coin_address_info_list().await
+    UpbitClient::default().get_coin_address_info_list().await
 }
 
 /// # Currently not working
@@ -347,5 +373,97 @@ pub async fn generate_deposit_address(
     currency: &str,
     net_type: &str,
 ) -> Result<CoinAddressGen, ResponseError> {
-    CoinAddressGen::generate_deposit_address(currency, net_type).await
+    UpbitClient::default()
+        .generate_deposit_address(currency, net_type)
+        .await
+}
+
+impl UpbitClient {
+    /// 입금 기록을 조회한다. (inquiry the records of deposits.)
+    ///
+    /// See [`get_deposit_info_list`].
+    #[allow(clippy::too_many_arguments)]
+    pub async fn get_deposit_info_list(
+        &self,
+        currency: &str,
+        state: DepositState,
+        uuids: Option<&[&str]>,
+        txids: Option<&[&str]>,
+        limit: u32,
+        page: u32,
+        order_by: OrderBy,
+    ) -> Result<Vec<TransactionInfo>, ResponseError> {
+        TransactionInfo::get_deposit_list(
+            self, currency, state, uuids, txids, limit, page, order_by,
+        )
+        .await
+    }
+
+    /// 개별 입금 조회.
+    ///
+    /// See [`get_deposit_info_by_currency`].
+    pub async fn get_deposit_info_by_currency(
+        &self,
+        currency: &str,
+    ) -> Result<TransactionInfo, ResponseError> {
+        TransactionInfo::get_deposit_info_by_currency(self, currency).await
+    }
+
+    /// 개별 입금 조회 (Inquiry individual deposit by uuid)
+    pub async fn get_deposit_info_by_uuid(
+        &self,
+        uuid: &str,
+    ) -> Result<TransactionInfo, ResponseError> {
+        TransactionInfo::get_deposit_info_by_uuid(self, uuid).await
+    }
+
+    /// 개별 입금 조회 (Inquiry individual deposit by txid)
+    pub async fn get_deposit_info_by_txid(
+        &self,
+        txid: &str,
+    ) -> Result<TransactionInfo, ResponseError> {
+        TransactionInfo::get_deposit_info_by_txid(self, txid).await
+    }
+
+    /// 원화를 입금한다.
+    ///
+    /// See [`deposit_krw`].
+    pub async fn deposit_krw(
+        &self,
+        amount: Number,
+        two_factor_type: TwoFactorType,
+    ) -> Result<TransactionInfo, ResponseError> {
+        TransactionInfo::deposit_krw(self, amount, two_factor_type).await
+    }
+
+    /// 개별 입금 주소 조회
+    ///
+    /// See [`get_coin_address_info`].
+    pub async fn get_coin_address_info(
+        &self,
+        currency: &str,
+        net_type: &str,
+    ) -> Result<CoinAddressResponse, ResponseError> {
+        CoinAddressResponse::get_coin_address_info(self, currency, net_type).await
+    }
+
+    /// 전체 입금 주소 조회
+    ///
+    /// See [`get_coin_address_info_list`].
+    pub async fn get_coin_address_info_list(
+        &self,
+    ) -> Result<Vec<CoinAddressResponse>, ResponseError> {
+        CoinAddressResponse::get_coin_address_info_list(self).await
+    }
+
+    /// # Currently not working
+    ///
+    /// See [`generate_deposit_address`].
+    pub async fn generate_deposit_address(
+        &self,
+        currency: &str,
+        net_type: &str,
+    ) -> Result<CoinAddressGen, ResponseError> {
+        CoinAddressGen::generate_deposit_address(self, currency, net_type).await
+    }
 }
@@ -1,28 +1,17 @@
 use reqwest::header::{ACCEPT, AUTHORIZATION};
 use reqwest::Response;
 
-use crate::request::Request;
-
 use super::{
-    super::constant::{URL_ACCOUNTS, URL_SERVER},
+    super::constant::URL_ACCOUNTS,
     super::response::ResponseError,
     super::response::{AccountsInfo, AccountsInfoSource},
 };
+use crate::client::UpbitClient;
 
 impl AccountsInfo {
-    pub async fn get_account_info() -> Result<Vec<Self>, ResponseError> {
-        let res = Self::request().await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+    pub async fn get_account_info(client: &UpbitClient) -> Result<Vec<Self>, ResponseError> {
+        let res = Self::request(client).await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         serde_json::from_str(&res_serialized)
             .map(|i: Vec<AccountsInfoSource>| {
@@ -40,16 +29,18 @@ impl AccountsInfo {
             .map_err(crate::response::response_error_from_json)
     }
 
-    async fn request() -> Result<Response, ResponseError> {
-        let token_string = Self::set_token()?;
-
-        reqwest::Client::new()
-            .get(format!("{URL_SERVER}{URL_ACCOUNTS}"))
-            .header(ACCEPT, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+    async fn request(client: &UpbitClient) -> Result<Response, ResponseError> {
+        let token_string = client.set_token()?;
+
+        client
+            .send(
+                client
+                    .http
+                    .get(format!("{}{URL_ACCOUNTS}", client.server_url))
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -63,10 +54,12 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_account_info() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
-        let res = AccountsInfo::request().await.unwrap();
+        let res = AccountsInfo::request(&client).await.unwrap();
         let res_serialized = res
             .text()
             .await
@@ -74,7 +67,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -91,9 +84,9 @@ mod tests {
         .collect::<HashSet<&str>>();
 
         if let Value::Array(json) = json {
-            if json.len() == 0 {
+            if json.is_empty() {
                 println!("[get_account_info] The JSON is empty.");
-                assert!(true);
+                return;
             }
 
             if let Value::Object(map) = &json[0] {
@@ -102,15 +95,13 @@ mod tests {
                 let missing_keys = expected_keys.difference(&json_keys).collect::<HashSet<_>>();
 
                 if !unexpected_keys.is_empty() {
-                    println!("[get_account_info] Unexpected keys found: {unexpected_keys:?}");
-                    assert!(false);
+                    panic!("[get_account_info] Unexpected keys found: {unexpected_keys:?}");
                 } else {
                     println!("[get_account_info] No unexpected keys found.");
                 }
 
                 if !missing_keys.is_empty() {
-                    println!("[get_account_info] Missing keys: {missing_keys:?}");
-                    assert!(false);
+                    panic!("[get_account_info] Missing keys: {missing_keys:?}");
                 } else {
                     println!("[get_account_info] No keys are missing.");
                 }
@@ -118,7 +109,5 @@ mod tests {
                 println!("[get_account_info] The JSON is not an object.");
             }
         }
-
-        assert!(true);
     }
 }
@@ -1,35 +1,43 @@
 pub mod accounts;
 pub mod order;
 pub mod order_cancel;
+pub mod order_cancel_batch;
 pub mod order_chance;
+pub mod order_replace;
+pub mod order_request;
 pub mod order_status;
 pub mod order_status_list;
+pub mod order_validator;
 
+use crate::number::Number;
 use std::fmt::Display;
+use std::str::FromStr;
 
+pub use order_cancel_batch::CancelOpenOrdersRequest;
+pub use order_replace::{ReplaceOrderRequest, ReplaceVolume};
+pub use order_request::{OrderId, OrderRequest, OrderRequestError};
+pub use order_validator::{OrderValidator, OrderViolation};
 use serde::{Deserialize, Serialize};
 
-use crate::constant::OrderBy;
+use crate::client::UpbitClient;
+use crate::constant::{OrderBy, ParseEnumError};
+use crate::sqlx_type::impl_sqlx_type;
+use crate::tick_size::TickSize;
 
-use super::response::{AccountsInfo, OrderChance, OrderInfo, OrderStatus, ResponseError};
-
-#[cfg(feature = "sqlx-type")]
-use sqlx::Type;
+use super::response::{
+    AccountsInfo, OrderCancelResult, OrderChance, OrderInfo, OrderReplacement, OrderStatus,
+    ResponseError,
+};
 
 /// Side of order
-#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
-
-#[cfg_attr(
-    feature = "sqlx-type",
-    derive(sqlx::Type),
-    sqlx(type_name = "order_side"),
-    sqlx(rename_all = "snake_case")
-)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub enum OrderSide {
     /// 매수
     Bid,
     /// 매도
     Ask,
+    /// value unknown to this version of the crate
+    Unknown(String),
 }
 
 impl Display for OrderSide {
@@ -37,28 +45,44 @@ impl Display for OrderSide {
         match self {
             OrderSide::Bid => write!(f, "bid"),
             OrderSide::Ask => write!(f, "ask"),
+            OrderSide::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
 
-impl From<&str> for OrderSide {
-    fn from(value: &str) -> Self {
-        match value {
-            "bid" => OrderSide::Bid,
-            "ask" => OrderSide::Ask,
-            a @ _ => panic!("Unexpected value: {}", a),
+impl FromStr for OrderSide {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bid" => Ok(Self::Bid),
+            "ask" => Ok(Self::Ask),
+            a => Err(ParseEnumError::new("OrderSide", a)),
         }
     }
 }
 
+impl TryFrom<&str> for OrderSide {
+    type Error = ParseEnumError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl OrderSide {
+    /// Parse `value`, keeping values unknown to this version of the crate as [`OrderSide::Unknown`]
+    pub fn parse_or_unknown(value: &str) -> Self {
+        value
+            .parse()
+            .unwrap_or_else(|_| Self::Unknown(value.to_owned()))
+    }
+}
+
+impl_sqlx_type!(OrderSide, "order_side");
+
 /// Type of order
-#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
-#[cfg_attr(feature = "sqlx-type", derive(sqlx::Type))]
-#[cfg_attr(
-    feature = "sqlx-type",
-    sqlx(type_name = "order_type"),
-    sqlx(rename_all = "snake_case")
-)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub enum OrderType {
     /// 지정가 주문
     Limit,
@@ -68,6 +92,8 @@ pub enum OrderType {
     Market,
     /// 최유리 주문
     Best,
+    /// value unknown to this version of the crate
+    Unknown(String),
 }
 
 impl Display for OrderType {
@@ -77,73 +103,172 @@ impl Display for OrderType {
             OrderType::Price => write!(f, "price"),
             OrderType::Market => write!(f, "market"),
             OrderType::Best => write!(f, "best"),
+            OrderType::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
 
-impl From<&str> for OrderType {
-    fn from(value: &str) -> Self {
-        match value {
-            "limit" => OrderType::Limit,
-            "price" => OrderType::Price,
-            "market" => OrderType::Market,
-            "best" => OrderType::Best,
-            a @ _ => panic!("Unexpected value: {}", a),
+impl FromStr for OrderType {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "limit" => Ok(Self::Limit),
+            "price" => Ok(Self::Price),
+            "market" => Ok(Self::Market),
+            "best" => Ok(Self::Best),
+            a => Err(ParseEnumError::new("OrderType", a)),
         }
     }
 }
 
+impl TryFrom<&str> for OrderType {
+    type Error = ParseEnumError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl OrderType {
+    /// Parse `value`, keeping values unknown to this version of the crate as [`OrderType::Unknown`]
+    pub fn parse_or_unknown(value: &str) -> Self {
+        value
+            .parse()
+            .unwrap_or_else(|_| Self::Unknown(value.to_owned()))
+    }
+}
+
+impl_sqlx_type!(OrderType, "order_type");
+
 /// New Order type
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Copy, Clone)]
-#[cfg_attr(feature = "sqlx-type", derive(sqlx::Type))]
-#[cfg_attr(
-    feature = "sqlx-type",
-    sqlx(type_name = "order_condition"),
-    sqlx(rename_all = "snake_case")
-)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 pub enum OrderCondition {
     /// Immediate or Cancel
     IOK,
     /// Fill or Kill
     FOK,
+    /// 메이커 주문으로만 체결. limit orders only
+    PostOnly,
+    /// value unknown to this version of the crate
+    Unknown(String),
 }
 
 impl Display for OrderCondition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            OrderCondition::IOK => write!(f, "iok"),
+            OrderCondition::IOK => write!(f, "ioc"),
             OrderCondition::FOK => write!(f, "fok"),
+            OrderCondition::PostOnly => write!(f, "post_only"),
+            OrderCondition::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
 
-impl From<&str> for OrderCondition {
-    fn from(value: &str) -> Self {
-        match value {
-            "iok" => OrderCondition::IOK,
-            "fok" => OrderCondition::FOK,
-            a @ _ => panic!("Unexpected value: {}", a),
+impl FromStr for OrderCondition {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            // "iok" was written by earlier versions of the crate
+            "ioc" | "iok" => Ok(Self::IOK),
+            "fok" => Ok(Self::FOK),
+            "post_only" => Ok(Self::PostOnly),
+            a => Err(ParseEnumError::new("OrderCondition", a)),
         }
     }
 }
 
+impl TryFrom<&str> for OrderCondition {
+    type Error = ParseEnumError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl OrderCondition {
+    /// Parse `value`, keeping values unknown to this version of the crate as [`OrderCondition::Unknown`]
+    pub fn parse_or_unknown(value: &str) -> Self {
+        value
+            .parse()
+            .unwrap_or_else(|_| Self::Unknown(value.to_owned()))
+    }
+}
+
+impl_sqlx_type!(OrderCondition, "order_condition");
+
+/// Self-match prevention. what happens when an order would fill against an order of the same account
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub enum SmpType {
+    /// 메이커 주문(기존 주문)을 취소
+    CancelMaker,
+    /// 테이커 주문(새 주문)을 취소
+    CancelTaker,
+    /// 두 주문의 수량을 줄이고 겹치는 수량만큼 취소
+    Reduce,
+    /// value unknown to this version of the crate
+    Unknown(String),
+}
+
+impl Display for SmpType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SmpType::CancelMaker => write!(f, "cancel_maker"),
+            SmpType::CancelTaker => write!(f, "cancel_taker"),
+            SmpType::Reduce => write!(f, "reduce"),
+            SmpType::Unknown(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl FromStr for SmpType {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "cancel_maker" => Ok(Self::CancelMaker),
+            "cancel_taker" => Ok(Self::CancelTaker),
+            "reduce" => Ok(Self::Reduce),
+            a => Err(ParseEnumError::new("SmpType", a)),
+        }
+    }
+}
+
+impl TryFrom<&str> for SmpType {
+    type Error = ParseEnumError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl SmpType {
+    /// Parse `value`, keeping values unknown to this version of the crate as [`SmpType::Unknown`]
+    pub fn parse_or_unknown(value: &str) -> Self {
+        value
+            .parse()
+            .unwrap_or_else(|_| Self::Unknown(value.to_owned()))
+    }
+}
+
+impl_sqlx_type!(SmpType, "smp_type");
+
 /// List of order state
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Copy, Clone)]
-#[cfg_attr(feature = "sqlx-type", derive(sqlx::Type))]
-#[cfg_attr(
-    feature = "sqlx-type",
-    sqlx(type_name = "order_state"),
-    sqlx(rename_all = "snake_case")
-)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 pub enum OrderState {
     /// 체결 대기
     Wait,
     /// 예약주문 대기
     Watch,
+    /// 체결 발생. myOrder WebSocket 이벤트에만 쓰인다
+    Trade,
     /// 전체 체결 완료
     Done,
     /// 주문 취소
     Cancel,
+    /// value unknown to this version of the crate
+    Unknown(String),
 }
 
 impl Display for OrderState {
@@ -151,24 +276,48 @@ impl Display for OrderState {
         match self {
             OrderState::Wait => write!(f, "wait"),
             OrderState::Watch => write!(f, "watch"),
+            OrderState::Trade => write!(f, "trade"),
             OrderState::Done => write!(f, "done"),
             OrderState::Cancel => write!(f, "cancel"),
+            OrderState::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
 
-impl From<&str> for OrderState {
-    fn from(value: &str) -> Self {
-        match value {
-            "wait" => OrderState::Wait,
-            "watch" => OrderState::Watch,
-            "done" => OrderState::Done,
-            "cancel" => OrderState::Cancel,
-            a @ _ => panic!("Unexpected value: {}", a),
+impl FromStr for OrderState {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "wait" => Ok(Self::Wait),
+            "watch" => Ok(Self::Watch),
+            "trade" => Ok(Self::Trade),
+            "done" => Ok(Self::Done),
+            "cancel" => Ok(Self::Cancel),
+            a => Err(ParseEnumError::new("OrderState", a)),
         }
     }
 }
 
+impl TryFrom<&str> for OrderState {
+    type Error = ParseEnumError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl OrderState {
+    /// Parse `value`, keeping values unknown to this version of the crate as [`OrderState::Unknown`]
+    pub fn parse_or_unknown(value: &str) -> Self {
+        value
+            .parse()
+            .unwrap_or_else(|_| Self::Unknown(value.to_owned()))
+    }
+}
+
+impl_sqlx_type!(OrderState, "order_state");
+
 /// 주문 요청을 한다. (Make an order(buy or sell) with desired price )
 ///
 /// # Example
@@ -231,20 +380,72 @@ impl From<&str> for OrderState {
 pub async fn order_by_price(
     market_id: &str,
     side: OrderSide,
-    price: f64,
-    price_desired: f64,
+    price: Number,
+    price_desired: Number,
     ord_type: OrderType,
     identifier: Option<&str>,
 ) -> Result<OrderInfo, ResponseError> {
-    OrderInfo::order_by_price(
-        market_id,
-        side,
-        (price + 1.0) / price_checker(price_desired),
-        price_checker(price_desired),
-        ord_type,
-        identifier,
-    )
-    .await
+    UpbitClient::default()
+        .order_by_price(market_id, side, price, price_desired, ord_type, identifier)
+        .await
+}
+
+/// 주문 요청을 한다. (Make an order of any ord_type and time_in_force.)
+///
+/// # Example
+/// ```no_run
+/// use upbit::api_exchange::{self, OrderCondition, OrderRequest, OrderSide};
+/// use upbit::Number;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let request = OrderRequest::limit("KRW-ETH", OrderSide::Bid, Number::from(1_435_000), "0.01".parse()?)
+///     .with_time_in_force(OrderCondition::PostOnly)
+///     .with_identifier("my-order-1");
+/// let order_info = api_exchange::place_order(&request).await;
+///
+/// let order_info = api_exchange::place_order(&OrderRequest::market_bid("KRW-ETH", Number::from(10_000))).await;
+/// let order_info = api_exchange::place_order(&OrderRequest::best_ask("KRW-ETH", "0.01".parse()?, OrderCondition::IOK)).await;
+/// # Ok(())
+/// # }
+/// ```
+/// - parameters
+/// > `request` order built by one of
+/// >> *  `OrderRequest::limit` 지정가 주문. price and volume<br>
+/// >> *  `OrderRequest::market_bid` 시장가 매수. funds<br>
+/// >> *  `OrderRequest::market_ask` 시장가 매도. volume<br>
+/// >> *  `OrderRequest::best_bid` 최유리 매수. funds and time_in_force<br>
+/// >> *  `OrderRequest::best_ask` 최유리 매도. volume and time_in_force<br>
+///
+/// > with optional `identifier`, `time_in_force` and `smp_type`<br>
+/// # Response
+/// * same as [`order_by_price`].
+/// * a request failing [`OrderRequest::validate`] is not sent and returns `InvalidParameter`.
+/// * the price is sent as is. round it with [`crate::TickSize`] first.
+pub async fn place_order(request: &OrderRequest) -> Result<OrderInfo, ResponseError> {
+    UpbitClient::default().place_order(request).await
+}
+
+/// 주문 생성을 테스트한다. (Validate an order on the server without placing it.)
+///
+/// # Example
+/// ```no_run
+/// use upbit::api_exchange::{self, OrderRequest, OrderSide};
+/// use upbit::Number;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let request = OrderRequest::limit("KRW-ETH", OrderSide::Bid, Number::from(1_435_000), "0.01".parse()?);
+/// let order_info = api_exchange::test_order(&request).await;
+/// # Ok(())
+/// # }
+/// ```
+/// - parameters
+/// > `request` same as [`place_order`]<br>
+/// # Response
+/// * same as [`place_order`]. the order is answered as if placed but never reaches the order book,
+///   and its uuid can't be queried or cancelled.
+/// * every order of a client goes here when it is built with [`UpbitClient::with_dry_run`].
+pub async fn test_order(request: &OrderRequest) -> Result<OrderInfo, ResponseError> {
+    UpbitClient::default().test_order(request).await
 }
 
 /// 주문을 취소한다. (Cancel an order.)
@@ -296,7 +497,7 @@ pub async fn order_by_price(
 /// | executed_volume   | 체결된 양                    | NumberString |
 /// | trades_count      | 해당 주문에 걸린 체결 수      | Integer |
 pub async fn cancel_order_by_uuid(uuid: &str) -> Result<OrderInfo, ResponseError> {
-    OrderInfo::cancel_order_by_uuid(uuid).await
+    UpbitClient::default().cancel_order_by_uuid(uuid).await
 }
 
 /// 주문을 취소한다. (Cancel an order.)
@@ -348,7 +549,177 @@ pub async fn cancel_order_by_uuid(uuid: &str) -> Result<OrderInfo, ResponseError
 /// | executed_volume   | 체결된 양                    | NumberString |
 /// | trades_count      | 해당 주문에 걸린 체결 수      | Integer |
 pub async fn cancel_order_by_identifier(identifier: &str) -> Result<OrderInfo, ResponseError> {
-    OrderInfo::cancel_order_by_identifier(identifier).await
+    UpbitClient::default()
+        .cancel_order_by_identifier(identifier)
+        .await
+}
+
+/// 주문을 취소하고 새 주문을 낸다. (Cancel an order and place another in its stead at once.)
+///
+/// # Example
+/// ```no_run
+/// use upbit::api_exchange::{self, OrderId, ReplaceOrderRequest, ReplaceVolume};
+/// use upbit::Number;
+///
+/// # async fn run() {
+/// let prev = OrderId::Uuid("cdd92199-2897-4e14-9448-f923320408ad".to_owned());
+/// let request = ReplaceOrderRequest::limit(prev, Number::from(1_436_000), ReplaceVolume::RemainOnly)
+///     .with_identifier("my-order-2");
+/// let order_replacement = api_exchange::cancel_and_new_order(&request).await;
+/// # }
+/// ```
+/// - parameters
+/// > `request` order to cancel and the new order, built by one of
+/// >> *  `ReplaceOrderRequest::limit` 지정가 주문. price and volume<br>
+/// >> *  `ReplaceOrderRequest::market_bid` 시장가 매수. funds<br>
+/// >> *  `ReplaceOrderRequest::market_ask` 시장가 매도. volume<br>
+/// >> *  `ReplaceOrderRequest::best_bid` 최유리 매수. funds and time_in_force<br>
+/// >> *  `ReplaceOrderRequest::best_ask` 최유리 매도. volume and time_in_force<br>
+///
+/// > with optional `identifier`, `time_in_force` and `smp_type` of the new order<br>
+/// > volume is a number or `ReplaceVolume::RemainOnly`, the unfilled volume of the cancelled order<br>
+/// # Response
+/// ```json
+/// {
+///    "uuid": "cdd92199-2897-4e14-9448-f923320408ad",
+///    "side": "bid",
+///    "ord_type": "limit",
+///    "price": "100.0",
+///    "state": "wait",
+///    "market": "KRW-BTC",
+///    "created_at": "2018-04-10T15:42:23+09:00",
+///    "volume": "0.01",
+///    "remaining_volume": "0.01",
+///    "reserved_fee": "0.0015",
+///    "remaining_fee": "0.0015",
+///    "paid_fee": "0.0",
+///    "locked": "1.0015",
+///    "executed_volume": "0.0",
+///    "trades_count": 0,
+///    "new_order_uuid": "9ca023a5-851b-4fec-9f0a-48cd83c2eaae",
+///    "new_order_identifier": "my-order-2"
+///  }
+/// ```
+/// # Response Description
+/// | field                | description                   | type         |
+/// |:---------------------|:------------------------------|:-------------|
+/// | uuid ~ trades_count  | 취소된 주문. same as [`cancel_order_by_uuid`] | |
+/// | new_order_uuid       | 새 주문의 고유 아이디          | String |
+/// | new_order_identifier | 새 주문의 사용자 지정 아이디   | String |
+///
+/// * the new order keeps the market and side of the cancelled one.
+/// * a request failing [`ReplaceOrderRequest::validate`] is not sent and returns `InvalidParameter`,
+///   as does any request of a client built with [`UpbitClient::with_dry_run`].
+pub async fn cancel_and_new_order(
+    request: &ReplaceOrderRequest,
+) -> Result<OrderReplacement, ResponseError> {
+    UpbitClient::default().cancel_and_new_order(request).await
+}
+
+/// 주문 목록을 UUID 로 한 번에 취소한다. (Cancel up to 20 orders at once via their UUIDs.)
+///
+/// # Example
+/// ```no_run
+/// use upbit::api_exchange;
+///
+/// # async fn run() {
+/// let order_cancel_result = api_exchange::cancel_orders_by_uuids(&["cdd92199-2897-4e14-9448-f923320408ad", "9ca023a5-851b-4fec-9f0a-48cd83c2eaae"]).await;
+/// # }
+/// ```
+/// - parameters
+/// > `uuids` 취소할 주문의 UUID 목록. 1 to 20 uuids<br>
+/// # Response
+/// ```json
+/// {
+///    "success": {
+///      "count": 1,
+///      "orders": [
+///        {
+///          "uuid": "cdd92199-2897-4e14-9448-f923320408ad",
+///          "market": "KRW-BTC",
+///          "identifier": null
+///        }
+///      ]
+///    },
+///    "failed": {
+///      "count": 1,
+///      "orders": [
+///        {
+///          "uuid": "9ca023a5-851b-4fec-9f0a-48cd83c2eaae",
+///          "market": "KRW-ETH",
+///          "identifier": "my-order-1"
+///        }
+///      ]
+///    }
+///  }
+/// ```
+/// # Response Description
+/// | field                | description                   | type         |
+/// |:---------------------|:------------------------------|:-------------|
+/// | success              | 취소된 주문                    | Object |
+/// | success.orders       | 취소된 주문 목록               | Array  |
+/// | failed               | 취소하지 못한 주문             | Object |
+/// | failed.orders        | 취소하지 못한 주문 목록        | Array  |
+/// | *.orders.uuid        | 주문의 고유 아이디             | String |
+/// | *.orders.market      | 마켓의 유일키                 | String |
+/// | *.orders.identifier  | 조회용 사용자 지정 값          | String |
+pub async fn cancel_orders_by_uuids(uuids: &[&str]) -> Result<OrderCancelResult, ResponseError> {
+    UpbitClient::default().cancel_orders_by_uuids(uuids).await
+}
+
+/// 주문 목록을 identifier 로 한 번에 취소한다. (Cancel up to 20 orders at once via their identifiers.)
+///
+/// # Example
+/// ```no_run
+/// use upbit::api_exchange;
+///
+/// # async fn run() {
+/// let order_cancel_result = api_exchange::cancel_orders_by_identifiers(&["my-order-1", "my-order-2"]).await;
+/// # }
+/// ```
+/// - parameters
+/// > `identifiers` 취소할 주문의 사용자 지정 값 목록. 1 to 20 identifiers<br>
+/// # Response
+/// * same as [`cancel_orders_by_uuids`].
+pub async fn cancel_orders_by_identifiers(
+    identifiers: &[&str],
+) -> Result<OrderCancelResult, ResponseError> {
+    UpbitClient::default()
+        .cancel_orders_by_identifiers(identifiers)
+        .await
+}
+
+/// 대기 중인 주문을 일괄 취소한다. (Cancel open orders at once, filtered by side and market.)
+///
+/// # Example
+/// ```no_run
+/// use upbit::api_exchange::{self, CancelOpenOrdersRequest, OrderSide};
+///
+/// # async fn run() {
+/// // every open order
+/// let order_cancel_result = api_exchange::cancel_open_orders(&CancelOpenOrdersRequest::new()).await;
+///
+/// let request = CancelOpenOrdersRequest::new()
+///     .with_side(OrderSide::Bid)
+///     .with_pairs(&["KRW-BTC", "KRW-ETH"]);
+/// let order_cancel_result = api_exchange::cancel_open_orders(&request).await;
+/// # }
+/// ```
+/// - parameters
+/// > `request` orders to cancel, narrowed down by
+/// >> *  `with_side` 매수/매도. both sides if not set<br>
+/// >> *  `with_pairs` 취소할 마켓 목록. up to 20 markets<br>
+/// >> *  `with_excluded_pairs` 제외할 마켓 목록. up to 20 markets<br>
+/// >> *  `with_quote_currencies` 취소할 마켓의 기준 화폐 목록. ex) KRW, BTC, USDT<br>
+/// >> *  `with_count` 취소할 주문 수. up to 300<br>
+/// >> *  `with_order_by` 취소 순서. ex) `OrderBy::Desc`, the latest orders first<br>
+/// # Response
+/// * same as [`cancel_orders_by_uuids`].
+/// * Upbit takes one request every 2 seconds. queued by the `order-cancel-all` group of [`crate::RateLimiter`].
+pub async fn cancel_open_orders(
+    request: &CancelOpenOrdersRequest,
+) -> Result<OrderCancelResult, ResponseError> {
+    UpbitClient::default().cancel_open_orders(request).await
 }
 
 /// 내가 보유한 자산 리스트를 보여줍니다. (inquire your account info)
@@ -388,7 +759,7 @@ pub async fn cancel_order_by_identifier(identifier: &str) -> Result<OrderInfo, R
 /// | avg_buy_price_modified | 매수평균가 수정 여부            | Boolean      |
 /// | unit_currency          | 평단가 기준 화폐                | String       |
 pub async fn get_account_info() -> Result<Vec<AccountsInfo>, ResponseError> {
-    AccountsInfo::get_account_info().await
+    UpbitClient::default().get_account_info().await
 }
 
 /// 마켓별 주문 가능 정보를 확인한다. (check specific market status.)
@@ -501,7 +872,36 @@ pub async fn get_account_info() -> Result<Vec<AccountsInfo>, ResponseError> {
 /// | ask_account.avg_buy_price_modified| 매수평균가 수정 여부 | Boolean |
 /// | ask_account.unit_currency| 평단가 기준 화폐 | String |
 pub async fn get_order_chance(market_id: &str) -> Result<OrderChance, ResponseError> {
-    OrderChance::get_order_chance(market_id).await
+    UpbitClient::default().get_order_chance(market_id).await
+}
+
+/// 주문 가능 정보로 주문 검증기를 만든다. (Fetch order chance of a market to check orders before placing them.)
+///
+/// # Example
+/// ```no_run
+/// use upbit::api_exchange::{self, OrderRequest, OrderSide};
+/// use upbit::Number;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let validator = api_exchange::get_order_validator("KRW-ETH").await?;
+/// let request = validator.adjust(&OrderRequest::limit("KRW-ETH", OrderSide::Bid, Number::from(1_435_085), "0.01".parse()?));
+///
+/// if let Err(violations) = validator.check(&request) {
+///     println!("{violations:?}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+/// - parameters
+/// > `market_id` ex) KRW-ETH<br>
+/// # Response
+/// * [`OrderValidator`] holding the response of [`get_order_chance`].
+/// * [`OrderValidator::check`] returns every [`OrderViolation`] of an order: order type not in
+///   `bid_types` or `ask_types`, price off the tick, total out of `min_total` and `max_total`, and
+///   balance short of the total and its fee.
+/// * [`OrderValidator::adjust`] rounds the price to the tick and cuts the amount to the balance.
+pub async fn get_order_validator(market_id: &str) -> Result<OrderValidator, ResponseError> {
+    UpbitClient::default().get_order_validator(market_id).await
 }
 
 /// 주문 UUID 를 통해 개별 주문건을 조회한다. (inquire each order status via order UUID.)
@@ -571,7 +971,7 @@ pub async fn get_order_chance(market_id: &str) -> Result<OrderChance, ResponseEr
 /// | trades.side | 체결 종류 | String |
 /// | trades.created_at | 체결 시각 | DateString |
 pub async fn get_order_status_by_uuid(uuid: &str) -> Result<OrderStatus, ResponseError> {
-    OrderStatus::get_order_status_by_uuid(uuid).await
+    UpbitClient::default().get_order_status_by_uuid(uuid).await
 }
 
 /// 주문 UUID 를 통해 개별 주문건을 조회한다. (inquire each order status via order UUID.)
@@ -643,7 +1043,9 @@ pub async fn get_order_status_by_uuid(uuid: &str) -> Result<OrderStatus, Respons
 pub async fn get_order_status_by_identifier(
     identifier: &str,
 ) -> Result<OrderStatus, ResponseError> {
-    OrderStatus::get_order_status_by_identifier(identifier).await
+    UpbitClient::default()
+        .get_order_status_by_identifier(identifier)
+        .await
 }
 
 /// 주문 리스트를 조회한다. (inquire every order status.)
@@ -695,7 +1097,7 @@ pub async fn get_order_status_by_identifier(
 #[allow(deprecated)]
 #[deprecated(since = "1.6.0", note = "use get_order_status_*() instead")]
 pub async fn list_order_status() -> Result<Vec<OrderInfo>, ResponseError> {
-    OrderInfo::get_order_state_list().await
+    UpbitClient::default().list_order_status().await
 }
 
 /// 주문 리스트를 조회한다. (inquire every order status.)
@@ -759,7 +1161,9 @@ pub async fn get_order_status_by_uuids(
     uuids: &[&str],
     order_by: OrderBy,
 ) -> Result<Vec<OrderInfo>, ResponseError> {
-    OrderInfo::get_order_status_by_uuids(market_id, uuids, order_by).await
+    UpbitClient::default()
+        .get_order_status_by_uuids(market_id, uuids, order_by)
+        .await
 }
 
 /// 주문 리스트를 조회한다. (inquire every order status.)
@@ -823,7 +1227,9 @@ pub async fn get_order_status_by_identifiers(
     identifiers: &[&str],
     order_by: OrderBy,
 ) -> Result<Vec<OrderInfo>, ResponseError> {
-    OrderInfo::get_order_status_by_identifiers(market_id, identifiers, order_by).await
+    UpbitClient::default()
+        .get_order_status_by_identifiers(market_id, identifiers, order_by)
+        .await
 }
 
 /// 주문 리스트를 조회한다. (inquire every order status.)
@@ -843,6 +1249,7 @@ pub async fn get_order_status_by_identifiers(
 /// > `states` Array of OrderState
 /// >> *  `OrderState::Wait` 대기<br>
 /// >> *  `OrderState::Watch` 주문 중<br>
+/// >
 /// > `page` page number. 1~ <br>
 /// > `limit` number of orders per page. 1~100<br>
 /// > `order_by`
@@ -896,7 +1303,9 @@ pub async fn get_order_status_opened(
     limit: u8,
     order_by: OrderBy,
 ) -> Result<Vec<OrderInfo>, ResponseError> {
-    OrderInfo::get_order_status_opened(market_id, states, page, limit, order_by).await
+    UpbitClient::default()
+        .get_order_status_opened(market_id, states, page, limit, order_by)
+        .await
 }
 
 /// 주문 리스트를 조회한다. (inquire every order status.)
@@ -917,6 +1326,7 @@ pub async fn get_order_status_opened(
 /// > `states` Array of OrderState
 /// >> *  `OrderState::Done` 완료<br>
 /// >> *  `OrderState::Cancel` 취소<br>
+/// >
 /// > `start_time` (optional) start time of the order<br>
 /// > `end_time` (optional) end time of the order<br>
 /// > `page` page number. 1~ <br>
@@ -973,40 +1383,226 @@ pub async fn get_order_status_closed(
     limit: u16,
     order_by: OrderBy,
 ) -> Result<Vec<OrderInfo>, ResponseError> {
-    OrderInfo::get_order_status_closed(market_id, states, start_time, end_time, limit, order_by)
+    UpbitClient::default()
+        .get_order_status_closed(market_id, states, start_time, end_time, limit, order_by)
         .await
 }
 
-pub fn price_checker(price: f64) -> f64 {
-    let truncation = if price >= 2_000_000.0 {
-        1000.0
-    } else if price >= 1_000_000.0 {
-        500.0
-    } else if price >= 500_000.0 {
-        100.0
-    } else if price >= 100_000.0 {
-        50.0
-    } else if price >= 10_000.0 {
-        10.0
-    } else if price >= 1000.0 {
-        1.0
-    } else if price >= 100.0 {
-        0.1
-    } else if price >= 10.0 {
-        0.01
-    } else if price >= 1.0 {
-        0.001
-    } else if price >= 0.1 {
-        0.0001
-    } else if price >= 0.01 {
-        0.00001
-    } else if price >= 0.001 {
-        0.000001
-    } else if price >= 0.0001 {
-        0.0000001
-    } else {
-        0.00000001
-    };
-
-    f64::trunc(price / truncation) * truncation
+/// Truncate `price` to the tick size of KRW markets
+#[deprecated(
+    since = "1.10.0",
+    note = "use TickSize::for_market(market_id).round_down(price) instead"
+)]
+pub fn price_checker(price: Number) -> Number {
+    TickSize::for_market("KRW")
+        .round_down(price)
+        .unwrap_or(price)
+}
+
+impl UpbitClient {
+    /// 주문 요청을 한다. (Make an order(buy or sell) with desired price )
+    ///
+    /// See [`order_by_price`].
+    pub async fn order_by_price(
+        &self,
+        market_id: &str,
+        side: OrderSide,
+        price: Number,
+        price_desired: Number,
+        ord_type: OrderType,
+        identifier: Option<&str>,
+    ) -> Result<OrderInfo, ResponseError> {
+        // prices rejected here are sent as is and left to the server to report
+        let price_desired = TickSize::for_market(market_id)
+            .round_down(price_desired)
+            .unwrap_or(price_desired);
+
+        OrderInfo::order_by_price(
+            self,
+            market_id,
+            side,
+            (price + Number::from(1u8)) / price_desired,
+            price_desired,
+            ord_type,
+            identifier,
+        )
+        .await
+    }
+
+    /// 주문 요청을 한다. (Make an order of any ord_type and time_in_force.)
+    ///
+    /// See [`place_order`].
+    pub async fn place_order(&self, request: &OrderRequest) -> Result<OrderInfo, ResponseError> {
+        OrderInfo::place_order(self, request).await
+    }
+
+    /// 주문 생성을 테스트한다. (Validate an order on the server without placing it.)
+    ///
+    /// See [`test_order`].
+    pub async fn test_order(&self, request: &OrderRequest) -> Result<OrderInfo, ResponseError> {
+        OrderInfo::test_order(self, request).await
+    }
+
+    /// 주문을 취소한다. (Cancel an order.)
+    ///
+    /// See [`cancel_order_by_uuid`].
+    pub async fn cancel_order_by_uuid(&self, uuid: &str) -> Result<OrderInfo, ResponseError> {
+        OrderInfo::cancel_order_by_uuid(self, uuid).await
+    }
+
+    /// 주문을 취소한다. (Cancel an order.)
+    ///
+    /// See [`cancel_order_by_identifier`].
+    pub async fn cancel_order_by_identifier(
+        &self,
+        identifier: &str,
+    ) -> Result<OrderInfo, ResponseError> {
+        OrderInfo::cancel_order_by_identifier(self, identifier).await
+    }
+
+    /// 주문을 취소하고 새 주문을 낸다. (Cancel an order and place another in its stead at once.)
+    ///
+    /// See [`cancel_and_new_order`].
+    pub async fn cancel_and_new_order(
+        &self,
+        request: &ReplaceOrderRequest,
+    ) -> Result<OrderReplacement, ResponseError> {
+        OrderReplacement::cancel_and_new_order(self, request).await
+    }
+
+    /// 주문 목록을 UUID 로 한 번에 취소한다. (Cancel up to 20 orders at once via their UUIDs.)
+    ///
+    /// See [`cancel_orders_by_uuids`].
+    pub async fn cancel_orders_by_uuids(
+        &self,
+        uuids: &[&str],
+    ) -> Result<OrderCancelResult, ResponseError> {
+        OrderCancelResult::cancel_orders_by_uuids(self, uuids).await
+    }
+
+    /// 주문 목록을 identifier 로 한 번에 취소한다. (Cancel up to 20 orders at once via their identifiers.)
+    ///
+    /// See [`cancel_orders_by_identifiers`].
+    pub async fn cancel_orders_by_identifiers(
+        &self,
+        identifiers: &[&str],
+    ) -> Result<OrderCancelResult, ResponseError> {
+        OrderCancelResult::cancel_orders_by_identifiers(self, identifiers).await
+    }
+
+    /// 대기 중인 주문을 일괄 취소한다. (Cancel open orders at once, filtered by side and market.)
+    ///
+    /// See [`cancel_open_orders`].
+    pub async fn cancel_open_orders(
+        &self,
+        request: &CancelOpenOrdersRequest,
+    ) -> Result<OrderCancelResult, ResponseError> {
+        OrderCancelResult::cancel_open_orders(self, request).await
+    }
+
+    /// 내가 보유한 자산 리스트를 보여줍니다. (inquire your account info)
+    ///
+    /// See [`get_account_info`].
+    pub async fn get_account_info(&self) -> Result<Vec<AccountsInfo>, ResponseError> {
+        AccountsInfo::get_account_info(self).await
+    }
+
+    /// 마켓별 주문 가능 정보를 확인한다. (check specific market status.)
+    ///
+    /// See [`get_order_chance`].
+    pub async fn get_order_chance(&self, market_id: &str) -> Result<OrderChance, ResponseError> {
+        OrderChance::get_order_chance(self, market_id).await
+    }
+
+    /// 주문 가능 정보로 주문 검증기를 만든다. (Fetch order chance of a market to check orders before placing them.)
+    ///
+    /// See [`get_order_validator`].
+    pub async fn get_order_validator(
+        &self,
+        market_id: &str,
+    ) -> Result<OrderValidator, ResponseError> {
+        OrderValidator::fetch(self, market_id).await
+    }
+
+    /// 주문 UUID 를 통해 개별 주문건을 조회한다. (inquire each order status via order UUID.)
+    ///
+    /// See [`get_order_status_by_uuid`].
+    pub async fn get_order_status_by_uuid(&self, uuid: &str) -> Result<OrderStatus, ResponseError> {
+        OrderStatus::get_order_status_by_uuid(self, uuid).await
+    }
+
+    /// 주문 UUID 를 통해 개별 주문건을 조회한다. (inquire each order status via order UUID.)
+    ///
+    /// See [`get_order_status_by_identifier`].
+    pub async fn get_order_status_by_identifier(
+        &self,
+        identifier: &str,
+    ) -> Result<OrderStatus, ResponseError> {
+        OrderStatus::get_order_status_by_identifier(self, identifier).await
+    }
+
+    /// 주문 리스트를 조회한다. (inquire every order status.)
+    ///
+    /// See [`list_order_status`].
+    #[allow(deprecated)]
+    #[deprecated(since = "1.6.0", note = "use get_order_status_*() instead")]
+    pub async fn list_order_status(&self) -> Result<Vec<OrderInfo>, ResponseError> {
+        OrderInfo::get_order_state_list(self).await
+    }
+
+    /// 주문 리스트를 조회한다. (inquire every order status.)
+    ///
+    /// See [`get_order_status_by_uuids`].
+    pub async fn get_order_status_by_uuids(
+        &self,
+        market_id: &str,
+        uuids: &[&str],
+        order_by: OrderBy,
+    ) -> Result<Vec<OrderInfo>, ResponseError> {
+        OrderInfo::get_order_status_by_uuids(self, market_id, uuids, order_by).await
+    }
+
+    /// 주문 리스트를 조회한다. (inquire every order status.)
+    ///
+    /// See [`get_order_status_by_identifiers`].
+    pub async fn get_order_status_by_identifiers(
+        &self,
+        market_id: &str,
+        identifiers: &[&str],
+        order_by: OrderBy,
+    ) -> Result<Vec<OrderInfo>, ResponseError> {
+        OrderInfo::get_order_status_by_identifiers(self, market_id, identifiers, order_by).await
+    }
+
+    /// 주문 리스트를 조회한다. (inquire every order status.)
+    ///
+    /// See [`get_order_status_opened`].
+    pub async fn get_order_status_opened(
+        &self,
+        market_id: &str,
+        states: &[OrderState],
+        page: u8,
+        limit: u8,
+        order_by: OrderBy,
+    ) -> Result<Vec<OrderInfo>, ResponseError> {
+        OrderInfo::get_order_status_opened(self, market_id, states, page, limit, order_by).await
+    }
+
+    /// 주문 리스트를 조회한다. (inquire every order status.)
+    ///
+    /// See [`get_order_status_closed`].
+    pub async fn get_order_status_closed(
+        &self,
+        market_id: &str,
+        states: &[OrderState],
+        start_time: Option<&str>,
+        end_time: Option<&str>,
+        limit: u16,
+        order_by: OrderBy,
+    ) -> Result<Vec<OrderInfo>, ResponseError> {
+        OrderInfo::get_order_status_closed(
+            self, market_id, states, start_time, end_time, limit, order_by,
+        )
+        .await
+    }
 }
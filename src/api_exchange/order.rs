@@ -1,168 +1,219 @@
+use crate::number::Number;
 use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
 use reqwest::{Response, Url};
 
-use crate::request::RequestWithQuery;
-
 use super::{
     super::{
-        constant::{URL_ORDER, URL_SERVER},
+        constant::{URL_ORDER, URL_ORDER_TEST},
         response::{OrderInfo, OrderInfoSource, ResponseError},
     },
-    OrderSide, OrderType,
+    OrderRequest, OrderSide, OrderType,
 };
+use crate::client::UpbitClient;
+
+/// Path orders of `client` are placed to. the order test API when it is a dry run
+fn order_path(client: &UpbitClient) -> &'static str {
+    if client.dry_run {
+        URL_ORDER_TEST
+    } else {
+        URL_ORDER
+    }
+}
 
 impl OrderInfo {
     pub async fn order_by_price(
+        client: &UpbitClient,
         market_id: &str,
         side: OrderSide,
-        volume: f64,
-        price: f64,
+        volume: Number,
+        price: Number,
         ord_type: OrderType,
         identifier: Option<&str>,
     ) -> Result<Self, ResponseError> {
-        let res =
-            Self::request_order_by_price(market_id, side, volume, price, ord_type, identifier)
-                .await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+        let res = Self::request_order_by_price(
+            client, market_id, side, volume, price, ord_type, identifier,
+        )
+        .await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         Self::deserialize_order_response(&res_serialized)
     }
 
     pub async fn order_ask_at_market_price(
+        client: &UpbitClient,
         market_id: &str,
         side: OrderSide,
-        volume: f64,
+        volume: Number,
         ord_type: OrderType,
         identifier: Option<&str>,
     ) -> Result<Self, ResponseError> {
-        let res = Self::request_ask_at_market_price(market_id, side, volume, ord_type, identifier)
-            .await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+        let res = Self::request_ask_at_market_price(
+            client, market_id, side, volume, ord_type, identifier,
+        )
+        .await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+        Self::deserialize_order_response(&res_serialized)
+    }
+
+    pub async fn place_order(
+        client: &UpbitClient,
+        request: &OrderRequest,
+    ) -> Result<Self, ResponseError> {
+        request.validate()?;
+
+        let res = Self::request_place_order(client, request, order_path(client)).await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         Self::deserialize_order_response(&res_serialized)
     }
 
+    pub async fn test_order(
+        client: &UpbitClient,
+        request: &OrderRequest,
+    ) -> Result<Self, ResponseError> {
+        request.validate()?;
+
+        let res = Self::request_place_order(client, request, URL_ORDER_TEST).await?;
+        let res_serialized = crate::response::response_text(res).await?;
+
+        Self::deserialize_order_response(&res_serialized)
+    }
+
+    async fn request_place_order(
+        client: &UpbitClient,
+        request: &OrderRequest,
+        path: &str,
+    ) -> Result<Response, ResponseError> {
+        let mut url = Url::parse(&format!("{}{path}", client.server_url))
+            .map_err(crate::response::response_error_internal_url_parse_error)?;
+
+        url.query_pairs_mut().extend_pairs(request.query_pairs());
+
+        let token_string = client.set_token_with_query(url.as_str())?;
+
+        client
+            .send(
+                client
+                    .http
+                    .post(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(CONTENT_TYPE, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
+            .await
+    }
+
     async fn request_order_by_price(
+        client: &UpbitClient,
         market_id: &str,
         side: OrderSide,
-        volume: f64,
-        price: f64,
+        volume: Number,
+        price: Number,
         ord_type: OrderType,
         identifier: Option<&str>,
     ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER}"))
+        let mut url = Url::parse(&format!("{}{}", client.server_url, order_path(client)))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
-        let price = format!("{:.8}", price);
-        let volume = format!("{:.8}", volume);
+        let price = crate::number::to_wire(price);
+        let volume = crate::number::to_wire(volume);
 
         url.query_pairs_mut()
             .append_pair("market", market_id)
             .append_pair("side", &side.to_string())
             .append_pair("ord_type", &ord_type.to_string())
-            .append_pair("price", &price.to_string())
-            .append_pair("volume", &volume.to_string());
+            .append_pair("price", &price)
+            .append_pair("volume", &volume);
 
         if let Some(identifier) = identifier {
             url.query_pairs_mut().append_pair("identifier", identifier);
         }
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
-
-        reqwest::Client::new()
-            .post(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(CONTENT_TYPE, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        let token_string = client.set_token_with_query(url.as_str())?;
+
+        client
+            .send(
+                client
+                    .http
+                    .post(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(CONTENT_TYPE, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     async fn request_ask_at_market_price(
+        client: &UpbitClient,
         market_id: &str,
         side: OrderSide,
-        volume: f64,
+        volume: Number,
         ord_type: OrderType,
         identifier: Option<&str>,
     ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER}"))
+        let mut url = Url::parse(&format!("{}{}", client.server_url, order_path(client)))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
-        let volume = format!("{:.8}", volume);
+        let volume = crate::number::to_wire(volume);
 
         url.query_pairs_mut()
             .append_pair("market", market_id)
             .append_pair("side", &side.to_string())
             .append_pair("ord_type", &ord_type.to_string())
-            .append_pair("volume", &volume.to_string());
+            .append_pair("volume", &volume);
 
         if let Some(identifier) = identifier {
             url.query_pairs_mut().append_pair("identifier", identifier);
         }
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
-
-        reqwest::Client::new()
-            .post(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(CONTENT_TYPE, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        let token_string = client.set_token_with_query(url.as_str())?;
+
+        client
+            .send(
+                client
+                    .http
+                    .post(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(CONTENT_TYPE, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     #[allow(dead_code)]
     async fn request_bid_at_market_price(
+        client: &UpbitClient,
         market_id: &str,
         side: OrderSide,
-        price: f64,
+        price: Number,
         ord_type: OrderType,
         identifier: Option<&str>,
     ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER}"))
+        let mut url = Url::parse(&format!("{}{}", client.server_url, order_path(client)))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
-        let price = format!("{:.8}", price);
+        let price = crate::number::to_wire(price);
 
         url.query_pairs_mut()
             .append_pair("market", market_id)
             .append_pair("side", &side.to_string())
             .append_pair("ord_type", &ord_type.to_string())
-            .append_pair("price", &price.to_string());
+            .append_pair("price", &price);
 
         if let Some(identifier) = identifier {
             url.query_pairs_mut().append_pair("identifier", identifier);
         }
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
-
-        reqwest::Client::new()
-            .post(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(CONTENT_TYPE, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        let token_string = client.set_token_with_query(url.as_str())?;
+
+        client
+            .send(
+                client
+                    .http
+                    .post(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(CONTENT_TYPE, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     fn deserialize_order_response(res_serialized: &str) -> Result<Self, ResponseError> {
@@ -196,19 +247,29 @@ mod tests {
 
     use serde_json::Value;
 
-    use crate::api_exchange::price_checker;
+    use crate::tick_size::TickSize;
 
     use super::*;
+    use crate::number::Number;
+    use crate::response::ResponseErrorState;
 
     #[tokio::test]
     async fn test_order_bid_by_price() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let volume = (5000.0 + 1.) / price_checker(1_435_085.0);
-        let price = price_checker(1_435_085.0);
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
+
+        let volume = (Number::from(5000) + Number::from(1))
+            / TickSize::for_market("KRW-ETH")
+                .round_down(Number::from(1_435_085))
+                .unwrap();
+        let price = TickSize::for_market("KRW-ETH")
+            .round_down(Number::from(1_435_085))
+            .unwrap();
 
         let res = OrderInfo::request_order_by_price(
+            &client,
             "KRW-ETH",
             OrderSide::Bid,
             volume,
@@ -225,7 +286,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -259,31 +320,35 @@ mod tests {
         let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
 
         if !missing_keys.is_empty() {
-            println!("[test_order_bid_by_price] Missing keys: {:?}", missing_keys);
-            assert!(false);
+            panic!("[test_order_bid_by_price] Missing keys: {:?}", missing_keys);
         } else {
             println!("[test_order_bid_by_price] No keys are missing");
         }
 
         if !extra_keys.is_empty() {
-            println!("[test_order_bid_by_price] Extra keys: {:?}", extra_keys);
-            assert!(false);
+            panic!("[test_order_bid_by_price] Extra keys: {:?}", extra_keys);
         } else {
             println!("[test_order_bid_by_price] No extra keys found.");
         }
-
-        assert!(true);
     }
 
     #[tokio::test]
     async fn test_order_ask_by_price() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let volume = (5000.0 + 1.) / price_checker(3_435_085.0);
-        let price = price_checker(3_435_085.0);
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
+
+        let volume = (Number::from(5000) + Number::from(1))
+            / TickSize::for_market("KRW-ETH")
+                .round_down(Number::from(3_435_085))
+                .unwrap();
+        let price = TickSize::for_market("KRW-ETH")
+            .round_down(Number::from(3_435_085))
+            .unwrap();
 
         let res = OrderInfo::request_order_by_price(
+            &client,
             "KRW-ETH",
             OrderSide::Ask,
             volume,
@@ -300,7 +365,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -334,30 +399,31 @@ mod tests {
         let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
 
         if !missing_keys.is_empty() {
-            println!("[test_order_ask_by_price] Missing keys: {:?}", missing_keys);
-            assert!(false);
+            panic!("[test_order_ask_by_price] Missing keys: {:?}", missing_keys);
         } else {
             println!("[test_order_ask_by_price] No keys are missing");
         }
 
         if !extra_keys.is_empty() {
-            println!("[test_order_ask_by_price] Extra keys: {:?}", extra_keys);
-            assert!(false);
+            panic!("[test_order_ask_by_price] Extra keys: {:?}", extra_keys);
         } else {
             println!("[test_order_ask_by_price] No extra keys found.");
         }
-
-        assert!(true);
     }
 
     #[tokio::test]
     async fn test_order_bid_at_market_price() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
-        let price = price_checker(5000.0);
+        let price = TickSize::for_market("KRW-ETH")
+            .round_down(Number::from(5000))
+            .unwrap();
 
         let res = OrderInfo::request_bid_at_market_price(
+            &client,
             "KRW-ETH",
             OrderSide::Bid,
             price,
@@ -373,7 +439,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -407,36 +473,35 @@ mod tests {
         let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
 
         if !missing_keys.is_empty() {
-            println!(
+            panic!(
                 "[test_order_bid_at_market_price] Missing keys: {:?}",
                 missing_keys
             );
-            assert!(false);
         } else {
             println!("[test_order_bid_at_market_price] No keys are missing");
         }
 
         if !extra_keys.is_empty() {
-            println!(
+            panic!(
                 "[test_order_bid_at_market_price] Extra keys: {:?}",
                 extra_keys
             );
-            assert!(false);
         } else {
             println!("[test_order_bid_at_market_price] No extra keys found.");
         }
-
-        assert!(true);
     }
 
     #[tokio::test]
     async fn test_order_ask_at_market_price() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
-        let volume = 0.0015;
+        let volume = "0.0015".parse().unwrap();
 
         let res = OrderInfo::request_ask_at_market_price(
+            &client,
             "KRW-ETH",
             OrderSide::Ask,
             volume,
@@ -452,7 +517,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -486,26 +551,189 @@ mod tests {
         let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
 
         if !missing_keys.is_empty() {
-            println!(
+            panic!(
                 "[test_order_ask_at_market_price] Missing keys: {:?}",
                 missing_keys
             );
-            assert!(false);
         } else {
             println!("[test_order_ask_at_market_price] No keys are missing");
         }
 
         if !extra_keys.is_empty() {
-            println!(
+            panic!(
                 "[test_order_ask_at_market_price] Extra keys: {:?}",
                 extra_keys
             );
-            assert!(false);
         } else {
             println!("[test_order_ask_at_market_price] No extra keys found.");
         }
+    }
+
+    #[tokio::test]
+    async fn test_order_sends_exact_decimal_strings() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/orders"))
+            .and(query_param("price", "1435000"))
+            .and(query_param("volume", "0.3"))
+            .respond_with(ResponseTemplate::new(201).set_body_string(
+                r#"{"uuid":"cdd92199-2897-4e14-9448-f923320408ad","side":"bid","ord_type":"limit","price":"1435000","state":"wait","market":"KRW-ETH","created_at":"2018-04-10T15:42:23+09:00","volume":"0.3","remaining_volume":"0.3","reserved_fee":"0.0015","remaining_fee":"0.0015","paid_fee":"0.0","locked":"430500.0","executed_volume":"0.0","trades_count":0}"#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key").with_server_url(&server.uri());
+        let volume = "0.1".parse::<Number>().unwrap() + "0.2".parse::<Number>().unwrap();
+
+        let order_info = OrderInfo::order_by_price(
+            &client,
+            "KRW-ETH",
+            OrderSide::Bid,
+            volume,
+            "1435000".parse().unwrap(),
+            OrderType::Limit,
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(order_info.volume, "0.3".parse::<Number>().unwrap());
+        assert_eq!(order_info.locked, "430500".parse::<Number>().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_place_order() {
+        use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/orders"))
+            .and(query_param("ord_type", "best"))
+            .and(query_param("side", "bid"))
+            .and(query_param("price", "10000"))
+            .and(query_param("time_in_force", "fok"))
+            .and(query_param("smp_type", "cancel_taker"))
+            .and(query_param_is_missing("volume"))
+            .respond_with(ResponseTemplate::new(201).set_body_string(
+                r#"{"uuid":"cdd92199-2897-4e14-9448-f923320408ad","side":"bid","ord_type":"best","price":"10000","state":"wait","market":"KRW-ETH","created_at":"2018-04-10T15:42:23+09:00","volume":null,"remaining_volume":null,"reserved_fee":"5","remaining_fee":"5","paid_fee":"0.0","locked":"10005","executed_volume":"0.0","trades_count":0,"time_in_force":"fok"}"#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key").with_server_url(&server.uri());
+        let request = OrderRequest::best_bid(
+            "KRW-ETH",
+            Number::from(10000),
+            super::super::OrderCondition::FOK,
+        )
+        .with_smp_type(super::super::SmpType::CancelTaker);
+
+        let order_info = OrderInfo::place_order(&client, &request).await.unwrap();
+
+        assert_eq!(order_info.ord_type, OrderType::Best);
+        assert_eq!(
+            order_info.time_in_force,
+            Some(super::super::OrderCondition::FOK)
+        );
+
+        // rejected before a request is sent
+        let error = OrderInfo::place_order(
+            &client,
+            &OrderRequest::market_ask("KRW-ETH", Number::from(0)),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(error.state, ResponseErrorState::InvalidParameter);
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[tokio::test]
+    async fn test_order_by_price_sends_volume_of_8_decimal_places() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        // (5000 + 1) / 1435000 = 0.003485017421602787...
+        Mock::given(method("POST"))
+            .and(path("/v1/orders"))
+            .and(query_param("price", "1435000"))
+            .and(query_param("volume", "0.00348501"))
+            .respond_with(ResponseTemplate::new(201).set_body_string(
+                r#"{"uuid":"cdd92199-2897-4e14-9448-f923320408ad","side":"bid","ord_type":"limit","price":"1435000","state":"wait","market":"KRW-ETH","created_at":"2018-04-10T15:42:23+09:00","volume":"0.00348501","remaining_volume":"0.00348501","reserved_fee":"2.5","remaining_fee":"2.5","paid_fee":"0.0","locked":"5003.5","executed_volume":"0.0","trades_count":0}"#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key").with_server_url(&server.uri());
+
+        client
+            .order_by_price(
+                "KRW-ETH",
+                OrderSide::Bid,
+                Number::from(5000),
+                Number::from(1_435_085),
+                OrderType::Limit,
+                None,
+            )
+            .await
+            .unwrap();
+    }
 
-        assert!(true);
+    #[tokio::test]
+    async fn test_dry_run_orders_go_to_order_test() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/orders/test"))
+            .respond_with(ResponseTemplate::new(201).set_body_string(
+                r#"{"uuid":"cdd92199-2897-4e14-9448-f923320408ad","side":"bid","ord_type":"limit","price":"100.0","state":"wait","market":"KRW-ETH","created_at":"2018-04-10T15:42:23+09:00","volume":"0.01","remaining_volume":"0.01","reserved_fee":"0.0015","remaining_fee":"0.0015","paid_fee":"0.0","locked":"1.0015","executed_volume":"0.0","trades_count":0}"#,
+            ))
+            .expect(3)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/orders"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key").with_server_url(&server.uri());
+        let request = OrderRequest::limit(
+            "KRW-ETH",
+            OrderSide::Bid,
+            Number::from(100),
+            "0.01".parse().unwrap(),
+        );
+
+        let order_info = OrderInfo::test_order(&client, &request).await.unwrap();
+
+        assert_eq!(order_info.uuid, "cdd92199-2897-4e14-9448-f923320408ad");
+
+        let client = client.with_dry_run(true);
+
+        OrderInfo::place_order(&client, &request).await.unwrap();
+        OrderInfo::order_by_price(
+            &client,
+            "KRW-ETH",
+            OrderSide::Bid,
+            "0.01".parse().unwrap(),
+            Number::from(100),
+            OrderType::Limit,
+            None,
+        )
+        .await
+        .unwrap();
     }
 
     // async fn test order_bid_by_price
@@ -1,79 +1,75 @@
-use crate::request::RequestWithQuery;
 use reqwest::{
     header::{ACCEPT, AUTHORIZATION},
     Response, Url,
 };
 
 use super::{
-    super::constant::{URL_ORDER_STATUS, URL_SERVER},
+    super::constant::URL_ORDER_STATUS,
     super::response::{OrderInfo, OrderInfoSource, ResponseError},
 };
+use crate::client::UpbitClient;
 
 impl OrderInfo {
-    pub async fn cancel_order_by_uuid(uuid: &str) -> Result<Self, ResponseError> {
-        let res = Self::request_cancel_by_uuid(uuid).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+    pub async fn cancel_order_by_uuid(
+        client: &UpbitClient,
+        uuid: &str,
+    ) -> Result<Self, ResponseError> {
+        let res = Self::request_cancel_by_uuid(client, uuid).await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         Self::deserialize_order_cancel(&res_serialized)
     }
 
-    pub async fn cancel_order_by_identifier(identifier: &str) -> Result<Self, ResponseError> {
-        let res = Self::request_cancel_by_identifier(identifier).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+    pub async fn cancel_order_by_identifier(
+        client: &UpbitClient,
+        identifier: &str,
+    ) -> Result<Self, ResponseError> {
+        let res = Self::request_cancel_by_identifier(client, identifier).await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         Self::deserialize_order_cancel(&res_serialized)
     }
 
-    async fn request_cancel_by_uuid(uuid: &str) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER_STATUS}"))
+    async fn request_cancel_by_uuid(
+        client: &UpbitClient,
+        uuid: &str,
+    ) -> Result<Response, ResponseError> {
+        let mut url = Url::parse(&format!("{}{URL_ORDER_STATUS}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
         url.query_pairs_mut().append_pair("uuid", uuid);
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
+        let token_string = client.set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .delete(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        client
+            .send(
+                client
+                    .http
+                    .delete(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
-    async fn request_cancel_by_identifier(identifier: &str) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER_STATUS}"))
+    async fn request_cancel_by_identifier(
+        client: &UpbitClient,
+        identifier: &str,
+    ) -> Result<Response, ResponseError> {
+        let mut url = Url::parse(&format!("{}{URL_ORDER_STATUS}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
         url.query_pairs_mut().append_pair("identifier", identifier);
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
+        let token_string = client.set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .delete(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        client
+            .send(
+                client
+                    .http
+                    .delete(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     fn deserialize_order_cancel(res_serialized: &str) -> Result<Self, ResponseError> {
@@ -112,14 +108,21 @@ mod tests {
         response::OrderInfo,
     };
 
+    use crate::client::UpbitClient;
+    use crate::number::Number;
+
     #[tokio::test]
     async fn test_order_cancel_by_uuid() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
         let uuid = order_to_get_uuid().await;
 
-        let res = OrderInfo::request_cancel_by_uuid(&uuid).await.unwrap();
+        let res = OrderInfo::request_cancel_by_uuid(&client, &uuid)
+            .await
+            .unwrap();
         let res_serialized = res
             .text()
             .await
@@ -127,7 +130,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -161,38 +164,37 @@ mod tests {
         let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
 
         if !missing_keys.is_empty() {
-            println!("[test_order_cancel_by_uuid] Missing keys: {missing_keys:?}",);
-            assert!(false);
+            panic!("[test_order_cancel_by_uuid] Missing keys: {missing_keys:?}",);
         } else {
             println!("[test_order_cancel_by_uuid] No keys are missing");
         }
 
         if !extra_keys.is_empty() {
-            println!("[test_order_cancel_by_uuid] Extra keys: {extra_keys:?}");
-            assert!(false);
+            panic!("[test_order_cancel_by_uuid] Extra keys: {extra_keys:?}");
         } else {
             println!("[test_order_cancel_by_uuid] No extra keys found.");
         }
-
-        assert!(true);
     }
 
     async fn order_to_get_uuid() -> String {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let price = 5000.0;
-        let price_desired = 1_435_085.0;
-
-        let res = super::super::order_by_price(
-            "KRW-ETH",
-            OrderSide::Bid,
-            price,
-            price_desired,
-            OrderType::Limit,
-            None,
-        )
-        .await;
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
+
+        let price = Number::from(5000);
+        let price_desired = Number::from(1_435_085);
+
+        let res = client
+            .order_by_price(
+                "KRW-ETH",
+                OrderSide::Bid,
+                price,
+                price_desired,
+                OrderType::Limit,
+                None,
+            )
+            .await;
 
         if let Ok(res) = res {
             res.uuid
@@ -0,0 +1,410 @@
+use reqwest::header::{ACCEPT, AUTHORIZATION};
+use reqwest::{Response, Url};
+
+use super::OrderSide;
+use crate::client::UpbitClient;
+use crate::constant::{OrderBy, URL_ORDER_CANCEL_BY_UUID, URL_ORDER_CANCEL_OPEN};
+use crate::response::{OrderCancelResult, OrderCancelResultSource, ResponseError};
+
+/// Most orders cancelled by uuids or identifiers in a request
+pub const MAX_CANCEL_ORDERS: usize = 20;
+
+/// Most markets in `pairs` or `excluded_pairs` of [`CancelOpenOrdersRequest`]
+pub const MAX_CANCEL_PAIRS: usize = 20;
+
+/// Most open orders cancelled in a request
+pub const MAX_CANCEL_OPEN_COUNT: u32 = 300;
+
+/// Open orders to cancel with [`super::cancel_open_orders`]
+///
+/// Every open order of the account is cancelled unless narrowed down by side, markets or quote
+/// currencies.
+///
+/// # Example
+/// ```
+/// use upbit::api_exchange::{CancelOpenOrdersRequest, OrderSide};
+///
+/// let request = CancelOpenOrdersRequest::new()
+///     .with_side(OrderSide::Bid)
+///     .with_pairs(&["KRW-BTC", "KRW-ETH"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CancelOpenOrdersRequest {
+    side: Option<OrderSide>,
+    pairs: Vec<String>,
+    excluded_pairs: Vec<String>,
+    quote_currencies: Vec<String>,
+    count: Option<u32>,
+    order_by: Option<OrderBy>,
+}
+
+impl CancelOpenOrdersRequest {
+    /// Cancel every open order
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel orders of `side` only
+    pub fn with_side(mut self, side: OrderSide) -> Self {
+        self.side = Some(side);
+        self
+    }
+
+    /// Cancel orders of these markets only. ex) `["KRW-BTC", "KRW-ETH"]`
+    pub fn with_pairs(mut self, market_ids: &[&str]) -> Self {
+        self.pairs = market_ids.iter().map(|x| (*x).to_owned()).collect();
+        self
+    }
+
+    /// Keep orders of these markets
+    pub fn with_excluded_pairs(mut self, market_ids: &[&str]) -> Self {
+        self.excluded_pairs = market_ids.iter().map(|x| (*x).to_owned()).collect();
+        self
+    }
+
+    /// Cancel orders of markets quoted in these currencies only. ex) `["KRW", "USDT"]`
+    pub fn with_quote_currencies(mut self, currencies: &[&str]) -> Self {
+        self.quote_currencies = currencies.iter().map(|x| (*x).to_owned()).collect();
+        self
+    }
+
+    /// Cancel up to `count` orders, at most [`MAX_CANCEL_OPEN_COUNT`]
+    pub fn with_count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Cancel the oldest (`Asc`) or the latest (`Desc`) orders first
+    pub fn with_order_by(mut self, order_by: OrderBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    pub fn side(&self) -> Option<&OrderSide> {
+        self.side.as_ref()
+    }
+
+    pub fn pairs(&self) -> &[String] {
+        &self.pairs
+    }
+
+    pub fn excluded_pairs(&self) -> &[String] {
+        &self.excluded_pairs
+    }
+
+    pub fn quote_currencies(&self) -> &[String] {
+        &self.quote_currencies
+    }
+
+    pub fn count(&self) -> Option<u32> {
+        self.count
+    }
+
+    pub fn order_by(&self) -> Option<&OrderBy> {
+        self.order_by.as_ref()
+    }
+
+    /// Get parameters of the request as sent to the API, in the order they are sent
+    pub(crate) fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = vec![(
+            "cancel_side",
+            self.side
+                .as_ref()
+                .map_or("all".to_owned(), ToString::to_string),
+        )];
+
+        for (key, values) in [
+            ("pairs", &self.pairs),
+            ("excluded_pairs", &self.excluded_pairs),
+            ("quote_currencies", &self.quote_currencies),
+        ] {
+            if !values.is_empty() {
+                pairs.push((key, values.join(",")));
+            }
+        }
+        if let Some(count) = self.count {
+            pairs.push(("count", count.to_string()));
+        }
+        if let Some(order_by) = &self.order_by {
+            pairs.push(("order_by", order_by.to_string()));
+        }
+
+        pairs
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if let Some(OrderSide::Unknown(side)) = &self.side {
+            return Err(format!("unknown side: {side}"));
+        }
+        if self.pairs.len() > MAX_CANCEL_PAIRS || self.excluded_pairs.len() > MAX_CANCEL_PAIRS {
+            return Err(format!("pairs take at most {MAX_CANCEL_PAIRS} markets"));
+        }
+        if self
+            .count
+            .is_some_and(|x| x == 0 || x > MAX_CANCEL_OPEN_COUNT)
+        {
+            return Err(format!("count must be in 1..={MAX_CANCEL_OPEN_COUNT}"));
+        }
+
+        Ok(())
+    }
+}
+
+impl OrderCancelResult {
+    pub async fn cancel_orders_by_uuids(
+        client: &UpbitClient,
+        uuids: &[&str],
+    ) -> Result<Self, ResponseError> {
+        let res = Self::request_cancel_by_ids(client, "uuids", uuids).await?;
+        let res_serialized = crate::response::response_text(res).await?;
+
+        Self::deserialize_cancel_result(&res_serialized)
+    }
+
+    pub async fn cancel_orders_by_identifiers(
+        client: &UpbitClient,
+        identifiers: &[&str],
+    ) -> Result<Self, ResponseError> {
+        let res = Self::request_cancel_by_ids(client, "identifiers", identifiers).await?;
+        let res_serialized = crate::response::response_text(res).await?;
+
+        Self::deserialize_cancel_result(&res_serialized)
+    }
+
+    pub async fn cancel_open_orders(
+        client: &UpbitClient,
+        request: &CancelOpenOrdersRequest,
+    ) -> Result<Self, ResponseError> {
+        request
+            .validate()
+            .map_err(crate::response::response_error_invalid_parameter)?;
+
+        let res = Self::request_cancel_open(client, request).await?;
+        let res_serialized = crate::response::response_text(res).await?;
+
+        Self::deserialize_cancel_result(&res_serialized)
+    }
+
+    async fn request_cancel_by_ids(
+        client: &UpbitClient,
+        key: &str,
+        ids: &[&str],
+    ) -> Result<Response, ResponseError> {
+        if ids.is_empty() || ids.len() > MAX_CANCEL_ORDERS {
+            return Err(crate::response::response_error_invalid_parameter(format!(
+                "{key} take 1 to {MAX_CANCEL_ORDERS} orders: {}",
+                ids.len()
+            )));
+        }
+
+        let key = format!("{key}[]");
+        let query = query_string(ids.iter().map(|id| (key.as_str(), *id)));
+
+        Self::request_cancel(client, URL_ORDER_CANCEL_BY_UUID, &query).await
+    }
+
+    async fn request_cancel_open(
+        client: &UpbitClient,
+        request: &CancelOpenOrdersRequest,
+    ) -> Result<Response, ResponseError> {
+        let pairs = request.query_pairs();
+        let query = query_string(pairs.iter().map(|(key, value)| (*key, value.as_str())));
+
+        Self::request_cancel(client, URL_ORDER_CANCEL_OPEN, &query).await
+    }
+
+    async fn request_cancel(
+        client: &UpbitClient,
+        path: &str,
+        query: &str,
+    ) -> Result<Response, ResponseError> {
+        let mut url = Url::parse(&format!("{}{path}", client.server_url))
+            .map_err(crate::response::response_error_internal_url_parse_error)?;
+
+        // the token is signed over the query as sent, with `[]` keys and comma separated lists
+        url.set_query(Some(query));
+        let token_string = client.set_token_with_query(url.as_str())?;
+
+        client
+            .send(
+                client
+                    .http
+                    .delete(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
+            .await
+    }
+
+    fn deserialize_cancel_result(res_serialized: &str) -> Result<Self, ResponseError> {
+        serde_json::from_str::<OrderCancelResultSource>(res_serialized)
+            .map(Self::from)
+            .map_err(crate::response::response_error_from_json)
+    }
+}
+
+/// Join `pairs` into a query string, percent-encoding values but for the commas of lists
+///
+/// Keys are kept as is, so `uuids[]` isn't sent as `uuids%5B%5D`.
+fn query_string<'a>(pairs: impl IntoIterator<Item = (&'a str, &'a str)>) -> String {
+    pairs
+        .into_iter()
+        .map(|(key, value)| format!("{key}={}", encode_query_value(value)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn encode_query_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|x| match x {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b',' => {
+                char::from(x).to_string()
+            }
+            _ => format!("%{x:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::ResponseErrorState;
+
+    const CANCEL_RESULT: &str = r#"{"success":{"count":2,"orders":[{"uuid":"cdd92199-2897-4e14-9448-f923320408ad","market":"KRW-BTC","identifier":null},{"uuid":"9ca023a5-851b-4fec-9f0a-48cd83c2eaae","market":"KRW-ETH","identifier":"my-order-1"}]},"failed":{"count":1,"orders":[{"uuid":"d60dfc8a-db0a-4087-9974-fed6433eb8f1","market":"KRW-BTC","identifier":null}]}}"#;
+
+    #[tokio::test]
+    async fn test_cancel_orders_by_uuids() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/v1/orders/uuids"))
+            .respond_with(|request: &Request| {
+                let uuids = request
+                    .url
+                    .query_pairs()
+                    .filter(|(k, _)| k == "uuids[]")
+                    .count();
+
+                assert_eq!(uuids, 3);
+                assert!(request
+                    .url
+                    .query()
+                    .unwrap()
+                    .starts_with("uuids[]=cdd92199-2897-4e14-9448-f923320408ad&uuids[]="));
+                ResponseTemplate::new(200).set_body_string(CANCEL_RESULT)
+            })
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key").with_server_url(&server.uri());
+        let result = OrderCancelResult::cancel_orders_by_uuids(
+            &client,
+            &[
+                "cdd92199-2897-4e14-9448-f923320408ad",
+                "9ca023a5-851b-4fec-9f0a-48cd83c2eaae",
+                "d60dfc8a-db0a-4087-9974-fed6433eb8f1",
+            ],
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.success.len(), 2);
+        assert_eq!(result.success[1].identifier.as_deref(), Some("my-order-1"));
+        assert_eq!(
+            result.failed[0].uuid,
+            "d60dfc8a-db0a-4087-9974-fed6433eb8f1"
+        );
+        assert!(!result.is_all_success());
+
+        // rejected before a request is sent
+        let error = OrderCancelResult::cancel_orders_by_identifiers(&client, &[])
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.state, ResponseErrorState::InvalidParameter);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_orders_by_identifiers_encodes_identifiers() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/v1/orders/uuids"))
+            .and(query_param("identifiers[]", "my&order=1 #+"))
+            .respond_with(|request: &Request| {
+                assert_eq!(
+                    request.url.query(),
+                    Some("identifiers[]=my%26order%3D1%20%23%2B&identifiers[]=my-order-2")
+                );
+                ResponseTemplate::new(200).set_body_string(CANCEL_RESULT)
+            })
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key").with_server_url(&server.uri());
+        let result = OrderCancelResult::cancel_orders_by_identifiers(
+            &client,
+            &["my&order=1 #+", "my-order-2"],
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.success.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_open_orders() {
+        use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/v1/orders/open"))
+            .and(query_param("cancel_side", "bid"))
+            .and(query_param("pairs", "KRW-BTC,KRW-ETH"))
+            .and(query_param("count", "100"))
+            .and(query_param_is_missing("quote_currencies"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(CANCEL_RESULT))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key").with_server_url(&server.uri());
+        let request = CancelOpenOrdersRequest::new()
+            .with_side(OrderSide::Bid)
+            .with_pairs(&["KRW-BTC", "KRW-ETH"])
+            .with_count(100);
+
+        let result = OrderCancelResult::cancel_open_orders(&client, &request)
+            .await
+            .unwrap();
+
+        assert_eq!(result.success.len(), 2);
+        assert_eq!(result.failed.len(), 1);
+
+        assert_eq!(
+            query_string([("cancel_side", "bid"), ("pairs", "KRW-BTC,KRW-ETH")]),
+            "cancel_side=bid&pairs=KRW-BTC,KRW-ETH"
+        );
+        assert_eq!(
+            CancelOpenOrdersRequest::new().query_pairs(),
+            [("cancel_side", "all".to_owned())]
+        );
+
+        let error = OrderCancelResult::cancel_open_orders(
+            &client,
+            &CancelOpenOrdersRequest::new().with_count(301),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(error.state, ResponseErrorState::InvalidParameter);
+    }
+}
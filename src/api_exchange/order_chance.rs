@@ -1,104 +1,107 @@
 use reqwest::header::{ACCEPT, AUTHORIZATION};
 use reqwest::{Response, Url};
 
+use super::OrderSide;
 use super::{
-    super::constant::{URL_ORDER_CHANCE, URL_SERVER},
+    super::constant::{AskType, BidType, URL_ORDER_CHANCE},
     super::response::{
         AccountsInfo, ObjectAskBid, ObjectMarket, OrderChance, OrderChanceSource, ResponseError,
     },
 };
+use crate::client::UpbitClient;
 use crate::request::RequestWithQuery;
 
 impl RequestWithQuery for OrderChance {}
 impl OrderChance {
-    pub async fn get_order_chance(market_id: &str) -> Result<Self, ResponseError> {
-        let res = Self::request(market_id).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
-
-        serde_json::from_str(&res_serialized)
-            .map(|x: OrderChanceSource| {
-                Self {
-                    bid_fee: x.bid_fee.parse().unwrap(),
-                    ask_fee: x.ask_fee.parse().unwrap(),
-                    market: ObjectMarket {
-                        id: x.market.id.to_owned(),
-                        name: x.market.name.to_owned(),
-                        // order_types: x.market.order_types.into_iter().map(|x|x.as_str().into()).collect(),
-                        order_sides: x
-                            .market
-                            .order_sides
-                            .into_iter()
-                            .map(|x| x.as_str().into())
-                            .collect(),
-                        bid: ObjectAskBid {
-                            currency: x.market.bid.currency.to_owned(),
-                            price_unit: x.market.bid.price_unit.to_owned(),
-                            min_total: x.market.bid.min_total.parse().unwrap(),
-                        },
-                        ask: ObjectAskBid {
-                            currency: x.market.ask.currency.to_owned(),
-                            price_unit: x.market.ask.price_unit.to_owned(),
-                            min_total: x.market.ask.min_total.parse().unwrap(),
-                        },
-                        max_total: x.market.max_total.parse().unwrap(),
-                        state: x.market.state.to_owned(),
-                        ask_types: x
-                            .market
-                            .ask_types
-                            .map(|x| x.into_iter().map(|x| x.as_str().into()).collect()),
-                        bid_types: x
-                            .market
-                            .bid_types
-                            .map(|x| x.into_iter().map(|x| x.as_str().into()).collect()),
-                    },
-                    bid_account: AccountsInfo {
-                        currency: x.bid_account.currency(),
-                        balance: x.bid_account.balance(),
-                        locked: x.bid_account.locked(),
-                        avg_buy_price: x.bid_account.avg_buy_price(),
-                        avg_buy_price_modified: x.bid_account.avg_buy_price_modified(),
-                        unit_currency: x.bid_account.unit_currency(),
-                    },
-                    ask_account: AccountsInfo {
-                        currency: x.ask_account.currency(),
-                        balance: x.ask_account.balance(),
-                        locked: x.ask_account.locked(),
-                        avg_buy_price: x.ask_account.avg_buy_price(),
-                        avg_buy_price_modified: x.ask_account.avg_buy_price_modified(),
-                        unit_currency: x.ask_account.unit_currency(),
-                    },
-                    maker_bid_fee: x.maker_bid_fee.parse().unwrap(),
-                    maker_ask_fee: x.maker_ask_fee.parse().unwrap(),
-                }
-            })
-            .map_err(crate::response::response_error_from_json)
+    pub async fn get_order_chance(
+        client: &UpbitClient,
+        market_id: &str,
+    ) -> Result<Self, ResponseError> {
+        let res = Self::request(client, market_id).await?;
+        let res_serialized = crate::response::response_text(res).await?;
+
+        let x = serde_json::from_str::<OrderChanceSource>(&res_serialized)
+            .map_err(crate::response::response_error_from_json)?;
+
+        Ok(Self {
+            bid_fee: crate::number::from_wire(&x.bid_fee)
+                .map_err(crate::response::response_error_internal_json_parse_error)?,
+            ask_fee: crate::number::from_wire(&x.ask_fee)
+                .map_err(crate::response::response_error_internal_json_parse_error)?,
+            market: ObjectMarket {
+                id: x.market.id.to_owned(),
+                name: x.market.name.to_owned(),
+                // order_types: x.market.order_types.into_iter().map(|x|x.as_str().into()).collect(),
+                order_sides: x
+                    .market
+                    .order_sides
+                    .into_iter()
+                    .map(|x| OrderSide::parse_or_unknown(&x))
+                    .collect(),
+                bid: ObjectAskBid {
+                    currency: x.market.bid.currency.to_owned(),
+                    price_unit: x.market.bid.price_unit.to_owned(),
+                    min_total: crate::number::from_wire(&x.market.bid.min_total)
+                        .map_err(crate::response::response_error_internal_json_parse_error)?,
+                },
+                ask: ObjectAskBid {
+                    currency: x.market.ask.currency.to_owned(),
+                    price_unit: x.market.ask.price_unit.to_owned(),
+                    min_total: crate::number::from_wire(&x.market.ask.min_total)
+                        .map_err(crate::response::response_error_internal_json_parse_error)?,
+                },
+                max_total: crate::number::from_wire(&x.market.max_total)
+                    .map_err(crate::response::response_error_internal_json_parse_error)?,
+                state: x.market.state.to_owned(),
+                ask_types: x
+                    .market
+                    .ask_types
+                    .map(|x| x.iter().map(|x| AskType::parse_or_unknown(x)).collect()),
+                bid_types: x
+                    .market
+                    .bid_types
+                    .map(|x| x.iter().map(|x| BidType::parse_or_unknown(x)).collect()),
+            },
+            bid_account: AccountsInfo {
+                currency: x.bid_account.currency(),
+                balance: x.bid_account.balance(),
+                locked: x.bid_account.locked(),
+                avg_buy_price: x.bid_account.avg_buy_price(),
+                avg_buy_price_modified: x.bid_account.avg_buy_price_modified(),
+                unit_currency: x.bid_account.unit_currency(),
+            },
+            ask_account: AccountsInfo {
+                currency: x.ask_account.currency(),
+                balance: x.ask_account.balance(),
+                locked: x.ask_account.locked(),
+                avg_buy_price: x.ask_account.avg_buy_price(),
+                avg_buy_price_modified: x.ask_account.avg_buy_price_modified(),
+                unit_currency: x.ask_account.unit_currency(),
+            },
+            maker_bid_fee: crate::number::from_wire(&x.maker_bid_fee)
+                .map_err(crate::response::response_error_internal_json_parse_error)?,
+            maker_ask_fee: crate::number::from_wire(&x.maker_ask_fee)
+                .map_err(crate::response::response_error_internal_json_parse_error)?,
+        })
     }
 
-    async fn request(market_id: &str) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER_CHANCE}"))
+    async fn request(client: &UpbitClient, market_id: &str) -> Result<Response, ResponseError> {
+        let mut url = Url::parse(&format!("{}{URL_ORDER_CHANCE}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
         url.query_pairs_mut().append_pair("market", market_id);
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
+        let token_string = client.set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .get(url)
-            .header(ACCEPT, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        client
+            .send(
+                client
+                    .http
+                    .get(url)
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -112,10 +115,12 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_order_chance() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
-        let res = OrderChance::request("KRW-ETH").await.unwrap();
+        let res = OrderChance::request(&client, "KRW-ETH").await.unwrap();
         let res_serialized = res
             .text()
             .await
@@ -123,7 +128,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -178,20 +183,16 @@ mod tests {
         let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
 
         if !missing_keys.is_empty() {
-            println!("[test_get_order_chance] Missing keys: {:?}", missing_keys);
-            assert!(false);
+            panic!("[test_get_order_chance] Missing keys: {:?}", missing_keys);
         } else {
             println!("[test_get_order_chance] No keys are missing");
         }
 
         if !extra_keys.is_empty() {
-            println!("[test_get_order_chance] Extra keys: {:?}", extra_keys);
-            assert!(false);
+            panic!("[test_get_order_chance] Extra keys: {:?}", extra_keys);
         } else {
             println!("[test_get_order_chance] No extra keys found.");
         }
-
-        assert!(true);
     }
 
     fn compare_keys(
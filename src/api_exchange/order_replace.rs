@@ -0,0 +1,330 @@
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use reqwest::{Response, Url};
+
+use super::order_request::{validate_positive, validate_time_in_force};
+use super::{OrderCondition, OrderId, OrderRequestError, OrderType, SmpType};
+use crate::client::UpbitClient;
+use crate::constant::URL_ORDER_CANCEL_AND_NEW;
+use crate::number::Number;
+use crate::response::{OrderReplacement, OrderReplacementSource, ResponseError};
+
+/// Volume of the order placed by [`super::cancel_and_new_order`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplaceVolume {
+    Volume(Number),
+    /// what was left unfilled of the cancelled order
+    RemainOnly,
+}
+
+/// Order to cancel and the order to place in its stead with [`super::cancel_and_new_order`]
+///
+/// The new order keeps the market and side of the cancelled one, so only its type and amounts are
+/// given. Upbit cancels and places in one request, leaving no moment with both orders or neither
+/// in the book.
+///
+/// # Example
+/// ```
+/// use upbit::api_exchange::{OrderId, ReplaceOrderRequest, ReplaceVolume};
+/// use upbit::Number;
+///
+/// let prev = OrderId::Uuid("cdd92199-2897-4e14-9448-f923320408ad".to_owned());
+/// let request = ReplaceOrderRequest::limit(prev, Number::from(1_436_000), ReplaceVolume::RemainOnly)
+///     .with_identifier("my-order-2");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplaceOrderRequest {
+    prev: OrderId,
+    ord_type: OrderType,
+    price: Option<Number>,
+    volume: Option<ReplaceVolume>,
+    identifier: Option<String>,
+    time_in_force: Option<OrderCondition>,
+    smp_type: Option<SmpType>,
+}
+
+impl ReplaceOrderRequest {
+    fn new(
+        prev: OrderId,
+        ord_type: OrderType,
+        price: Option<Number>,
+        volume: Option<ReplaceVolume>,
+    ) -> Self {
+        Self {
+            prev,
+            ord_type,
+            price,
+            volume,
+            identifier: None,
+            time_in_force: None,
+            smp_type: None,
+        }
+    }
+
+    /// 지정가 주문. `volume` at `price`
+    pub fn limit(prev: OrderId, price: Number, volume: ReplaceVolume) -> Self {
+        Self::new(prev, OrderType::Limit, Some(price), Some(volume))
+    }
+
+    /// 시장가 매수. spend `funds` of the quote currency
+    pub fn market_bid(prev: OrderId, funds: Number) -> Self {
+        Self::new(prev, OrderType::Price, Some(funds), None)
+    }
+
+    /// 시장가 매도. sell `volume`
+    pub fn market_ask(prev: OrderId, volume: ReplaceVolume) -> Self {
+        Self::new(prev, OrderType::Market, None, Some(volume))
+    }
+
+    /// 최유리 매수. spend `funds` at the best ask, with `time_in_force` ioc or fok
+    pub fn best_bid(prev: OrderId, funds: Number, time_in_force: OrderCondition) -> Self {
+        Self::new(prev, OrderType::Best, Some(funds), None).with_time_in_force(time_in_force)
+    }
+
+    /// 최유리 매도. sell `volume` at the best bid, with `time_in_force` ioc or fok
+    pub fn best_ask(prev: OrderId, volume: ReplaceVolume, time_in_force: OrderCondition) -> Self {
+        Self::new(prev, OrderType::Best, None, Some(volume)).with_time_in_force(time_in_force)
+    }
+
+    /// Tag the new order with an id of your own, unique per account
+    pub fn with_identifier(mut self, identifier: &str) -> Self {
+        self.identifier = Some(identifier.to_owned());
+        self
+    }
+
+    /// Set the order condition of the new order
+    pub fn with_time_in_force(mut self, time_in_force: OrderCondition) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    /// Set what happens when the new order would fill against an order of the same account
+    pub fn with_smp_type(mut self, smp_type: SmpType) -> Self {
+        self.smp_type = Some(smp_type);
+        self
+    }
+
+    /// Get order to cancel
+    pub fn prev(&self) -> &OrderId {
+        &self.prev
+    }
+
+    pub fn ord_type(&self) -> &OrderType {
+        &self.ord_type
+    }
+
+    /// Get price of a limit order, or funds of a market or best bid
+    pub fn price(&self) -> Option<Number> {
+        self.price
+    }
+
+    /// Get volume of a limit order, or of a market or best ask
+    pub fn volume(&self) -> Option<ReplaceVolume> {
+        self.volume
+    }
+
+    pub fn identifier(&self) -> Option<&str> {
+        self.identifier.as_deref()
+    }
+
+    pub fn time_in_force(&self) -> Option<&OrderCondition> {
+        self.time_in_force.as_ref()
+    }
+
+    pub fn smp_type(&self) -> Option<&SmpType> {
+        self.smp_type.as_ref()
+    }
+
+    /// Check the request is one Upbit accepts, without sending it
+    pub fn validate(&self) -> Result<(), OrderRequestError> {
+        if self.prev.as_str().is_empty() {
+            return Err(OrderRequestError::EmptyOrderId);
+        }
+
+        let volume = match self.volume {
+            Some(ReplaceVolume::Volume(volume)) => Some(volume),
+            _ => None,
+        };
+
+        validate_positive("price", self.price)?;
+        validate_positive("volume", volume)?;
+        validate_time_in_force(&self.ord_type, self.time_in_force.as_ref())
+    }
+
+    /// Get parameters of the request as sent to the API, in the order they are sent
+    pub(crate) fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = vec![
+            match &self.prev {
+                OrderId::Uuid(uuid) => ("prev_order_uuid", uuid.to_owned()),
+                OrderId::Identifier(identifier) => ("prev_order_identifier", identifier.to_owned()),
+            },
+            ("new_ord_type", self.ord_type.to_string()),
+        ];
+
+        if let Some(price) = self.price {
+            pairs.push(("new_price", crate::number::to_wire(price)));
+        }
+        match self.volume {
+            Some(ReplaceVolume::Volume(volume)) => {
+                pairs.push(("new_volume", crate::number::to_wire(volume)))
+            }
+            Some(ReplaceVolume::RemainOnly) => pairs.push(("new_volume", "remain_only".to_owned())),
+            None => {}
+        }
+        if let Some(identifier) = &self.identifier {
+            pairs.push(("new_identifier", identifier.to_owned()));
+        }
+        if let Some(time_in_force) = &self.time_in_force {
+            pairs.push(("new_time_in_force", time_in_force.to_string()));
+        }
+        if let Some(smp_type) = &self.smp_type {
+            pairs.push(("new_smp_type", smp_type.to_string()));
+        }
+
+        pairs
+    }
+}
+
+impl OrderReplacement {
+    pub async fn cancel_and_new_order(
+        client: &UpbitClient,
+        request: &ReplaceOrderRequest,
+    ) -> Result<Self, ResponseError> {
+        request.validate()?;
+
+        // the order test API has no counterpart, and the new order would be placed for real
+        if client.dry_run {
+            return Err(crate::response::response_error_invalid_parameter(
+                "cancel_and_new can't be tested on a dry run client",
+            ));
+        }
+
+        let res = Self::request(client, request).await?;
+        let res_serialized = crate::response::response_text(res).await?;
+
+        serde_json::from_str::<OrderReplacementSource>(&res_serialized)
+            .map(Self::from)
+            .map_err(crate::response::response_error_from_json)
+    }
+
+    async fn request(
+        client: &UpbitClient,
+        request: &ReplaceOrderRequest,
+    ) -> Result<Response, ResponseError> {
+        let mut url = Url::parse(&format!("{}{URL_ORDER_CANCEL_AND_NEW}", client.server_url))
+            .map_err(crate::response::response_error_internal_url_parse_error)?;
+
+        url.query_pairs_mut().extend_pairs(request.query_pairs());
+
+        let token_string = client.set_token_with_query(url.as_str())?;
+
+        client
+            .send(
+                client
+                    .http
+                    .post(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(CONTENT_TYPE, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::ResponseErrorState;
+
+    fn n(value: &str) -> Number {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_replace_order_request_validate() {
+        let prev = OrderId::Identifier("my-order-1".to_owned());
+
+        assert_eq!(
+            ReplaceOrderRequest::limit(prev.clone(), n("100"), ReplaceVolume::RemainOnly)
+                .validate(),
+            Ok(())
+        );
+        assert_eq!(
+            ReplaceOrderRequest::limit(
+                OrderId::Uuid(String::new()),
+                n("100"),
+                ReplaceVolume::RemainOnly
+            )
+            .validate(),
+            Err(OrderRequestError::EmptyOrderId)
+        );
+        assert_eq!(
+            ReplaceOrderRequest::market_ask(prev.clone(), ReplaceVolume::Volume(n("-1")))
+                .validate(),
+            Err(OrderRequestError::NotPositive {
+                field: "volume",
+                value: n("-1"),
+            })
+        );
+        assert!(matches!(
+            ReplaceOrderRequest::best_bid(prev, n("10000"), OrderCondition::PostOnly).validate(),
+            Err(OrderRequestError::InvalidTimeInForce { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_and_new_order() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/orders/cancel_and_new"))
+            .and(query_param(
+                "prev_order_uuid",
+                "cdd92199-2897-4e14-9448-f923320408ad",
+            ))
+            .and(query_param("new_ord_type", "limit"))
+            .and(query_param("new_price", "101"))
+            .and(query_param("new_volume", "remain_only"))
+            .and(query_param("new_identifier", "my-order-2"))
+            .respond_with(ResponseTemplate::new(201).set_body_string(
+                r#"{"uuid":"cdd92199-2897-4e14-9448-f923320408ad","side":"bid","ord_type":"limit","price":"100","state":"wait","market":"KRW-ETH","created_at":"2018-04-10T15:42:23+09:00","volume":"1","remaining_volume":"0.4","reserved_fee":"0.05","remaining_fee":"0.02","paid_fee":"0.03","locked":"40.02","executed_volume":"0.6","trades_count":1,"new_order_uuid":"9ca023a5-851b-4fec-9f0a-48cd83c2eaae","new_order_identifier":"my-order-2"}"#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key").with_server_url(&server.uri());
+        let request = ReplaceOrderRequest::limit(
+            OrderId::Uuid("cdd92199-2897-4e14-9448-f923320408ad".to_owned()),
+            n("101"),
+            ReplaceVolume::RemainOnly,
+        )
+        .with_identifier("my-order-2");
+
+        let replacement = OrderReplacement::cancel_and_new_order(&client, &request)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            replacement.cancelled.uuid,
+            "cdd92199-2897-4e14-9448-f923320408ad"
+        );
+        assert_eq!(replacement.cancelled.remaining_volume, n("0.4"));
+        assert_eq!(
+            replacement.new_order_uuid,
+            "9ca023a5-851b-4fec-9f0a-48cd83c2eaae"
+        );
+        assert_eq!(
+            replacement.new_order_identifier.as_deref(),
+            Some("my-order-2")
+        );
+
+        // never sent by a dry run client
+        let error = OrderReplacement::cancel_and_new_order(&client.with_dry_run(true), &request)
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.state, ResponseErrorState::InvalidParameter);
+    }
+}
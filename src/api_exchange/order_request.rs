@@ -0,0 +1,404 @@
+use std::fmt::Display;
+
+use crate::number::Number;
+use crate::response::ResponseError;
+
+use super::{OrderCondition, OrderSide, OrderType, SmpType};
+
+/// Order to submit with [`super::place_order`]
+///
+/// Each constructor takes exactly the amounts its `ord_type` needs, so a market bid with a volume
+/// or a limit order without a price can't be built. What is left to check, such as amounts not
+/// positive or a `time_in_force` the `ord_type` doesn't take, is reported by [`OrderRequest::validate`]
+/// before anything is sent.
+///
+/// # Example
+/// ```
+/// use upbit::api_exchange::{OrderCondition, OrderRequest, OrderSide, SmpType};
+/// use upbit::Number;
+///
+/// let limit = OrderRequest::limit("KRW-BTC", OrderSide::Bid, Number::from(100_000_000), "0.001".parse()?)
+///     .with_time_in_force(OrderCondition::PostOnly)
+///     .with_identifier("my-order-1");
+/// let market_bid = OrderRequest::market_bid("KRW-BTC", Number::from(10_000));
+/// let best_ask = OrderRequest::best_ask("KRW-BTC", "0.001".parse()?, OrderCondition::IOK)
+///     .with_smp_type(SmpType::CancelMaker);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderRequest {
+    market: String,
+    side: OrderSide,
+    ord_type: OrderType,
+    price: Option<Number>,
+    volume: Option<Number>,
+    identifier: Option<String>,
+    time_in_force: Option<OrderCondition>,
+    smp_type: Option<SmpType>,
+}
+
+/// Order placed before, by the uuid Upbit gave it or the identifier it was placed with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderId {
+    Uuid(String),
+    Identifier(String),
+}
+
+impl OrderId {
+    /// Get the uuid or identifier
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Uuid(x) | Self::Identifier(x) => x,
+        }
+    }
+}
+
+/// Reason an [`OrderRequest`] or a [`super::ReplaceOrderRequest`] is rejected before being sent
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderRequestError {
+    /// market id is empty
+    EmptyMarket,
+    /// uuid or identifier of the order to cancel is empty
+    EmptyOrderId,
+    /// side is unknown, or not the side `ord_type` is for
+    InvalidSide {
+        ord_type: OrderType,
+        side: OrderSide,
+    },
+    /// price or volume is zero or negative
+    NotPositive { field: &'static str, value: Number },
+    /// `ord_type` doesn't take this `time_in_force`, or requires one and got none
+    InvalidTimeInForce {
+        ord_type: OrderType,
+        time_in_force: Option<OrderCondition>,
+    },
+}
+
+impl Display for OrderRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyMarket => write!(f, "market id is empty"),
+            Self::EmptyOrderId => write!(f, "uuid or identifier of the order is empty"),
+            Self::InvalidSide { ord_type, side } => {
+                write!(f, "ord_type {ord_type} can't be placed on side {side}")
+            }
+            Self::NotPositive { field, value } => write!(f, "{field} must be positive: {value}"),
+            Self::InvalidTimeInForce {
+                ord_type,
+                time_in_force: Some(time_in_force),
+            } => write!(
+                f,
+                "ord_type {ord_type} doesn't take time_in_force {time_in_force}"
+            ),
+            Self::InvalidTimeInForce {
+                ord_type,
+                time_in_force: None,
+            } => write!(f, "ord_type {ord_type} requires time_in_force ioc or fok"),
+        }
+    }
+}
+
+impl std::error::Error for OrderRequestError {}
+
+impl From<OrderRequestError> for ResponseError {
+    fn from(error: OrderRequestError) -> Self {
+        crate::response::response_error_invalid_parameter(error)
+    }
+}
+
+impl OrderRequest {
+    fn new(
+        market_id: &str,
+        side: OrderSide,
+        ord_type: OrderType,
+        price: Option<Number>,
+        volume: Option<Number>,
+    ) -> Self {
+        Self {
+            market: market_id.to_owned(),
+            side,
+            ord_type,
+            price,
+            volume,
+            identifier: None,
+            time_in_force: None,
+            smp_type: None,
+        }
+    }
+
+    /// 지정가 주문. buy or sell `volume` at `price`
+    pub fn limit(market_id: &str, side: OrderSide, price: Number, volume: Number) -> Self {
+        Self::new(market_id, side, OrderType::Limit, Some(price), Some(volume))
+    }
+
+    /// 시장가 매수. spend `funds` of the quote currency
+    pub fn market_bid(market_id: &str, funds: Number) -> Self {
+        Self::new(
+            market_id,
+            OrderSide::Bid,
+            OrderType::Price,
+            Some(funds),
+            None,
+        )
+    }
+
+    /// 시장가 매도. sell `volume`
+    pub fn market_ask(market_id: &str, volume: Number) -> Self {
+        Self::new(
+            market_id,
+            OrderSide::Ask,
+            OrderType::Market,
+            None,
+            Some(volume),
+        )
+    }
+
+    /// 최유리 매수. spend `funds` at the best ask, with `time_in_force` ioc or fok
+    pub fn best_bid(market_id: &str, funds: Number, time_in_force: OrderCondition) -> Self {
+        Self::new(
+            market_id,
+            OrderSide::Bid,
+            OrderType::Best,
+            Some(funds),
+            None,
+        )
+        .with_time_in_force(time_in_force)
+    }
+
+    /// 최유리 매도. sell `volume` at the best bid, with `time_in_force` ioc or fok
+    pub fn best_ask(market_id: &str, volume: Number, time_in_force: OrderCondition) -> Self {
+        Self::new(
+            market_id,
+            OrderSide::Ask,
+            OrderType::Best,
+            None,
+            Some(volume),
+        )
+        .with_time_in_force(time_in_force)
+    }
+
+    /// Tag the order with an id of your own, unique per account
+    pub fn with_identifier(mut self, identifier: &str) -> Self {
+        self.identifier = Some(identifier.to_owned());
+        self
+    }
+
+    /// Set the order condition. limit orders take ioc, fok or post_only, best orders ioc or fok
+    pub fn with_time_in_force(mut self, time_in_force: OrderCondition) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    /// Set what happens when the order would fill against an order of the same account
+    pub fn with_smp_type(mut self, smp_type: SmpType) -> Self {
+        self.smp_type = Some(smp_type);
+        self
+    }
+
+    /// Replace price and volume, keeping the rest of the order
+    pub(crate) fn with_amounts(mut self, price: Option<Number>, volume: Option<Number>) -> Self {
+        self.price = price;
+        self.volume = volume;
+        self
+    }
+
+    pub fn market(&self) -> &str {
+        &self.market
+    }
+
+    pub fn side(&self) -> &OrderSide {
+        &self.side
+    }
+
+    pub fn ord_type(&self) -> &OrderType {
+        &self.ord_type
+    }
+
+    /// Get price of a limit order, or funds of a market or best bid
+    pub fn price(&self) -> Option<Number> {
+        self.price
+    }
+
+    /// Get volume of a limit order, or of a market or best ask
+    pub fn volume(&self) -> Option<Number> {
+        self.volume
+    }
+
+    pub fn identifier(&self) -> Option<&str> {
+        self.identifier.as_deref()
+    }
+
+    pub fn time_in_force(&self) -> Option<&OrderCondition> {
+        self.time_in_force.as_ref()
+    }
+
+    pub fn smp_type(&self) -> Option<&SmpType> {
+        self.smp_type.as_ref()
+    }
+
+    /// Check the request is one Upbit accepts, without sending it
+    pub fn validate(&self) -> Result<(), OrderRequestError> {
+        if self.market.is_empty() {
+            return Err(OrderRequestError::EmptyMarket);
+        }
+
+        let is_side_valid = match (&self.ord_type, &self.side) {
+            (_, OrderSide::Unknown(_)) => false,
+            (OrderType::Price, side) => *side == OrderSide::Bid,
+            (OrderType::Market, side) => *side == OrderSide::Ask,
+            _ => true,
+        };
+
+        if !is_side_valid {
+            return Err(OrderRequestError::InvalidSide {
+                ord_type: self.ord_type.clone(),
+                side: self.side.clone(),
+            });
+        }
+
+        validate_positive("price", self.price)?;
+        validate_positive("volume", self.volume)?;
+        validate_time_in_force(&self.ord_type, self.time_in_force.as_ref())
+    }
+
+    /// Get parameters of the order as sent to the API, in the order they are sent
+    pub(crate) fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = vec![
+            ("market", self.market.to_owned()),
+            ("side", self.side.to_string()),
+            ("ord_type", self.ord_type.to_string()),
+        ];
+
+        if let Some(price) = self.price {
+            pairs.push(("price", crate::number::to_wire(price)));
+        }
+        if let Some(volume) = self.volume {
+            pairs.push(("volume", crate::number::to_wire(volume)));
+        }
+        if let Some(identifier) = &self.identifier {
+            pairs.push(("identifier", identifier.to_owned()));
+        }
+        if let Some(time_in_force) = &self.time_in_force {
+            pairs.push(("time_in_force", time_in_force.to_string()));
+        }
+        if let Some(smp_type) = &self.smp_type {
+            pairs.push(("smp_type", smp_type.to_string()));
+        }
+
+        pairs
+    }
+}
+
+/// Check an amount of an order, if given, is positive
+pub(crate) fn validate_positive(
+    field: &'static str,
+    value: Option<Number>,
+) -> Result<(), OrderRequestError> {
+    match value.filter(|x| *x <= Number::default()) {
+        Some(value) => Err(OrderRequestError::NotPositive { field, value }),
+        None => Ok(()),
+    }
+}
+
+/// Check `ord_type` takes `time_in_force`
+pub(crate) fn validate_time_in_force(
+    ord_type: &OrderType,
+    time_in_force: Option<&OrderCondition>,
+) -> Result<(), OrderRequestError> {
+    let is_valid = match (ord_type, time_in_force) {
+        (OrderType::Limit, _) => true,
+        (OrderType::Best, Some(x)) => *x != OrderCondition::PostOnly,
+        (_, None) => !matches!(ord_type, OrderType::Best),
+        _ => false,
+    };
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(OrderRequestError::InvalidTimeInForce {
+            ord_type: ord_type.clone(),
+            time_in_force: time_in_force.cloned(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn n(value: &str) -> Number {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_order_request_validate() {
+        assert_eq!(
+            OrderRequest::limit("KRW-BTC", OrderSide::Bid, n("100000000"), n("0.001"))
+                .with_time_in_force(OrderCondition::PostOnly)
+                .validate(),
+            Ok(())
+        );
+        assert_eq!(
+            OrderRequest::best_bid("KRW-BTC", n("10000"), OrderCondition::FOK).validate(),
+            Ok(())
+        );
+        assert_eq!(
+            OrderRequest::limit("", OrderSide::Bid, n("1"), n("1")).validate(),
+            Err(OrderRequestError::EmptyMarket)
+        );
+        assert_eq!(
+            OrderRequest::limit("KRW-BTC", OrderSide::Ask, n("100000000"), n("0")).validate(),
+            Err(OrderRequestError::NotPositive {
+                field: "volume",
+                value: n("0"),
+            })
+        );
+        assert_eq!(
+            OrderRequest::market_bid("KRW-BTC", n("10000"))
+                .with_time_in_force(OrderCondition::IOK)
+                .validate(),
+            Err(OrderRequestError::InvalidTimeInForce {
+                ord_type: OrderType::Price,
+                time_in_force: Some(OrderCondition::IOK),
+            })
+        );
+        assert_eq!(
+            OrderRequest::best_ask("KRW-BTC", n("0.1"), OrderCondition::PostOnly).validate(),
+            Err(OrderRequestError::InvalidTimeInForce {
+                ord_type: OrderType::Best,
+                time_in_force: Some(OrderCondition::PostOnly),
+            })
+        );
+        assert!(matches!(
+            OrderRequest::limit(
+                "KRW-BTC",
+                OrderSide::Unknown("x".to_owned()),
+                n("1"),
+                n("1")
+            )
+            .validate(),
+            Err(OrderRequestError::InvalidSide { .. })
+        ));
+    }
+
+    #[test]
+    fn test_order_request_query_pairs() {
+        let pairs = OrderRequest::best_ask("KRW-BTC", n("0.3"), OrderCondition::IOK)
+            .with_identifier("my-order-1")
+            .with_smp_type(SmpType::Reduce)
+            .query_pairs();
+
+        assert_eq!(
+            pairs,
+            [
+                ("market", "KRW-BTC".to_owned()),
+                ("side", "ask".to_owned()),
+                ("ord_type", "best".to_owned()),
+                ("volume", "0.3".to_owned()),
+                ("identifier", "my-order-1".to_owned()),
+                ("time_in_force", "ioc".to_owned()),
+                ("smp_type", "reduce".to_owned()),
+            ]
+        );
+    }
+}
@@ -4,76 +4,70 @@ use reqwest::{Response, Url};
 use crate::request::RequestWithQuery;
 
 use super::{
-    super::constant::{URL_ORDER_STATUS, URL_SERVER},
+    super::constant::URL_ORDER_STATUS,
     super::response::{ObjectTrades, OrderInfo, OrderStatus, OrderStatusSource, ResponseError},
 };
+use crate::client::UpbitClient;
 
 impl RequestWithQuery for OrderStatus {}
 impl OrderStatus {
-    pub async fn get_order_status_by_uuid(uuid: &str) -> Result<Self, ResponseError> {
-        let res = Self::request_by_uuid(uuid).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+    pub async fn get_order_status_by_uuid(
+        client: &UpbitClient,
+        uuid: &str,
+    ) -> Result<Self, ResponseError> {
+        let res = Self::request_by_uuid(client, uuid).await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         Self::deserialize_order_status(&res_serialized)
     }
 
-    pub async fn get_order_status_by_identifier(identifier: &str) -> Result<Self, ResponseError> {
-        let res = Self::request_by_identifier(identifier).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+    pub async fn get_order_status_by_identifier(
+        client: &UpbitClient,
+        identifier: &str,
+    ) -> Result<Self, ResponseError> {
+        let res = Self::request_by_identifier(client, identifier).await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         Self::deserialize_order_status(&res_serialized)
     }
 
-    async fn request_by_uuid(uuid: &str) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER_STATUS}"))
+    async fn request_by_uuid(client: &UpbitClient, uuid: &str) -> Result<Response, ResponseError> {
+        let mut url = Url::parse(&format!("{}{URL_ORDER_STATUS}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
         url.query_pairs_mut().append_pair("uuid", uuid);
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
+        let token_string = client.set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        client
+            .send(
+                client
+                    .http
+                    .get(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
-    async fn request_by_identifier(identifier: &str) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER_STATUS}"))
+    async fn request_by_identifier(
+        client: &UpbitClient,
+        identifier: &str,
+    ) -> Result<Response, ResponseError> {
+        let mut url = Url::parse(&format!("{}{URL_ORDER_STATUS}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
         url.query_pairs_mut().append_pair("identifier", identifier);
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
+        let token_string = client.set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        client
+            .send(
+                client
+                    .http
+                    .get(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     fn deserialize_order_status(res_serialized: &str) -> Result<Self, ResponseError> {
@@ -104,9 +98,9 @@ impl OrderStatus {
                     .map(|object_trades| ObjectTrades {
                         market: object_trades.market,
                         uuid: object_trades.uuid,
-                        price: object_trades.price.parse().unwrap_or(0.0),
-                        volume: object_trades.volume.parse().unwrap_or(0.0),
-                        funds: object_trades.funds.parse().unwrap_or(0.0),
+                        price: crate::number::from_wire(&object_trades.price).unwrap_or_default(),
+                        volume: crate::number::from_wire(&object_trades.volume).unwrap_or_default(),
+                        funds: crate::number::from_wire(&object_trades.funds).unwrap_or_default(),
                         side: object_trades.side,
                         created_at: object_trades.created_at,
                     })
@@ -123,17 +117,20 @@ mod tests {
     use crate::api_exchange::{OrderSide, OrderType};
 
     use super::*;
+    use crate::number::Number;
 
     use serde_json::Value;
 
     #[tokio::test]
     async fn test_get_order_status_by_uuid() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
         let uuid = order_to_get_uuid().await;
 
-        let res = OrderStatus::request_by_uuid(&uuid).await.unwrap();
+        let res = OrderStatus::request_by_uuid(&client, &uuid).await.unwrap();
         let res_serialized = res
             .text()
             .await
@@ -141,7 +138,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -186,36 +183,34 @@ mod tests {
         let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
 
         if !missing_keys.is_empty() {
-            println!(
+            panic!(
                 "[test_get_order_status_by_uuid] Missing keys: {:?}",
                 missing_keys
             );
-            assert!(false);
         } else {
             println!("[test_get_order_status_by_uuid] No keys are missing");
         }
 
         if !extra_keys.is_empty() {
-            println!(
+            panic!(
                 "[test_get_order_status_by_uuid] Extra keys: {:?}",
                 extra_keys
             );
-            assert!(false);
         } else {
             println!("[test_get_order_status_by_uuid] No extra keys found.");
         }
-
-        assert!(true);
     }
 
     #[tokio::test]
     async fn test_get_order_status_by_identifier() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
         let identifier = order_to_get_identifier().await;
 
-        let res = OrderStatus::request_by_identifier(&identifier)
+        let res = OrderStatus::request_by_identifier(&client, &identifier)
             .await
             .unwrap();
         let res_serialized = res
@@ -225,7 +220,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -270,44 +265,43 @@ mod tests {
         let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
 
         if !missing_keys.is_empty() {
-            println!(
+            panic!(
                 "[test_get_order_status_by_identifier] Missing keys: {:?}",
                 missing_keys
             );
-            assert!(false);
         } else {
             println!("[test_get_order_status_by_identifier] No keys are missing");
         }
 
         if !extra_keys.is_empty() {
-            println!(
+            panic!(
                 "[test_get_order_status_by_identifier] Extra keys: {:?}",
                 extra_keys
             );
-            assert!(false);
         } else {
             println!("[test_get_order_status_by_identifier] No extra keys found.");
         }
-
-        assert!(true);
     }
 
     async fn order_to_get_uuid() -> String {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let price = 5000.0;
-        let price_desired = 1_435_085.0;
-
-        let res = super::super::order_by_price(
-            "KRW-ETH",
-            OrderSide::Bid,
-            price,
-            price_desired,
-            OrderType::Limit,
-            None,
-        )
-        .await;
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
+
+        let price = Number::from(5000);
+        let price_desired = Number::from(1_435_085);
+
+        let res = client
+            .order_by_price(
+                "KRW-ETH",
+                OrderSide::Bid,
+                price,
+                price_desired,
+                OrderType::Limit,
+                None,
+            )
+            .await;
 
         if let Ok(res) = res {
             res.uuid
@@ -317,24 +311,27 @@ mod tests {
     }
 
     async fn order_to_get_identifier() -> String {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
-        let price = 5000.0;
-        let price_desired = 1_435_085.0;
+        let price = Number::from(5000);
+        let price_desired = Number::from(1_435_085);
         let identifier = "test_identifier4".to_string();
 
-        let res = super::super::order_by_price(
-            "KRW-ETH",
-            OrderSide::Bid,
-            price,
-            price_desired,
-            OrderType::Limit,
-            Some(&identifier),
-        )
-        .await;
-
-        if let Ok(_) = res {
+        let res = client
+            .order_by_price(
+                "KRW-ETH",
+                OrderSide::Bid,
+                price,
+                price_desired,
+                OrderType::Limit,
+                Some(&identifier),
+            )
+            .await;
+
+        if res.is_ok() {
             identifier
         } else {
             panic!("Failed to tag identifier from order_by_price")
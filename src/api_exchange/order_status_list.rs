@@ -3,68 +3,50 @@ use reqwest::{
     Response, Url,
 };
 
-use crate::{constant::OrderBy, request::Request};
-use crate::{
-    request::RequestWithQuery,
-    response::{ResponseErrorBody, ResponseErrorState},
-};
+use crate::constant::OrderBy;
+use crate::response::ResponseErrorState;
 
 #[allow(deprecated)]
 use super::{
     super::{
         constant::{
             URL_ORDER_STATUS_BY_UUID, URL_ORDER_STATUS_CLOSED, URL_ORDER_STATUS_LIST,
-            URL_ORDER_STATUS_OPEN, URL_SERVER,
+            URL_ORDER_STATUS_OPEN,
         },
         response::{OrderInfo, OrderInfoSource, ResponseError},
     },
     OrderState,
 };
+use crate::client::UpbitClient;
 
 impl OrderInfo {
     pub async fn get_order_status_by_uuids(
+        client: &UpbitClient,
         market_id: &str,
         uuids: &[&str],
         order_by: OrderBy,
     ) -> Result<Vec<Self>, ResponseError> {
-        let res = Self::request_get_orders_by_uuids(market_id, uuids, order_by).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+        let res = Self::request_get_orders_by_uuids(client, market_id, uuids, order_by).await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         Self::deserialize_order_status_response(&res_serialized)
     }
 
     pub async fn get_order_status_by_identifiers(
+        client: &UpbitClient,
         market_id: &str,
         identifiers: &[&str],
         order_by: OrderBy,
     ) -> Result<Vec<Self>, ResponseError> {
-        let res = Self::request_get_orders_by_identifiers(market_id, identifiers, order_by).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+        let res = Self::request_get_orders_by_identifiers(client, market_id, identifiers, order_by)
+            .await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         Self::deserialize_order_status_response(&res_serialized)
     }
 
     pub async fn get_order_status_opened(
+        client: &UpbitClient,
         market_id: &str,
         states: &[OrderState],
         page: u8,
@@ -75,48 +57,37 @@ impl OrderInfo {
             match state {
                 OrderState::Wait => {}
                 OrderState::Watch => {}
-                _ => return Err(ResponseError {
-                    state: ResponseErrorState::InvalidParameter,
-                    error: ResponseErrorBody {
-                        name: "invalid_parameter".to_string(),
-                        message:
-                            "state argument must be either OrderState::Wait op OrderState::Watch"
-                                .to_string(),
-                    },
-                }),
+                _ => {
+                    return Err(ResponseError::new(
+                        ResponseErrorState::InvalidParameter,
+                        "invalid_parameter".to_string(),
+                        "state argument must be either OrderState::Wait op OrderState::Watch"
+                            .to_string(),
+                    ))
+                }
             }
         }
 
         match limit {
             1..=100 => {}
             _ => {
-                return Err(ResponseError {
-                    state: ResponseErrorState::InvalidParameter,
-                    error: ResponseErrorBody {
-                        name: "invalid_parameter".to_string(),
-                        message: "limit argument must be between 1 and 100".to_string(),
-                    },
-                })
+                return Err(ResponseError::new(
+                    ResponseErrorState::InvalidParameter,
+                    "invalid_parameter".to_string(),
+                    "limit argument must be between 1 and 100".to_string(),
+                ))
             }
         }
 
-        let res = Self::request_get_orders_opened(market_id, states, page, limit, order_by).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+        let res = Self::request_get_orders_opened(client, market_id, states, page, limit, order_by)
+            .await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         Self::deserialize_order_status_response(&res_serialized)
     }
 
     pub async fn get_order_status_closed(
+        client: &UpbitClient,
         market_id: &str,
         states: &[OrderState],
         start_time: Option<&str>,
@@ -125,63 +96,51 @@ impl OrderInfo {
         order_by: OrderBy,
     ) -> Result<Vec<Self>, ResponseError> {
         let res = Self::request_get_orders_closed(
-            market_id, states, start_time, end_time, limit, order_by,
+            client, market_id, states, start_time, end_time, limit, order_by,
         )
         .await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+        let res_serialized = crate::response::response_text(res).await?;
 
         Self::deserialize_order_status_response(&res_serialized)
     }
 
     #[deprecated(since = "1.6.0")]
-    pub async fn get_order_state_list() -> Result<Vec<Self>, ResponseError> {
+    pub async fn get_order_state_list(client: &UpbitClient) -> Result<Vec<Self>, ResponseError> {
         #[allow(deprecated)]
-        let res = Self::request(&format!("{URL_SERVER}{URL_ORDER_STATUS_LIST}")).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+        let res = Self::request(
+            client,
+            &format!("{}{URL_ORDER_STATUS_LIST}", client.server_url),
+        )
+        .await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         Self::deserialize_order_status_response(&res_serialized)
     }
 
     #[deprecated(since = "1.6.0")]
-    async fn request(url: &str) -> Result<Response, ResponseError> {
-        let url = Url::parse(url).unwrap();
-        let token_string = Self::set_token()?;
-
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+    async fn request(client: &UpbitClient, url: &str) -> Result<Response, ResponseError> {
+        let url =
+            Url::parse(url).map_err(crate::response::response_error_internal_url_parse_error)?;
+        let token_string = client.set_token()?;
+
+        client
+            .send(
+                client
+                    .http
+                    .get(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     async fn request_get_orders_by_uuids(
+        client: &UpbitClient,
         market_id: &str,
         uuids: &[&str],
         order_by: OrderBy,
     ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER_STATUS_BY_UUID}"))
+        let mut url = Url::parse(&format!("{}{URL_ORDER_STATUS_BY_UUID}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
         url.query_pairs_mut()
@@ -193,23 +152,26 @@ impl OrderInfo {
         }
 
         let url = url.as_str().replace("&uuids", "&uuids[]");
-        let token_string = Self::set_token_with_query(&url)?;
-
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        let token_string = client.set_token_with_query(&url)?;
+
+        client
+            .send(
+                client
+                    .http
+                    .get(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     async fn request_get_orders_by_identifiers(
+        client: &UpbitClient,
         market_id: &str,
         identifiers: &[&str],
         order_by: OrderBy,
     ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER_STATUS_BY_UUID}"))
+        let mut url = Url::parse(&format!("{}{URL_ORDER_STATUS_BY_UUID}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
         url.query_pairs_mut()
@@ -221,25 +183,28 @@ impl OrderInfo {
         }
 
         let url = url.as_str().replace("identifiers", "identifiers[]");
-        let token_string = Self::set_token_with_query(&url)?;
-
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        let token_string = client.set_token_with_query(&url)?;
+
+        client
+            .send(
+                client
+                    .http
+                    .get(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     async fn request_get_orders_opened(
+        client: &UpbitClient,
         market_id: &str,
         states: &[OrderState],
         page: u8,
         limit: u8,
         order_by: OrderBy,
     ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER_STATUS_OPEN}"))
+        let mut url = Url::parse(&format!("{}{URL_ORDER_STATUS_OPEN}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
         url.query_pairs_mut()
@@ -255,18 +220,21 @@ impl OrderInfo {
         }
 
         let url = url.as_str().replace("states", "states[]");
-        let token_string = Self::set_token_with_query(url.as_str())?;
-
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        let token_string = client.set_token_with_query(url.as_str())?;
+
+        client
+            .send(
+                client
+                    .http
+                    .get(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     async fn request_get_orders_closed(
+        client: &UpbitClient,
         market_id: &str,
         states: &[OrderState],
         start_time: Option<&str>,
@@ -274,7 +242,7 @@ impl OrderInfo {
         limit: u16,
         order_by: OrderBy,
     ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDER_STATUS_CLOSED}"))
+        let mut url = Url::parse(&format!("{}{URL_ORDER_STATUS_CLOSED}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
         url.query_pairs_mut()
@@ -298,15 +266,17 @@ impl OrderInfo {
 
         let url = url.as_str().replace("states", "states[]");
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
+        let token_string = client.set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        client
+            .send(
+                client
+                    .http
+                    .get(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 
     fn deserialize_order_status_response(res_serialized: &str) -> Result<Vec<Self>, ResponseError> {
@@ -346,18 +316,26 @@ mod tests {
 
     use crate::api_exchange::{OrderSide, OrderState, OrderType};
     #[allow(deprecated)]
-    use crate::constant::{OrderBy, URL_ORDER_STATUS_LIST, URL_SERVER};
+    use crate::constant::{OrderBy, URL_ORDER_STATUS_LIST};
     use crate::response::OrderInfo;
 
+    use crate::client::UpbitClient;
+    use crate::number::Number;
+
     #[tokio::test]
     async fn test_get_order_state_list() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
         #[allow(deprecated)]
-        let res = OrderInfo::request(&format!("{URL_SERVER}{URL_ORDER_STATUS_LIST}"))
-            .await
-            .unwrap();
+        let res = OrderInfo::request(
+            &client,
+            &format!("{}{URL_ORDER_STATUS_LIST}", client.server_url),
+        )
+        .await
+        .unwrap();
         let res_serialized = res
             .text()
             .await
@@ -365,7 +343,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -402,11 +380,10 @@ mod tests {
                     compare_keys(item, &expected_structure, &format!("item[{}].", index));
 
                 if !missing_keys.is_empty() {
-                    println!(
+                    panic!(
                         "[test_get_order_state_list] Missing keys in item[{}]: {:?}",
                         index, missing_keys
                     );
-                    assert!(false);
                 } else {
                     println!(
                         "[test_get_order_state_list] No keys are missing in item[{}]",
@@ -415,11 +392,10 @@ mod tests {
                 }
 
                 if !extra_keys.is_empty() {
-                    println!(
+                    panic!(
                         "[test_get_order_state_list] Extra keys in item[{}]: {:?}",
                         index, extra_keys
                     );
-                    assert!(false);
                 } else {
                     println!(
                         "[test_get_order_state_list] No extra keys found in item[{}]",
@@ -428,23 +404,27 @@ mod tests {
                 }
             }
         } else {
-            assert!(false, "Expected an array of objects in the response");
+            panic!("Expected an array of objects in the response");
         }
-
-        assert!(true);
     }
 
     #[tokio::test]
     async fn test_get_orders_by_uuids() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
         let uuid = order_to_get_uuid().await;
 
-        let res =
-            OrderInfo::request_get_orders_by_uuids("KRW-ETH", &[uuid.as_str()], OrderBy::Desc)
-                .await
-                .unwrap();
+        let res = OrderInfo::request_get_orders_by_uuids(
+            &client,
+            "KRW-ETH",
+            &[uuid.as_str()],
+            OrderBy::Desc,
+        )
+        .await
+        .unwrap();
         let res_serialized = res
             .text()
             .await
@@ -452,7 +432,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -489,11 +469,10 @@ mod tests {
                     compare_keys(item, &expected_structure, &format!("item[{}].", index));
 
                 if !missing_keys.is_empty() {
-                    println!(
+                    panic!(
                         "[test_get_orders_by_uuids] Missing keys in item[{}]: {:?}",
                         index, missing_keys
                     );
-                    assert!(false);
                 } else {
                     println!(
                         "[test_get_orders_by_uuids] No keys are missing in item[{}]",
@@ -502,11 +481,10 @@ mod tests {
                 }
 
                 if !extra_keys.is_empty() {
-                    println!(
+                    panic!(
                         "[test_get_orders_by_uuids] Extra keys in item[{}]: {:?}",
                         index, extra_keys
                     );
-                    assert!(false);
                 } else {
                     println!(
                         "[test_get_orders_by_uuids] No extra keys found in item[{}]",
@@ -515,18 +493,19 @@ mod tests {
                 }
             }
         } else {
-            assert!(false, "Expected an array of objects in the response");
+            panic!("Expected an array of objects in the response");
         }
-
-        assert!(true);
     }
 
     #[tokio::test]
     async fn test_get_order_status_opened() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
         let res = OrderInfo::request_get_orders_opened(
+            &client,
             "KRW-ETH",
             &[OrderState::Wait],
             1,
@@ -542,7 +521,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -579,11 +558,10 @@ mod tests {
                     compare_keys(item, &expected_structure, &format!("item[{}].", index));
 
                 if !missing_keys.is_empty() {
-                    println!(
+                    panic!(
                         "[test_get_order_status_opened] Missing keys in item[{}]: {:?}",
                         index, missing_keys
                     );
-                    assert!(false);
                 } else {
                     println!(
                         "[test_get_order_status_opened] No keys are missing in item[{}]",
@@ -592,11 +570,10 @@ mod tests {
                 }
 
                 if !extra_keys.is_empty() {
-                    println!(
+                    panic!(
                         "[test_get_order_status_opened] Extra keys in item[{}]: {:?}",
                         index, extra_keys
                     );
-                    assert!(false);
                 } else {
                     println!(
                         "[test_get_order_status_opened] No extra keys found in item[{}]",
@@ -605,18 +582,19 @@ mod tests {
                 }
             }
         } else {
-            assert!(false, "Expected an array of objects in the response");
+            panic!("Expected an array of objects in the response");
         }
-
-        assert!(true);
     }
 
     #[tokio::test]
     async fn test_get_order_status_closed() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
         let res = OrderInfo::request_get_orders_closed(
+            &client,
             "KRW-ETH",
             &[OrderState::Done],
             None,
@@ -633,7 +611,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -695,7 +673,7 @@ mod tests {
                         "[test_get_order_status_closed] Missing keys in item[{}]: {:?}",
                         index, missing_keys
                     );
-                    assert!(false, "Missing keys found");
+                    panic!("Missing keys found");
                 }
 
                 if !extra_keys.is_empty() {
@@ -703,30 +681,33 @@ mod tests {
                         "[test_get_order_status_closed] Extra keys in item[{}]: {:?}",
                         index, extra_keys
                     );
-                    assert!(false, "Extra keys found");
+                    panic!("Extra keys found");
                 }
             }
         } else {
-            assert!(false, "Expected an array of objects in the response");
+            panic!("Expected an array of objects in the response");
         }
     }
 
     async fn order_to_get_uuid() -> String {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let price = 5000.0;
-        let price_desired = 1_435_085.0;
-
-        let res = super::super::order_by_price(
-            "KRW-ETH",
-            OrderSide::Bid,
-            price,
-            price_desired,
-            OrderType::Limit,
-            None,
-        )
-        .await;
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
+
+        let price = Number::from(5000);
+        let price_desired = Number::from(1_435_085);
+
+        let res = client
+            .order_by_price(
+                "KRW-ETH",
+                OrderSide::Bid,
+                price,
+                price_desired,
+                OrderType::Limit,
+                None,
+            )
+            .await;
 
         if let Ok(res) = res {
             res.uuid
@@ -744,12 +725,12 @@ mod tests {
         let mut extra_keys = Vec::new();
 
         if let Some(actual_map) = json.as_object() {
-            for (key, _) in expected {
+            for key in expected.keys() {
                 if !actual_map.contains_key(*key) {
                     missing_keys.push(format!("{}{}", path, key));
                 }
             }
-            for (key, _) in actual_map {
+            for key in actual_map.keys() {
                 if !expected.contains_key(key.as_str()) {
                     extra_keys.push(format!("{}{}", path, key));
                 }
@@ -0,0 +1,478 @@
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+use crate::client::UpbitClient;
+use crate::number::{self, Number};
+use crate::response::{OrderChance, ResponseError};
+use crate::tick_size::{TickSize, TickSizeError};
+
+use super::{OrderCondition, OrderRequest, OrderRequestError, OrderSide, OrderType};
+
+/// Reason an order would be rejected by Upbit, found before it is sent
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderViolation {
+    /// request is invalid by itself. see [`OrderRequest::validate`]
+    Request(OrderRequestError),
+    /// order chance was fetched for another market
+    MarketMismatch {
+        market: String,
+        order_chance_market: String,
+    },
+    /// market doesn't take orders in its current state
+    MarketNotActive(String),
+    /// market doesn't take orders on this side
+    UnsupportedSide(OrderSide),
+    /// market doesn't take this combination of `ord_type` and `time_in_force`, ex. `"limit_fok"`
+    UnsupportedOrderType(String),
+    /// price is not on a tick of the market. `invalid_price_bid`, `invalid_price_ask`
+    Tick(TickSizeError),
+    /// total is under the minimum of the side. `under_min_total_bid`, `under_min_total_ask`
+    UnderMinTotal { total: Number, min_total: Number },
+    /// total is over the maximum of the market. `over_max_total_bid`, `over_max_total_ask`
+    OverMaxTotal { total: Number, max_total: Number },
+    /// not enough balance, fees included. `insufficient_funds_bid`, `insufficient_funds_ask`
+    InsufficientFunds {
+        currency: String,
+        required: Number,
+        available: Number,
+    },
+}
+
+impl Display for OrderViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Request(e) => write!(f, "{e}"),
+            Self::MarketMismatch {
+                market,
+                order_chance_market,
+            } => write!(
+                f,
+                "order of market {market} checked against order chance of {order_chance_market}"
+            ),
+            Self::MarketNotActive(state) => write!(f, "market is not active: {state}"),
+            Self::UnsupportedSide(side) => write!(f, "market doesn't take side {side}"),
+            Self::UnsupportedOrderType(order_type) => {
+                write!(f, "market doesn't take order type {order_type}")
+            }
+            Self::Tick(e) => write!(f, "{e}"),
+            Self::UnderMinTotal { total, min_total } => {
+                write!(f, "total {total} is under the minimum {min_total}")
+            }
+            Self::OverMaxTotal { total, max_total } => {
+                write!(f, "total {total} is over the maximum {max_total}")
+            }
+            Self::InsufficientFunds {
+                currency,
+                required,
+                available,
+            } => write!(
+                f,
+                "insufficient {currency}: {required} required, {available} available"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for OrderViolation {}
+
+/// Check orders against the [`OrderChance`] of their market before sending them
+///
+/// Catches what Upbit would reject after a round trip: totals out of `min_total` and `max_total`,
+/// prices off the tick, order types the market doesn't take, and balances too low to pay the
+/// order and its fee. [`OrderValidator::adjust`] fixes what can be fixed by rounding the price to
+/// the tick and shrinking the amount to the balance.
+///
+/// A validator can be kept and reused to save a request per order. Balances in it are the ones at
+/// the time it was fetched, so fetch a new one when [`OrderValidator::age`] gets long or after
+/// orders are placed.
+///
+/// # Example
+/// ```no_run
+/// use upbit::api_exchange::{self, OrderRequest, OrderSide};
+/// use upbit::Number;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let validator = api_exchange::get_order_validator("KRW-ETH").await?;
+/// let request = validator.adjust(&OrderRequest::limit("KRW-ETH", OrderSide::Bid, Number::from(1_435_085), "0.01".parse()?));
+///
+/// match validator.check(&request) {
+///     Ok(()) => { api_exchange::place_order(&request).await?; }
+///     Err(violations) => violations.iter().for_each(|x| println!("{x}")),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct OrderValidator {
+    order_chance: OrderChance,
+    tick_size: TickSize,
+    fetched_at: Instant,
+}
+
+impl OrderValidator {
+    /// Validator from an order chance fetched just now
+    pub fn new(order_chance: OrderChance) -> Self {
+        let tick_size = TickSize::from_order_chance(&order_chance)
+            .unwrap_or_else(|_| TickSize::for_market(&order_chance.market.id));
+
+        Self {
+            order_chance,
+            tick_size,
+            fetched_at: Instant::now(),
+        }
+    }
+
+    /// Fetch the order chance of `market_id` and build a validator of it
+    pub async fn fetch(client: &UpbitClient, market_id: &str) -> Result<Self, ResponseError> {
+        Ok(Self::new(client.get_order_chance(market_id).await?))
+    }
+
+    pub fn order_chance(&self) -> &OrderChance {
+        &self.order_chance
+    }
+
+    /// Get time since the order chance was fetched
+    pub fn age(&self) -> Duration {
+        self.fetched_at.elapsed()
+    }
+
+    /// Check `request` could be placed, returning every reason it couldn't
+    pub fn check(&self, request: &OrderRequest) -> Result<(), Vec<OrderViolation>> {
+        if let Err(e) = request.validate() {
+            return Err(vec![OrderViolation::Request(e)]);
+        }
+
+        let market = &self.order_chance.market;
+
+        if request.market() != market.id {
+            return Err(vec![OrderViolation::MarketMismatch {
+                market: request.market().to_owned(),
+                order_chance_market: market.id.to_owned(),
+            }]);
+        }
+
+        let mut violations = Vec::new();
+
+        if market.state != "active" {
+            violations.push(OrderViolation::MarketNotActive(market.state.to_owned()));
+        }
+        if !market.order_sides.is_empty() && !market.order_sides.contains(request.side()) {
+            violations.push(OrderViolation::UnsupportedSide(request.side().clone()));
+        }
+
+        let order_type = order_type_name(request);
+        let supported_types = match request.side() {
+            OrderSide::Bid => market
+                .bid_types
+                .as_ref()
+                .map(|x| x.iter().map(ToString::to_string).collect::<Vec<_>>()),
+            _ => market
+                .ask_types
+                .as_ref()
+                .map(|x| x.iter().map(ToString::to_string).collect::<Vec<_>>()),
+        };
+
+        if supported_types.is_some_and(|x| !x.contains(&order_type)) {
+            violations.push(OrderViolation::UnsupportedOrderType(order_type));
+        }
+
+        if let (OrderType::Limit, Some(price)) = (request.ord_type(), request.price()) {
+            match self.tick_size.validate(price) {
+                Ok(()) | Err(TickSizeError::UnknownMarket(_)) => {}
+                Err(e) => violations.push(OrderViolation::Tick(e)),
+            }
+        }
+
+        if let Some(total) = total(request) {
+            let min_total = match request.side() {
+                OrderSide::Bid => market.bid.min_total,
+                _ => market.ask.min_total,
+            };
+            let max_total = market.max_total;
+
+            if total < min_total {
+                violations.push(OrderViolation::UnderMinTotal { total, min_total });
+            }
+            if max_total > Number::default() && total > max_total {
+                violations.push(OrderViolation::OverMaxTotal { total, max_total });
+            }
+        }
+
+        let (account, required) = match request.side() {
+            OrderSide::Bid => (
+                &self.order_chance.bid_account,
+                total(request).map(|x| x * (Number::from(1) + self.bid_fee(request))),
+            ),
+            _ => (&self.order_chance.ask_account, request.volume()),
+        };
+
+        if let Some(required) = required.filter(|x| *x > account.balance) {
+            violations.push(OrderViolation::InsufficientFunds {
+                currency: account.currency.to_owned(),
+                required,
+                available: account.balance,
+            });
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    /// Move `request` within what the market and balances allow
+    ///
+    /// The price of a limit order is rounded to the tick, down for a bid and up for an ask, so
+    /// the order is never worse than asked. The volume, or the funds of a market or best bid, is
+    /// then cut to what the balance pays for, fee included. Anything else, such as a total under
+    /// `min_total`, is left as is; run [`OrderValidator::check`] on the result.
+    pub fn adjust(&self, request: &OrderRequest) -> OrderRequest {
+        if request.validate().is_err() || request.market() != self.order_chance.market.id {
+            return request.clone();
+        }
+
+        let one = Number::from(1);
+        let bid_balance = self.order_chance.bid_account.balance;
+        let ask_balance = self.order_chance.ask_account.balance;
+        let fee = one + self.bid_fee(request);
+
+        let price = match (request.ord_type(), request.side(), request.price()) {
+            (OrderType::Limit, OrderSide::Bid, Some(price)) => {
+                Some(self.tick_size.round_down(price).unwrap_or(price))
+            }
+            (OrderType::Limit, _, Some(price)) => {
+                Some(self.tick_size.round_up(price).unwrap_or(price))
+            }
+            (_, OrderSide::Bid, Some(funds)) => Some(funds.min(floor_amount(bid_balance / fee))),
+            (_, _, price) => price,
+        };
+
+        let volume = match (request.side(), price, request.volume()) {
+            (OrderSide::Bid, Some(price), Some(volume)) => {
+                Some(volume.min(floor_amount(bid_balance / (price * fee))))
+            }
+            (_, _, Some(volume)) => Some(volume.min(ask_balance)),
+            (_, _, volume) => volume,
+        };
+
+        request.clone().with_amounts(price, volume)
+    }
+
+    /// Fee rate paid by a bid. post only orders always fill as maker
+    fn bid_fee(&self, request: &OrderRequest) -> Number {
+        match request.time_in_force() {
+            Some(OrderCondition::PostOnly) => self.order_chance.maker_bid_fee,
+            _ => self.order_chance.bid_fee,
+        }
+    }
+}
+
+/// Name of the order type as in `bid_types` and `ask_types`, ex. `"limit_ioc"`
+fn order_type_name(request: &OrderRequest) -> String {
+    match (request.ord_type(), request.time_in_force()) {
+        (ord_type, Some(OrderCondition::IOK)) => format!("{ord_type}_ioc"),
+        (ord_type, Some(OrderCondition::FOK)) => format!("{ord_type}_fok"),
+        (ord_type, _) => ord_type.to_string(),
+    }
+}
+
+/// Get amount of the quote currency the order trades, if known before it fills
+fn total(request: &OrderRequest) -> Option<Number> {
+    match (request.price(), request.volume()) {
+        (Some(price), Some(volume)) => Some(price * volume),
+        // funds of a market or best bid
+        (Some(funds), None) => Some(funds),
+        // market or best ask. the price is known only when it fills
+        _ => None,
+    }
+}
+
+/// Round down to 8 decimal places, the finest amount Upbit takes
+fn floor_amount(value: Number) -> Number {
+    let unit = number::from_parts(1, 8);
+
+    (value / unit).floor() * unit
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constant::{AskType, BidType};
+    use crate::response::{AccountsInfo, ObjectAskBid, ObjectMarket};
+
+    fn n(value: &str) -> Number {
+        value.parse().unwrap()
+    }
+
+    fn account(currency: &str, balance: &str) -> AccountsInfo {
+        AccountsInfo {
+            currency: currency.to_owned(),
+            balance: n(balance),
+            locked: Number::default(),
+            avg_buy_price: Number::default(),
+            avg_buy_price_modified: false,
+            unit_currency: "KRW".to_owned(),
+        }
+    }
+
+    fn validator() -> OrderValidator {
+        let side = |currency: &str| ObjectAskBid {
+            currency: currency.to_owned(),
+            price_unit: None,
+            min_total: n("5000"),
+        };
+
+        OrderValidator::new(OrderChance {
+            bid_fee: n("0.0005"),
+            ask_fee: n("0.0005"),
+            market: ObjectMarket {
+                id: "KRW-BTC".to_owned(),
+                name: "BTC/KRW".to_owned(),
+                ask_types: Some(vec![AskType::Limit, AskType::Market, AskType::LimitIOC]),
+                bid_types: Some(vec![BidType::Limit, BidType::Price, BidType::LimitIOC]),
+                order_sides: vec![OrderSide::Ask, OrderSide::Bid],
+                bid: side("KRW"),
+                ask: side("BTC"),
+                max_total: n("1000000000"),
+                state: "active".to_owned(),
+            },
+            bid_account: account("KRW", "100000"),
+            ask_account: account("BTC", "0.01"),
+            maker_bid_fee: n("0.0002"),
+            maker_ask_fee: n("0.0002"),
+        })
+    }
+
+    #[test]
+    fn test_order_validator_check() {
+        let validator = validator();
+
+        assert_eq!(
+            validator.check(&OrderRequest::limit(
+                "KRW-BTC",
+                OrderSide::Bid,
+                n("100000000"),
+                n("0.0005")
+            )),
+            Ok(())
+        );
+        assert_eq!(
+            validator.check(&OrderRequest::limit(
+                "KRW-BTC",
+                OrderSide::Bid,
+                n("100000500"),
+                n("0.00001")
+            )),
+            Err(vec![
+                OrderViolation::Tick(TickSizeError::OffTick {
+                    price: n("100000500"),
+                    tick_size: n("1000"),
+                    lower: n("100000000"),
+                    upper: n("100001000"),
+                }),
+                OrderViolation::UnderMinTotal {
+                    total: n("100000500") * n("0.00001"),
+                    min_total: n("5000"),
+                },
+            ])
+        );
+        // 100000 KRW pays 99950.02 KRW of order with the fee of 0.05%
+        assert_eq!(
+            validator.check(&OrderRequest::market_bid("KRW-BTC", n("100000"))),
+            Err(vec![OrderViolation::InsufficientFunds {
+                currency: "KRW".to_owned(),
+                required: n("100000") * (n("1") + n("0.0005")),
+                available: n("100000"),
+            }])
+        );
+        assert_eq!(
+            validator.check(&OrderRequest::best_ask(
+                "KRW-BTC",
+                n("0.02"),
+                OrderCondition::FOK
+            )),
+            Err(vec![
+                OrderViolation::UnsupportedOrderType("best_fok".to_owned()),
+                OrderViolation::InsufficientFunds {
+                    currency: "BTC".to_owned(),
+                    required: n("0.02"),
+                    available: n("0.01"),
+                },
+            ])
+        );
+        assert!(matches!(
+            validator
+                .check(&OrderRequest::market_ask("KRW-ETH", n("1")))
+                .unwrap_err()[..],
+            [OrderViolation::MarketMismatch { .. }]
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_order_validator_of_btc_market() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // min_total of BTC and USDT markets is a decimal
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/orders/chance"))
+            .and(query_param("market", "BTC-ETH"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"{"bid_fee":"0.0025","ask_fee":"0.0025","maker_bid_fee":"0.0025","maker_ask_fee":"0.0025","market":{"id":"BTC-ETH","name":"ETH/BTC","order_types":[],"order_sides":["ask","bid"],"bid_types":["limit","price"],"ask_types":["limit","market"],"bid":{"currency":"BTC","min_total":"0.00005"},"ask":{"currency":"ETH","min_total":"0.00005"},"max_total":"20.0","state":"active"},"bid_account":{"currency":"BTC","balance":"0.01","locked":"0.0","avg_buy_price":"0","avg_buy_price_modified":false,"unit_currency":"KRW"},"ask_account":{"currency":"ETH","balance":"0.0","locked":"0.0","avg_buy_price":"0","avg_buy_price_modified":false,"unit_currency":"KRW"}}"#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key").with_server_url(&server.uri());
+        let validator = OrderValidator::fetch(&client, "BTC-ETH").await.unwrap();
+
+        assert_eq!(validator.order_chance().market.bid.min_total, n("0.00005"));
+        assert_eq!(validator.order_chance().market.max_total, n("20"));
+        assert!(matches!(
+            validator
+                .check(&OrderRequest::market_bid("BTC-ETH", n("0.00001")))
+                .unwrap_err()[..],
+            [OrderViolation::UnderMinTotal { .. }]
+        ));
+    }
+
+    #[test]
+    fn test_order_validator_adjust() {
+        let validator = validator();
+
+        let bid = validator.adjust(&OrderRequest::limit(
+            "KRW-BTC",
+            OrderSide::Bid,
+            n("100000500"),
+            n("1"),
+        ));
+
+        assert_eq!(bid.price(), Some(n("100000000")));
+        assert_eq!(
+            bid.volume().map(number::to_wire).as_deref(),
+            Some("0.0009995")
+        );
+        assert_eq!(validator.check(&bid), Ok(()));
+
+        let ask = validator.adjust(&OrderRequest::limit(
+            "KRW-BTC",
+            OrderSide::Ask,
+            n("100000500"),
+            n("1"),
+        ));
+
+        assert_eq!(ask.price(), Some(n("100001000")));
+        assert_eq!(ask.volume(), Some(n("0.01")));
+
+        let market_bid = validator.adjust(&OrderRequest::market_bid("KRW-BTC", n("100000")));
+
+        assert_eq!(
+            market_bid.price().map(number::to_wire).as_deref(),
+            Some("99950.0249875")
+        );
+        assert_eq!(validator.check(&market_bid), Ok(()));
+    }
+}
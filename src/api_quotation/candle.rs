@@ -0,0 +1,510 @@
+use chrono::{Duration, NaiveDateTime};
+use reqwest::header::ACCEPT;
+use reqwest::{Response, Url};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::client::UpbitClient;
+use crate::number::Number;
+use crate::response::{ResponseError, ResponseErrorState};
+
+use super::{
+    CandleChartDay, CandleChartMinute, CandleChartMonth, CandleChartSecond, CandleChartWeek,
+    CandleChartYear, CandleMinute, UrlAssociates,
+};
+
+/// Interval of a candle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CandleInterval {
+    /// 초봉
+    Second,
+    /// 분봉
+    Minute(CandleMinute),
+    /// 일봉
+    Day,
+    /// 주봉
+    Week,
+    /// 월봉
+    Month,
+    /// 연봉
+    Year,
+    /// bucket made by [`super::resample_candles`]. not served by the API
+    Resampled(Duration),
+}
+
+impl CandleInterval {
+    /// Get length of the interval. `None` for months and years, whose length varies
+    pub fn duration(&self) -> Option<Duration> {
+        match self {
+            Self::Second => Some(Duration::seconds(1)),
+            Self::Minute(minute) => Some(Duration::minutes(u8::from(*minute).into())),
+            Self::Day => Some(Duration::days(1)),
+            Self::Week => Some(Duration::weeks(1)),
+            Self::Month | Self::Year => None,
+            Self::Resampled(duration) => Some(*duration),
+        }
+    }
+}
+
+impl From<CandleMinute> for CandleInterval {
+    fn from(value: CandleMinute) -> Self {
+        Self::Minute(value)
+    }
+}
+
+impl TryFrom<CandleInterval> for UrlAssociates {
+    type Error = CandleInterval;
+
+    fn try_from(value: CandleInterval) -> Result<Self, Self::Error> {
+        match value {
+            CandleInterval::Second => Ok(Self::UrlCandleSecond),
+            CandleInterval::Minute(minute) => Ok(Self::UrlCandleMinute(minute)),
+            CandleInterval::Day => Ok(Self::UrlCandleDay),
+            CandleInterval::Week => Ok(Self::UrlCandleWeek),
+            CandleInterval::Month => Ok(Self::UrlCandleMonth),
+            CandleInterval::Year => Ok(Self::UrlCandleYear),
+            CandleInterval::Resampled(_) => Err(value),
+        }
+    }
+}
+
+/// Fields only candles of some intervals have
+#[derive(Debug, Clone, PartialEq)]
+pub enum CandleExtra {
+    Second,
+    /// the minute unit is kept in [`Candle::interval`]
+    Minute,
+    Day {
+        prev_closing_price: Number,
+        change_price: Number,
+        change_rate: Number,
+        /// set when requested with `price_unit`
+        converted_trade_price: Option<Number>,
+    },
+    Week {
+        first_day_of_period: String,
+    },
+    Month {
+        first_day_of_period: String,
+    },
+    Year {
+        first_day_of_period: String,
+    },
+    /// extras of the candles merged into a bucket are dropped
+    Resampled,
+}
+
+/// Candle of any interval
+///
+/// Every candle chart type converts into it, so code reading prices and volumes is written once.
+/// Fields specific to the interval are kept in [`Candle::extra`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candle {
+    pub market: String,
+    pub interval: CandleInterval,
+    pub candle_date_time_utc: NaiveDateTime,
+    pub candle_date_time_kst: NaiveDateTime,
+    pub opening_price: Number,
+    pub high_price: Number,
+    pub low_price: Number,
+    pub trade_price: Number,
+    /// time of the last trade of the candle in milliseconds
+    pub timestamp: i64,
+    pub candle_acc_trade_price: Number,
+    pub candle_acc_trade_volume: Number,
+    pub extra: CandleExtra,
+}
+
+impl Candle {
+    pub async fn request_candle(
+        client: &UpbitClient,
+        market_id: &str,
+        interval: CandleInterval,
+        count: i32,
+        to: Option<String>,
+    ) -> Result<Vec<Self>, ResponseError> {
+        Ok(match interval {
+            CandleInterval::Second => {
+                CandleChartSecond::request_candle(client, market_id, count, to)
+                    .await?
+                    .into_iter()
+                    .map(Self::from)
+                    .collect()
+            }
+            CandleInterval::Minute(minute) => {
+                CandleChartMinute::request_candle(client, market_id, to, count, minute)
+                    .await?
+                    .into_iter()
+                    .map(Self::from)
+                    .collect()
+            }
+            CandleInterval::Day => {
+                CandleChartDay::request_candle(client, market_id, count, to, None)
+                    .await?
+                    .into_iter()
+                    .map(Self::from)
+                    .collect()
+            }
+            CandleInterval::Week => CandleChartWeek::request_candle(client, market_id, count, to)
+                .await?
+                .into_iter()
+                .map(Self::from)
+                .collect(),
+            CandleInterval::Month => CandleChartMonth::request_candle(client, market_id, count, to)
+                .await?
+                .into_iter()
+                .map(Self::from)
+                .collect(),
+            CandleInterval::Year => CandleChartYear::request_candle(client, market_id, count, to)
+                .await?
+                .into_iter()
+                .map(Self::from)
+                .collect(),
+            CandleInterval::Resampled(duration) => {
+                return Err(ResponseError::new(
+                    ResponseErrorState::InvalidParameter,
+                    "invalid_parameter",
+                    format!("resampled interval of {duration} is not served by the API"),
+                ))
+            }
+        })
+    }
+
+    /// Get the closing price of the previous day. day candles only
+    pub fn prev_closing_price(&self) -> Option<Number> {
+        match self.extra {
+            CandleExtra::Day {
+                prev_closing_price, ..
+            } => Some(prev_closing_price),
+            _ => None,
+        }
+    }
+
+    /// Get the first day of the period. week, month and year candles only
+    pub fn first_day_of_period(&self) -> Option<&str> {
+        match &self.extra {
+            CandleExtra::Week {
+                first_day_of_period,
+            }
+            | CandleExtra::Month {
+                first_day_of_period,
+            }
+            | CandleExtra::Year {
+                first_day_of_period,
+            } => Some(first_day_of_period),
+            _ => None,
+        }
+    }
+}
+
+impl From<CandleChartSecond> for Candle {
+    fn from(x: CandleChartSecond) -> Self {
+        Self {
+            market: x.market,
+            interval: CandleInterval::Second,
+            candle_date_time_utc: x.candle_date_time_utc,
+            candle_date_time_kst: x.candle_date_time_kst,
+            opening_price: x.opening_price,
+            high_price: x.high_price,
+            low_price: x.low_price,
+            trade_price: x.trade_price,
+            timestamp: x.timestamp,
+            candle_acc_trade_price: x.candle_acc_trade_price,
+            candle_acc_trade_volume: x.candle_acc_trade_volume,
+            extra: CandleExtra::Second,
+        }
+    }
+}
+
+impl From<CandleChartMinute> for Candle {
+    fn from(x: CandleChartMinute) -> Self {
+        Self {
+            interval: CandleInterval::Minute(x.candle_minute()),
+            market: x.market,
+            candle_date_time_utc: x.candle_date_time_utc,
+            candle_date_time_kst: x.candle_date_time_kst,
+            opening_price: x.opening_price,
+            high_price: x.high_price,
+            low_price: x.low_price,
+            trade_price: x.trade_price,
+            timestamp: x.timestamp,
+            candle_acc_trade_price: x.candle_acc_trade_price,
+            candle_acc_trade_volume: x.candle_acc_trade_volume,
+            extra: CandleExtra::Minute,
+        }
+    }
+}
+
+impl From<CandleChartDay> for Candle {
+    fn from(x: CandleChartDay) -> Self {
+        Self {
+            market: x.market,
+            interval: CandleInterval::Day,
+            candle_date_time_utc: x.candle_date_time_utc,
+            candle_date_time_kst: x.candle_date_time_kst,
+            opening_price: x.opening_price,
+            high_price: x.high_price,
+            low_price: x.low_price,
+            trade_price: x.trade_price,
+            timestamp: x.timestamp,
+            candle_acc_trade_price: x.candle_acc_trade_price,
+            candle_acc_trade_volume: x.candle_acc_trade_volume,
+            extra: CandleExtra::Day {
+                prev_closing_price: x.prev_closing_price,
+                change_price: x.change_price,
+                change_rate: x.change_rate,
+                converted_trade_price: x.converted_trade_price,
+            },
+        }
+    }
+}
+
+impl From<CandleChartWeek> for Candle {
+    fn from(x: CandleChartWeek) -> Self {
+        Self {
+            market: x.market,
+            interval: CandleInterval::Week,
+            candle_date_time_utc: x.candle_date_time_utc,
+            candle_date_time_kst: x.candle_date_time_kst,
+            opening_price: x.opening_price,
+            high_price: x.high_price,
+            low_price: x.low_price,
+            trade_price: x.trade_price,
+            timestamp: x.timestamp,
+            candle_acc_trade_price: x.candle_acc_trade_price,
+            candle_acc_trade_volume: x.candle_acc_trade_volume,
+            extra: CandleExtra::Week {
+                first_day_of_period: x.first_day_of_period,
+            },
+        }
+    }
+}
+
+impl From<CandleChartMonth> for Candle {
+    fn from(x: CandleChartMonth) -> Self {
+        Self {
+            market: x.market,
+            interval: CandleInterval::Month,
+            candle_date_time_utc: x.candle_date_time_utc,
+            candle_date_time_kst: x.candle_date_time_kst,
+            opening_price: x.opening_price,
+            high_price: x.high_price,
+            low_price: x.low_price,
+            trade_price: x.trade_price,
+            timestamp: x.timestamp,
+            candle_acc_trade_price: x.candle_acc_trade_price,
+            candle_acc_trade_volume: x.candle_acc_trade_volume,
+            extra: CandleExtra::Month {
+                first_day_of_period: x.first_day_of_period,
+            },
+        }
+    }
+}
+
+impl From<CandleChartYear> for Candle {
+    fn from(x: CandleChartYear) -> Self {
+        Self {
+            market: x.market,
+            interval: CandleInterval::Year,
+            candle_date_time_utc: x.candle_date_time_utc,
+            candle_date_time_kst: x.candle_date_time_kst,
+            opening_price: x.opening_price,
+            high_price: x.high_price,
+            low_price: x.low_price,
+            trade_price: x.trade_price,
+            timestamp: x.timestamp,
+            candle_acc_trade_price: x.candle_acc_trade_price,
+            candle_acc_trade_volume: x.candle_acc_trade_volume,
+            extra: CandleExtra::Year {
+                first_day_of_period: x.first_day_of_period,
+            },
+        }
+    }
+}
+
+/// Fields every candle API returns, followed by those of the interval in `extra`
+#[derive(Deserialize)]
+pub struct CandleSource<E> {
+    pub(crate) market: String,
+    pub(crate) candle_date_time_utc: String,
+    pub(crate) candle_date_time_kst: String,
+    pub(crate) opening_price: Number,
+    pub(crate) high_price: Number,
+    pub(crate) low_price: Number,
+    pub(crate) trade_price: Number,
+    pub(crate) timestamp: i64,
+    pub(crate) candle_acc_trade_price: Number,
+    pub(crate) candle_acc_trade_volume: Number,
+    #[serde(flatten)]
+    pub(crate) extra: E,
+}
+
+/// Fields only week, month and year candles have
+#[derive(Deserialize)]
+pub struct CandlePeriodExtraSource {
+    pub(crate) first_day_of_period: String,
+}
+
+/// Request up to `count` candles of the endpoint `url` starting before `to`, latest first
+///
+/// `query` holds the parameters only the endpoint takes.
+pub(crate) async fn request_candle_page(
+    client: &UpbitClient,
+    url: UrlAssociates,
+    market_id: &str,
+    count: i32,
+    to: Option<String>,
+    query: &[(&str, &str)],
+) -> Result<Response, ResponseError> {
+    let mut url = Url::parse(&url.to_url(&client.server_url))
+        .map_err(crate::response::response_error_internal_url_parse_error)?;
+
+    url.query_pairs_mut()
+        .append_pair("market", market_id)
+        .append_pair("count", count.to_string().as_str());
+
+    if let Some(to) = to {
+        url.query_pairs_mut().append_pair("to", to.as_str());
+    }
+    url.query_pairs_mut().extend_pairs(query);
+
+    client
+        .send(
+            client
+                .http
+                .get(url.as_str())
+                .header(ACCEPT, "application/json"),
+        )
+        .await
+}
+
+/// Request a page of candles as [`request_candle_page`] does and `build` a chart type of each
+pub(crate) async fn request_candles<E: DeserializeOwned, T>(
+    client: &UpbitClient,
+    url: UrlAssociates,
+    market_id: &str,
+    count: i32,
+    to: Option<String>,
+    query: &[(&str, &str)],
+    build: fn(CandleSource<E>) -> Result<T, ResponseError>,
+) -> Result<Vec<T>, ResponseError> {
+    let res = request_candle_page(client, url, market_id, count, to, query).await?;
+    let res_serialized = crate::response::response_text(res).await?;
+
+    serde_json::from_str::<Vec<CandleSource<E>>>(&res_serialized)
+        .map_err(crate::response::response_error_from_json)?
+        .into_iter()
+        .map(build)
+        .collect()
+}
+
+/// Parse `candle_date_time_utc` and `candle_date_time_kst` of the candle APIs
+pub(crate) fn parse_candle_time(value: &str) -> Result<NaiveDateTime, ResponseError> {
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")
+        .map_err(crate::response::response_error_internal_date_format_parse_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn n(value: &str) -> Number {
+        value.parse().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_request_candle_of_every_interval() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/candles/minutes/5"))
+            .and(query_param("count", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[{"market":"KRW-BTC","candle_date_time_utc":"2018-04-18T10:15:00","candle_date_time_kst":"2018-04-18T19:15:00","opening_price":8615000,"high_price":8618000,"low_price":8611000,"trade_price":8616000,"timestamp":1524046594584,"candle_acc_trade_price":60018891.90054,"candle_acc_trade_volume":6.96780929,"unit":5}]"#,
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/candles/days"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[{"market":"KRW-BTC","candle_date_time_utc":"2018-04-18T00:00:00","candle_date_time_kst":"2018-04-18T09:00:00","opening_price":8450000,"high_price":8679000,"low_price":8445000,"trade_price":8626000,"timestamp":1524046650532,"candle_acc_trade_price":107184005903.68721,"candle_acc_trade_volume":12505.93101659,"prev_closing_price":8450000,"change_price":176000,"change_rate":0.0208284024}]"#,
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/candles/weeks"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[{"market":"KRW-BTC","candle_date_time_utc":"2018-04-16T00:00:00","candle_date_time_kst":"2018-04-16T09:00:00","opening_price":8665000,"high_price":8840000,"low_price":8360000,"trade_price":8611000,"timestamp":1524046708995,"candle_acc_trade_price":466989414916.1301,"candle_acc_trade_volume":54410.56660813,"first_day_of_period":"2018-04-16"}]"#,
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/candles/seconds"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[{"market":"KRW-BTC","candle_date_time_utc":"2025-01-08T03:41:57","candle_date_time_kst":"2025-01-08T12:41:57","opening_price":145754000,"high_price":145754000,"low_price":145754000,"trade_price":145754000,"timestamp":1736307717829,"candle_acc_trade_price":1457540.0,"candle_acc_trade_volume":0.01}]"#,
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/candles/years"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[{"market":"KRW-BTC","candle_date_time_utc":"2025-01-01T00:00:00","candle_date_time_kst":"2025-01-01T09:00:00","opening_price":140887000,"high_price":148000000,"low_price":139900000,"trade_price":145754000,"timestamp":1736307717829,"candle_acc_trade_price":2133834089462.1035,"candle_acc_trade_volume":14788.56818495,"first_day_of_period":"2025-01-01"}]"#,
+            ))
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key").with_server_url(&server.uri());
+
+        let minute = Candle::request_candle(&client, "KRW-BTC", CandleMinute::Min5.into(), 1, None)
+            .await
+            .unwrap();
+        let day = Candle::request_candle(&client, "KRW-BTC", CandleInterval::Day, 1, None)
+            .await
+            .unwrap();
+        let week = Candle::request_candle(&client, "KRW-BTC", CandleInterval::Week, 1, None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            minute[0].interval,
+            CandleInterval::Minute(CandleMinute::Min5)
+        );
+        assert_eq!(minute[0].extra, CandleExtra::Minute);
+        assert_eq!(minute[0].trade_price, n("8616000"));
+        assert_eq!(
+            minute[0].candle_date_time_kst,
+            parse_candle_time("2018-04-18T19:15:00").unwrap()
+        );
+
+        assert_eq!(day[0].interval, CandleInterval::Day);
+        assert_eq!(day[0].prev_closing_price(), Some(n("8450000")));
+        assert!(matches!(
+            day[0].extra,
+            CandleExtra::Day {
+                converted_trade_price: None,
+                ..
+            }
+        ));
+
+        assert_eq!(week[0].interval, CandleInterval::Week);
+        assert_eq!(week[0].first_day_of_period(), Some("2018-04-16"));
+        assert_eq!(week[0].prev_closing_price(), None);
+
+        let second = Candle::request_candle(&client, "KRW-BTC", CandleInterval::Second, 1, None)
+            .await
+            .unwrap();
+        let year = Candle::request_candle(&client, "KRW-BTC", CandleInterval::Year, 1, None)
+            .await
+            .unwrap();
+
+        assert_eq!(second[0].extra, CandleExtra::Second);
+        assert_eq!(
+            second[0].candle_date_time_utc,
+            parse_candle_time("2025-01-08T03:41:57").unwrap()
+        );
+        assert_eq!(year[0].interval, CandleInterval::Year);
+        assert_eq!(year[0].first_day_of_period(), Some("2025-01-01"));
+    }
+}
@@ -1,114 +1,74 @@
-use super::{
-    super::constant::URL_SERVER, super::response::ResponseError, CandleMinute, UrlAssociates,
-};
+use crate::client::UpbitClient;
+use crate::number::Number;
+use crate::response::ResponseError;
+
+use super::candle::{parse_candle_time, request_candles, CandleSource};
+use super::{CandleMinute, UrlAssociates};
 
-use reqwest::header::ACCEPT;
-use reqwest::{Response, Url};
 use serde::Deserialize;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CandleChartMinute {
     pub market: String,
     pub candle_date_time_utc: chrono::NaiveDateTime,
     pub candle_date_time_kst: chrono::NaiveDateTime,
-    pub opening_price: f64,
-    pub high_price: f64,
-    pub low_price: f64,
-    pub trade_price: f64,
+    pub opening_price: Number,
+    pub high_price: Number,
+    pub low_price: Number,
+    pub trade_price: Number,
     pub timestamp: i64,
-    pub candle_acc_trade_price: f64,
-    pub candle_acc_trade_volume: f64,
+    pub candle_acc_trade_price: Number,
+    pub candle_acc_trade_volume: Number,
     pub unit: i64,
 }
 
+/// Fields only minute candles have
 #[derive(Deserialize)]
-pub struct CandleChartMinuteSource {
-    market: String,
-    candle_date_time_utc: String,
-    candle_date_time_kst: String,
-    opening_price: f64,
-    high_price: f64,
-    low_price: f64,
-    trade_price: f64,
-    timestamp: i64,
-    candle_acc_trade_price: f64,
-    candle_acc_trade_volume: f64,
+pub struct CandleMinuteExtraSource {
     unit: i64,
 }
 
+pub type CandleChartMinuteSource = CandleSource<CandleMinuteExtraSource>;
+
 impl CandleChartMinute {
     pub async fn request_candle(
+        client: &UpbitClient,
         market_id: &str,
         to: Option<String>,
         count: i32,
         candle_minute: CandleMinute,
     ) -> Result<Vec<Self>, ResponseError> {
-        let res = Self::request(market_id, to, count, candle_minute).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
-
-        serde_json::from_str(&res_serialized)
-            .map(|x: Vec<CandleChartMinuteSource>| {
-                x.into_iter()
-                    .map(|i| Self {
-                        market: i.market,
-                        candle_date_time_utc: chrono::NaiveDateTime::parse_from_str(
-                            &i.candle_date_time_utc,
-                            "%Y-%m-%dT%H:%M:%S",
-                        )
-                        .unwrap(),
-                        candle_date_time_kst: chrono::NaiveDateTime::parse_from_str(
-                            &i.candle_date_time_kst,
-                            "%Y-%m-%dT%H:%M:%S",
-                        )
-                        .unwrap(),
-                        opening_price: i.opening_price,
-                        high_price: i.high_price,
-                        low_price: i.low_price,
-                        trade_price: i.trade_price,
-                        timestamp: i.timestamp,
-                        candle_acc_trade_price: i.candle_acc_trade_price,
-                        candle_acc_trade_volume: i.candle_acc_trade_volume,
-                        unit: i.unit,
-                    })
-                    .collect()
-            })
-            .map_err(crate::response::response_error_from_json)
+        request_candles(
+            client,
+            UrlAssociates::UrlCandleMinute(candle_minute),
+            market_id,
+            count,
+            to,
+            &[],
+            Self::from_source,
+        )
+        .await
     }
 
-    async fn request(
-        market_id: &str,
-        to: Option<String>,
-        count: i32,
-        candle_minute: CandleMinute,
-    ) -> Result<Response, ResponseError> {
-        let url_candle = UrlAssociates::UrlCandleMinute(candle_minute).to_string();
-        let mut url = Url::parse(&format!("{URL_SERVER}{url_candle}"))
-            .map_err(crate::response::response_error_internal_url_parse_error)?;
-
-        url.query_pairs_mut()
-            .append_pair("market", market_id)
-            .append_pair("count", count.to_string().as_str());
-
-        if let Some(to) = to {
-            url.query_pairs_mut().append_pair("to", to.as_str());
-        }
+    /// Get the minute unit of the candle, falling back to [`CandleMinute::Min1`] for a unit unknown to this version of the crate
+    pub fn candle_minute(&self) -> CandleMinute {
+        CandleMinute::from_minutes(self.unit).unwrap_or(CandleMinute::Min1)
+    }
 
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .send()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)
+    fn from_source(x: CandleChartMinuteSource) -> Result<Self, ResponseError> {
+        Ok(Self {
+            candle_date_time_utc: parse_candle_time(&x.candle_date_time_utc)?,
+            candle_date_time_kst: parse_candle_time(&x.candle_date_time_kst)?,
+            market: x.market,
+            opening_price: x.opening_price,
+            high_price: x.high_price,
+            low_price: x.low_price,
+            trade_price: x.trade_price,
+            timestamp: x.timestamp,
+            candle_acc_trade_price: x.candle_acc_trade_price,
+            candle_acc_trade_volume: x.candle_acc_trade_volume,
+            unit: x.extra.unit,
+        })
     }
 }
 
@@ -118,16 +78,28 @@ mod tests {
 
     use serde_json::Value;
 
-    use crate::api_quotation::{CandleChartMinute, CandleMinute};
+    use crate::api_quotation::candle::request_candle_page;
+    use crate::api_quotation::{CandleMinute, UrlAssociates};
+
+    use crate::client::UpbitClient;
 
     #[tokio::test]
     async fn test_request_candle_minute() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = CandleChartMinute::request("KRW-ETH", None, 1, CandleMinute::Min30)
-            .await
-            .unwrap();
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
+
+        let res = request_candle_page(
+            &client,
+            UrlAssociates::UrlCandleMinute(CandleMinute::Min30),
+            "KRW-ETH",
+            1,
+            None,
+            &[],
+        )
+        .await
+        .unwrap();
         let res_serialized = res
             .text()
             .await
@@ -135,7 +107,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized)
@@ -168,11 +140,10 @@ mod tests {
                     compare_keys(item, &expected_structure, &format!("item[{}].", index));
 
                 if !missing_keys.is_empty() {
-                    println!(
+                    panic!(
                         "[test_request_candle_minute] Missing keys in item[{}]: {:?}",
                         index, missing_keys
                     );
-                    assert!(false);
                 } else {
                     println!(
                         "[test_request_candle_minute] No keys are missing in item[{}]",
@@ -181,11 +152,10 @@ mod tests {
                 }
 
                 if !extra_keys.is_empty() {
-                    println!(
+                    panic!(
                         "[test_request_candle_minute] Extra keys in item[{}]: {:?}",
                         index, extra_keys
                     );
-                    assert!(false);
                 } else {
                     println!(
                         "[test_request_candle_minute] No extra keys found in item[{}]",
@@ -194,10 +164,8 @@ mod tests {
                 }
             }
         } else {
-            assert!(false, "Expected an array of objects in the response");
+            panic!("Expected an array of objects in the response");
         }
-
-        assert!(true);
     }
 
     fn compare_keys(
@@ -209,12 +177,12 @@ mod tests {
         let mut extra_keys = Vec::new();
 
         if let Some(actual_map) = json.as_object() {
-            for (key, _) in expected {
+            for key in expected.keys() {
                 if !actual_map.contains_key(*key) {
                     missing_keys.push(format!("{}{}", path, key));
                 }
             }
-            for (key, _) in actual_map {
+            for key in actual_map.keys() {
                 if !expected.contains_key(key.as_str()) {
                     extra_keys.push(format!("{}{}", path, key));
                 }
@@ -1,90 +1,60 @@
+use crate::client::UpbitClient;
+use crate::number::Number;
 use crate::response::ResponseError;
 
-use super::super::constant::URL_SERVER;
+use super::candle::{parse_candle_time, request_candles, CandlePeriodExtraSource, CandleSource};
 use super::UrlAssociates;
 
-use reqwest::header::ACCEPT;
-use reqwest::{Response, Url};
-use serde::Deserialize;
-
-#[derive(Deserialize)]
+#[derive(Debug, Clone)]
 pub struct CandleChartMonth {
-    market: String,
-    candle_date_time_utc: String,
-    candle_date_time_kst: String,
-    opening_price: f64,
-    high_price: f64,
-    low_price: f64,
-    trade_price: f64,
-    timestamp: i64,
-    candle_acc_trade_price: f64,
-    candle_acc_trade_volume: f64,
-    first_day_of_period: String,
+    pub market: String,
+    pub candle_date_time_utc: chrono::NaiveDateTime,
+    pub candle_date_time_kst: chrono::NaiveDateTime,
+    pub opening_price: Number,
+    pub high_price: Number,
+    pub low_price: Number,
+    pub trade_price: Number,
+    pub timestamp: i64,
+    pub candle_acc_trade_price: Number,
+    pub candle_acc_trade_volume: Number,
+    pub first_day_of_period: String,
 }
 
+pub type CandleChartMonthSource = CandleSource<CandlePeriodExtraSource>;
+
 impl CandleChartMonth {
     pub async fn request_candle(
+        client: &UpbitClient,
         market_id: &str,
         count: i32,
         last_candle_time: Option<String>,
     ) -> Result<Vec<Self>, ResponseError> {
-        let res = Self::request(market_id, count, last_candle_time).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
-
-        serde_json::from_str(&res_serialized)
-            .map(|x: Vec<Self>| {
-                x.into_iter()
-                    .map(|i| Self {
-                        market: i.market,
-                        candle_date_time_utc: i.candle_date_time_utc,
-                        candle_date_time_kst: i.candle_date_time_kst,
-                        opening_price: i.opening_price,
-                        high_price: i.high_price,
-                        low_price: i.low_price,
-                        trade_price: i.trade_price,
-                        timestamp: i.timestamp,
-                        candle_acc_trade_price: i.candle_acc_trade_price,
-                        candle_acc_trade_volume: i.candle_acc_trade_volume,
-                        first_day_of_period: i.first_day_of_period,
-                    })
-                    .collect()
-            })
-            .map_err(crate::response::response_error_from_json)
+        request_candles(
+            client,
+            UrlAssociates::UrlCandleMonth,
+            market_id,
+            count,
+            last_candle_time,
+            &[],
+            Self::from_source,
+        )
+        .await
     }
 
-    async fn request(
-        market_id: &str,
-        count: i32,
-        last_candle_time: Option<String>,
-    ) -> Result<Response, ResponseError> {
-        let url_candle = UrlAssociates::UrlCandleMonth.to_string();
-        let mut url = Url::parse(&format!("{URL_SERVER}{url_candle}"))
-            .map_err(crate::response::response_error_internal_url_parse_error)?;
-        url.query_pairs_mut()
-            .append_pair("market", market_id)
-            .append_pair("count", count.to_string().as_str());
-
-        if let Some(last_candle_time) = last_candle_time {
-            url.query_pairs_mut()
-                .append_pair("to", last_candle_time.as_str());
-        }
-
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .send()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)
+    fn from_source(x: CandleChartMonthSource) -> Result<Self, ResponseError> {
+        Ok(Self {
+            candle_date_time_utc: parse_candle_time(&x.candle_date_time_utc)?,
+            candle_date_time_kst: parse_candle_time(&x.candle_date_time_kst)?,
+            market: x.market,
+            opening_price: x.opening_price,
+            high_price: x.high_price,
+            low_price: x.low_price,
+            trade_price: x.trade_price,
+            timestamp: x.timestamp,
+            candle_acc_trade_price: x.candle_acc_trade_price,
+            candle_acc_trade_volume: x.candle_acc_trade_volume,
+            first_day_of_period: x.extra.first_day_of_period,
+        })
     }
 }
 
@@ -94,14 +64,28 @@ mod tests {
 
     use serde_json::Value;
 
-    use crate::api_quotation::CandleChartMonth;
+    use crate::api_quotation::candle::request_candle_page;
+    use crate::api_quotation::UrlAssociates;
+
+    use crate::client::UpbitClient;
 
     #[tokio::test]
     async fn test_request_candle_month() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
-
-        let res = CandleChartMonth::request("KRW-ETH", 1, None).await.unwrap();
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
+
+        let res = request_candle_page(
+            &client,
+            UrlAssociates::UrlCandleMonth,
+            "KRW-ETH",
+            1,
+            None,
+            &[],
+        )
+        .await
+        .unwrap();
         let res_serialized = res
             .text()
             .await
@@ -109,7 +93,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized)
@@ -142,11 +126,10 @@ mod tests {
                     compare_keys(item, &expected_structure, &format!("item[{}].", index));
 
                 if !missing_keys.is_empty() {
-                    println!(
+                    panic!(
                         "[test_request_candle_month] Missing keys in item[{}]: {:?}",
                         index, missing_keys
                     );
-                    assert!(false);
                 } else {
                     println!(
                         "[test_request_candle_month] No keys are missing in item[{}]",
@@ -155,11 +138,10 @@ mod tests {
                 }
 
                 if !extra_keys.is_empty() {
-                    println!(
+                    panic!(
                         "[test_request_candle_month] Extra keys in item[{}]: {:?}",
                         index, extra_keys
                     );
-                    assert!(false);
                 } else {
                     println!(
                         "[test_request_candle_month] No extra keys found in item[{}]",
@@ -168,10 +150,8 @@ mod tests {
                 }
             }
         } else {
-            assert!(false, "Expected an array of objects in the response");
+            panic!("Expected an array of objects in the response");
         }
-
-        assert!(true);
     }
 
     fn compare_keys(
@@ -183,12 +163,12 @@ mod tests {
         let mut extra_keys = Vec::new();
 
         if let Some(actual_map) = json.as_object() {
-            for (key, _) in expected {
+            for key in expected.keys() {
                 if !actual_map.contains_key(*key) {
                     missing_keys.push(format!("{}{}", path, key));
                 }
             }
-            for (key, _) in actual_map {
+            for key in actual_map.keys() {
                 if !expected.contains_key(key.as_str()) {
                     extra_keys.push(format!("{}{}", path, key));
                 }
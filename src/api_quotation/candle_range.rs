@@ -0,0 +1,365 @@
+use std::collections::VecDeque;
+use std::future::Future;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use futures_util::stream::BoxStream;
+use futures_util::StreamExt;
+
+use crate::client::{fallback_rate_limiter, UpbitClient};
+use crate::rate_limit::RateLimiter;
+use crate::response::ResponseError;
+
+use super::{
+    Candle, CandleChartDay, CandleChartMinute, CandleChartMonth, CandleChartSecond,
+    CandleChartWeek, CandleChartYear, CandleInterval, CandleMinute,
+};
+
+/// Candles requested per page. the maximum `count` of the candle APIs
+pub const CANDLE_PAGE_SIZE: i32 = 200;
+
+/// Candle chart type which can be requested page by page
+///
+/// Implemented by [`Candle`] and every candle chart type, from [`CandleChartSecond`] to
+/// [`CandleChartYear`], so that [`super::get_candle_range`] works for each of them.
+pub trait CandleChart: Into<Candle> + Sized + Send + 'static {
+    /// series of the market to request. the interval for [`Candle`], the minute unit for
+    /// [`CandleChartMinute`], `()` otherwise
+    type Unit: Clone + Send + Sync + 'static;
+
+    /// Get start time of the candle in UTC
+    fn candle_time_utc(&self) -> NaiveDateTime;
+
+    /// Request up to `count` candles starting before `to`, latest first
+    fn request_page(
+        client: &UpbitClient,
+        market_id: &str,
+        unit: &Self::Unit,
+        to: Option<String>,
+        count: i32,
+    ) -> impl Future<Output = Result<Vec<Self>, ResponseError>> + Send;
+}
+
+impl CandleChart for Candle {
+    type Unit = CandleInterval;
+
+    fn candle_time_utc(&self) -> NaiveDateTime {
+        self.candle_date_time_utc
+    }
+
+    fn request_page(
+        client: &UpbitClient,
+        market_id: &str,
+        unit: &Self::Unit,
+        to: Option<String>,
+        count: i32,
+    ) -> impl Future<Output = Result<Vec<Self>, ResponseError>> + Send {
+        Self::request_candle(client, market_id, *unit, count, to)
+    }
+}
+
+impl CandleChart for CandleChartSecond {
+    type Unit = ();
+
+    fn candle_time_utc(&self) -> NaiveDateTime {
+        self.candle_date_time_utc
+    }
+
+    fn request_page(
+        client: &UpbitClient,
+        market_id: &str,
+        _: &Self::Unit,
+        to: Option<String>,
+        count: i32,
+    ) -> impl Future<Output = Result<Vec<Self>, ResponseError>> + Send {
+        Self::request_candle(client, market_id, count, to)
+    }
+}
+
+impl CandleChart for CandleChartMinute {
+    type Unit = CandleMinute;
+
+    fn candle_time_utc(&self) -> NaiveDateTime {
+        self.candle_date_time_utc
+    }
+
+    fn request_page(
+        client: &UpbitClient,
+        market_id: &str,
+        unit: &Self::Unit,
+        to: Option<String>,
+        count: i32,
+    ) -> impl Future<Output = Result<Vec<Self>, ResponseError>> + Send {
+        Self::request_candle(client, market_id, to, count, *unit)
+    }
+}
+
+impl CandleChart for CandleChartDay {
+    type Unit = ();
+
+    fn candle_time_utc(&self) -> NaiveDateTime {
+        self.candle_date_time_utc
+    }
+
+    fn request_page(
+        client: &UpbitClient,
+        market_id: &str,
+        _: &Self::Unit,
+        to: Option<String>,
+        count: i32,
+    ) -> impl Future<Output = Result<Vec<Self>, ResponseError>> + Send {
+        Self::request_candle(client, market_id, count, to, None)
+    }
+}
+
+impl CandleChart for CandleChartWeek {
+    type Unit = ();
+
+    fn candle_time_utc(&self) -> NaiveDateTime {
+        self.candle_date_time_utc
+    }
+
+    fn request_page(
+        client: &UpbitClient,
+        market_id: &str,
+        _: &Self::Unit,
+        to: Option<String>,
+        count: i32,
+    ) -> impl Future<Output = Result<Vec<Self>, ResponseError>> + Send {
+        Self::request_candle(client, market_id, count, to)
+    }
+}
+
+impl CandleChart for CandleChartMonth {
+    type Unit = ();
+
+    fn candle_time_utc(&self) -> NaiveDateTime {
+        self.candle_date_time_utc
+    }
+
+    fn request_page(
+        client: &UpbitClient,
+        market_id: &str,
+        _: &Self::Unit,
+        to: Option<String>,
+        count: i32,
+    ) -> impl Future<Output = Result<Vec<Self>, ResponseError>> + Send {
+        Self::request_candle(client, market_id, count, to)
+    }
+}
+
+impl CandleChart for CandleChartYear {
+    type Unit = ();
+
+    fn candle_time_utc(&self) -> NaiveDateTime {
+        self.candle_date_time_utc
+    }
+
+    fn request_page(
+        client: &UpbitClient,
+        market_id: &str,
+        _: &Self::Unit,
+        to: Option<String>,
+        count: i32,
+    ) -> impl Future<Output = Result<Vec<Self>, ResponseError>> + Send {
+        Self::request_candle(client, market_id, count, to)
+    }
+}
+
+struct RangeState<C: CandleChart> {
+    client: UpbitClient,
+    market_id: String,
+    unit: C::Unit,
+    from: NaiveDateTime,
+    /// start of the oldest candle requested so far. the next page ends before it
+    cursor: NaiveDateTime,
+    candles: VecDeque<C>,
+    is_done: bool,
+}
+
+impl<C: CandleChart> RangeState<C> {
+    async fn next(mut self) -> Option<(Result<C, ResponseError>, Self)> {
+        loop {
+            if let Some(candle) = self.candles.pop_front() {
+                return Some((Ok(candle), self));
+            }
+            if self.is_done {
+                return None;
+            }
+
+            let to = format!("{}Z", self.cursor.format("%Y-%m-%dT%H:%M:%S"));
+            let page = match C::request_page(
+                &self.client,
+                &self.market_id,
+                &self.unit,
+                Some(to),
+                CANDLE_PAGE_SIZE,
+            )
+            .await
+            {
+                Ok(page) => page,
+                Err(e) => {
+                    self.is_done = true;
+                    return Some((Err(e), self));
+                }
+            };
+            let is_last_page = page.len() < CANDLE_PAGE_SIZE as usize;
+            let mut oldest = self.cursor;
+
+            for candle in page {
+                let time = candle.candle_time_utc();
+
+                // candles at or after the cursor were yielded by the previous page
+                if time >= oldest {
+                    continue;
+                }
+
+                oldest = time;
+
+                if time >= self.from {
+                    self.candles.push_back(candle);
+                }
+            }
+
+            self.is_done = is_last_page || oldest <= self.from || oldest == self.cursor;
+            self.cursor = oldest;
+        }
+    }
+}
+
+/// Get limiter the pages of a range are requested through
+///
+/// Pages are requested back to back, so they are kept within the quota of the candles group even
+/// when the client has no limiter, unless it was turned off with
+/// [`UpbitClient::without_rate_limiter`].
+fn range_rate_limiter(client: &UpbitClient) -> Option<RateLimiter> {
+    match &client.rate_limiter {
+        Some(rate_limiter) => Some(rate_limiter.clone()),
+        None if client.is_rate_limiter_disabled => None,
+        None => Some(fallback_rate_limiter()),
+    }
+}
+
+/// Stream candles of `market_id` starting in `from..to`, latest first
+///
+/// See [`super::get_candle_range`].
+pub(crate) fn stream_candle_range<C: CandleChart>(
+    client: &UpbitClient,
+    market_id: &str,
+    unit: C::Unit,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> BoxStream<'static, Result<C, ResponseError>> {
+    let client = match range_rate_limiter(client) {
+        Some(rate_limiter) => client.clone().with_rate_limiter(rate_limiter),
+        None => client.clone(),
+    };
+    let state = RangeState::<C> {
+        client,
+        market_id: market_id.to_owned(),
+        unit,
+        from: from.naive_utc(),
+        cursor: to.naive_utc(),
+        candles: VecDeque::new(),
+        is_done: from >= to,
+    };
+
+    futures_util::stream::unfold(state, RangeState::next).boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+    use futures_util::TryStreamExt;
+
+    use super::*;
+
+    #[test]
+    fn test_range_rate_limiter() {
+        let client = UpbitClient::new("access_key", "secret_key");
+        let first = range_rate_limiter(&client).unwrap();
+        let second = range_rate_limiter(&client.clone()).unwrap();
+
+        // concurrent ranges split one quota
+        assert!(first.shares_quotas_with(&second));
+
+        let limiter = RateLimiter::new();
+        let own = range_rate_limiter(&client.clone().with_rate_limiter(limiter.clone())).unwrap();
+
+        assert!(own.shares_quotas_with(&limiter));
+        assert!(range_rate_limiter(&client.without_rate_limiter()).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_candle_range_pages_and_dedups() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+        let first = Utc
+            .with_ymd_and_hms(2024, 1, 1, 0, 0, 0)
+            .unwrap()
+            .naive_utc();
+
+        // one candle a minute since `first`, latest first. the candle starting at `to` is
+        // included to check boundaries are not yielded twice
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/candles/minutes/1"))
+            .respond_with(move |request: &Request| {
+                let query = |key: &str| {
+                    request
+                        .url
+                        .query_pairs()
+                        .find(|(k, _)| k == key)
+                        .map(|(_, v)| v.into_owned())
+                        .unwrap()
+                };
+                let to = NaiveDateTime::parse_from_str(&query("to"), "%Y-%m-%dT%H:%M:%SZ").unwrap();
+                let count = query("count").parse::<i64>().unwrap();
+                let body = (0..count)
+                    .map(|i| to - Duration::minutes(i))
+                    .take_while(|time| *time >= first)
+                    .map(|time| {
+                        format!(
+                            r#"{{"market":"KRW-BTC","candle_date_time_utc":"{}","candle_date_time_kst":"{}","opening_price":8615000,"high_price":8618000,"low_price":8611000,"trade_price":8616000,"timestamp":1524046594584,"candle_acc_trade_price":60018891.90054,"candle_acc_trade_volume":6.96780929,"unit":1}}"#,
+                            time.format("%Y-%m-%dT%H:%M:%S"),
+                            (time + Duration::hours(9)).format("%Y-%m-%dT%H:%M:%S"),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                ResponseTemplate::new(200).set_body_string(format!("[{body}]"))
+            })
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key").with_server_url(&server.uri());
+        let from = Utc.with_ymd_and_hms(2024, 1, 1, 1, 0, 0).unwrap();
+        let to = from + Duration::minutes(450);
+
+        let candles = stream_candle_range::<CandleChartMinute>(
+            &client,
+            "KRW-BTC",
+            CandleMinute::Min1,
+            from,
+            to,
+        )
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+        assert_eq!(candles.len(), 450);
+        assert_eq!(
+            candles[0].candle_date_time_utc,
+            (to - Duration::minutes(1)).naive_utc()
+        );
+        assert_eq!(candles[449].candle_date_time_utc, from.naive_utc());
+        assert!(
+            candles
+                .windows(2)
+                .all(|x| x[0].candle_date_time_utc - x[1].candle_date_time_utc
+                    == Duration::minutes(1))
+        );
+    }
+}
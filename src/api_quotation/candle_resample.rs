@@ -0,0 +1,395 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, Duration, NaiveDateTime};
+
+use crate::number::Number;
+
+use super::{Candle, CandleExtra, CandleInterval};
+
+/// Time zone the buckets of [`resample_candles`] start on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CandleAlignment {
+    /// ex) day buckets start at 00:00 UTC, as the day candles of Upbit
+    Utc,
+    /// ex) day buckets start at 00:00 KST, 15:00 UTC of the day before
+    Kst,
+}
+
+impl CandleAlignment {
+    fn offset(&self) -> Duration {
+        match self {
+            Self::Utc => Duration::zero(),
+            Self::Kst => Duration::hours(9),
+        }
+    }
+}
+
+/// Reason candles can't be filled or resampled
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResampleError {
+    /// length of the interval varies, as months and years
+    UnfixedInterval(CandleInterval),
+    /// bucket is not a positive multiple of the interval of the candles
+    InvalidBucket {
+        bucket: Duration,
+        interval: CandleInterval,
+    },
+    /// candles don't share one interval, ex. 1 minute candles mixed with 3 minute candles
+    MixedInterval {
+        expected: CandleInterval,
+        found: CandleInterval,
+    },
+}
+
+impl Display for ResampleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnfixedInterval(interval) => {
+                write!(f, "interval has no fixed length: {interval:?}")
+            }
+            Self::InvalidBucket { bucket, interval } => write!(
+                f,
+                "bucket {bucket} is not a positive multiple of interval {interval:?}"
+            ),
+            Self::MixedInterval { expected, found } => write!(
+                f,
+                "candles of interval {found:?} mixed with interval {expected:?}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ResampleError {}
+
+/// Add the candles Upbit omits for intervals with no trade
+///
+/// A missing candle carries the close of the candle before it forward as open, high, low and close,
+/// with zero volume. Candles are returned oldest first whatever order they come in, and only the
+/// gaps between the first and the last are filled. Every candle must have the interval of the
+/// first one.
+///
+/// # Example
+/// ```no_run
+/// use upbit::api_quotation::{self, CandleMinute};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let candles = api_quotation::get_candle_minute("KRW-ETH", None, 200, CandleMinute::Min1).await?;
+/// let candles = api_quotation::fill_candle_gaps(candles)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn fill_candle_gaps<C: Into<Candle>>(
+    candles: impl IntoIterator<Item = C>,
+) -> Result<Vec<Candle>, ResampleError> {
+    let candles = sorted(candles);
+    let Some(first) = candles.first() else {
+        return Ok(candles);
+    };
+    let step = step_of(first.interval, &candles)?;
+
+    let mut filled: Vec<Candle> = Vec::with_capacity(candles.len());
+
+    for candle in candles {
+        if let Some(prev) = filled.last() {
+            let mut time = prev.candle_date_time_utc + step;
+            let mut missing = Vec::new();
+
+            while time < candle.candle_date_time_utc {
+                missing.push(carried_forward(prev, time));
+                time += step;
+            }
+
+            filled.extend(missing);
+        }
+
+        filled.push(candle);
+    }
+
+    Ok(filled)
+}
+
+/// Merge candles into buckets of `bucket`, ex. 1 minute candles into 2 hour candles
+///
+/// Buckets start on multiples of `bucket` since the Unix epoch, 1970-01-01 00:00, in the time zone
+/// of `alignment`. Buckets dividing a day line up with the day, but longer ones don't: 7 day
+/// buckets start on Thursday, as the epoch does, not on Monday as the week candles of Upbit. Open is
+/// the open of the oldest candle of a bucket, close the close of the latest, and
+/// `candle_acc_trade_price` and `candle_acc_trade_volume` are summed. Buckets with no candle are
+/// skipped as Upbit does; pass the result to [`fill_candle_gaps`] to fill them.
+///
+/// Candles are returned oldest first with the interval [`CandleInterval::Resampled`]. Every candle
+/// must have the interval of the first one.
+///
+/// # Example
+/// ```no_run
+/// use chrono::Duration;
+/// use upbit::api_quotation::{self, CandleAlignment, CandleMinute};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let candles = api_quotation::get_candle_minute("KRW-ETH", None, 200, CandleMinute::Min60).await?;
+/// let candles = api_quotation::resample_candles(candles, Duration::hours(6), CandleAlignment::Kst)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn resample_candles<C: Into<Candle>>(
+    candles: impl IntoIterator<Item = C>,
+    bucket: Duration,
+    alignment: CandleAlignment,
+) -> Result<Vec<Candle>, ResampleError> {
+    let candles = sorted(candles);
+    let Some(first) = candles.first() else {
+        return Ok(candles);
+    };
+    let interval = first.interval;
+    let step = step_of(interval, &candles)?;
+
+    if bucket <= Duration::zero()
+        || bucket.subsec_nanos() != 0
+        || bucket.num_seconds() % step.num_seconds() != 0
+    {
+        return Err(ResampleError::InvalidBucket { bucket, interval });
+    }
+
+    let mut resampled: Vec<Candle> = Vec::new();
+
+    for candle in candles {
+        let start = bucket_start(candle.candle_date_time_utc, bucket, alignment);
+
+        match resampled.last_mut() {
+            Some(last) if last.candle_date_time_utc == start => {
+                if candle.high_price > last.high_price {
+                    last.high_price = candle.high_price;
+                }
+                if candle.low_price < last.low_price {
+                    last.low_price = candle.low_price;
+                }
+                last.trade_price = candle.trade_price;
+                last.timestamp = candle.timestamp;
+                last.candle_acc_trade_price += candle.candle_acc_trade_price;
+                last.candle_acc_trade_volume += candle.candle_acc_trade_volume;
+            }
+            _ => resampled.push(Candle {
+                interval: CandleInterval::Resampled(bucket),
+                candle_date_time_utc: start,
+                candle_date_time_kst: start + Duration::hours(9),
+                extra: CandleExtra::Resampled,
+                ..candle
+            }),
+        }
+    }
+
+    Ok(resampled)
+}
+
+/// Get the length of `interval`, checking every candle has it
+fn step_of(interval: CandleInterval, candles: &[Candle]) -> Result<Duration, ResampleError> {
+    if let Some(candle) = candles.iter().find(|x| x.interval != interval) {
+        return Err(ResampleError::MixedInterval {
+            expected: interval,
+            found: candle.interval,
+        });
+    }
+
+    interval
+        .duration()
+        .ok_or(ResampleError::UnfixedInterval(interval))
+}
+
+fn sorted<C: Into<Candle>>(candles: impl IntoIterator<Item = C>) -> Vec<Candle> {
+    let mut candles: Vec<Candle> = candles.into_iter().map(Into::into).collect();
+
+    candles.sort_by_key(|x| x.candle_date_time_utc);
+    candles.dedup_by_key(|x| x.candle_date_time_utc);
+
+    candles
+}
+
+fn bucket_start(
+    time: NaiveDateTime,
+    bucket: Duration,
+    alignment: CandleAlignment,
+) -> NaiveDateTime {
+    let offset = alignment.offset().num_seconds();
+    let seconds = bucket.num_seconds();
+    let local = time.and_utc().timestamp() + offset;
+    let start = local.div_euclid(seconds) * seconds - offset;
+
+    DateTime::from_timestamp(start, 0)
+        .unwrap_or_default()
+        .naive_utc()
+}
+
+/// Candle with no trade starting at `time`, right after `prev`
+fn carried_forward(prev: &Candle, time: NaiveDateTime) -> Candle {
+    let extra = match &prev.extra {
+        CandleExtra::Day { .. } => CandleExtra::Day {
+            prev_closing_price: prev.trade_price,
+            change_price: Number::default(),
+            change_rate: Number::default(),
+            converted_trade_price: None,
+        },
+        CandleExtra::Week { .. } => CandleExtra::Week {
+            first_day_of_period: time.format("%Y-%m-%d").to_string(),
+        },
+        x => x.clone(),
+    };
+
+    Candle {
+        market: prev.market.to_owned(),
+        interval: prev.interval,
+        candle_date_time_utc: time,
+        candle_date_time_kst: time + Duration::hours(9),
+        opening_price: prev.trade_price,
+        high_price: prev.trade_price,
+        low_price: prev.trade_price,
+        trade_price: prev.trade_price,
+        timestamp: prev.timestamp,
+        candle_acc_trade_price: Number::default(),
+        candle_acc_trade_volume: Number::default(),
+        extra,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, NaiveDate, Timelike, Weekday};
+
+    use super::*;
+    use crate::api_quotation::CandleMinute;
+
+    fn minute(hour: u32, min: u32, open: i32, close: i32, volume: i32) -> Candle {
+        let time = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(hour, min, 0)
+            .unwrap();
+
+        Candle {
+            market: "KRW-BTC".to_owned(),
+            interval: CandleInterval::Minute(CandleMinute::Min1),
+            candle_date_time_utc: time,
+            candle_date_time_kst: time + Duration::hours(9),
+            opening_price: Number::from(open),
+            high_price: Number::from(open.max(close) + 1),
+            low_price: Number::from(open.min(close) - 1),
+            trade_price: Number::from(close),
+            timestamp: time.and_utc().timestamp_millis() + 59_000,
+            candle_acc_trade_price: Number::from(close * volume),
+            candle_acc_trade_volume: Number::from(volume),
+            extra: CandleExtra::Minute,
+        }
+    }
+
+    #[test]
+    fn test_fill_candle_gaps() {
+        // latest first, as the API returns them
+        let candles = vec![minute(0, 4, 103, 104, 2), minute(0, 1, 100, 101, 1)];
+        let filled = fill_candle_gaps(candles).unwrap();
+
+        assert_eq!(
+            filled
+                .iter()
+                .map(|x| x.candle_date_time_utc.minute())
+                .collect::<Vec<_>>(),
+            [1, 2, 3, 4]
+        );
+        assert_eq!(filled[1].opening_price, Number::from(101));
+        assert_eq!(filled[2].high_price, Number::from(101));
+        assert_eq!(filled[2].trade_price, Number::from(101));
+        assert_eq!(filled[2].candle_acc_trade_volume, Number::default());
+        assert_eq!(filled[2].interval, filled[0].interval);
+        assert_eq!(filled[3].trade_price, Number::from(104));
+
+        let mut month = minute(0, 0, 100, 101, 1);
+        month.interval = CandleInterval::Month;
+        assert_eq!(
+            fill_candle_gaps(vec![month]),
+            Err(ResampleError::UnfixedInterval(CandleInterval::Month))
+        );
+
+        let mut min3 = minute(0, 6, 104, 105, 1);
+        min3.interval = CandleInterval::Minute(CandleMinute::Min3);
+        assert_eq!(
+            fill_candle_gaps(vec![minute(0, 1, 100, 101, 1), min3]),
+            Err(ResampleError::MixedInterval {
+                expected: CandleInterval::Minute(CandleMinute::Min1),
+                found: CandleInterval::Minute(CandleMinute::Min3),
+            })
+        );
+    }
+
+    #[test]
+    fn test_resample_candles() {
+        let candles = vec![
+            minute(14, 58, 100, 101, 1),
+            minute(14, 59, 101, 99, 2),
+            minute(15, 0, 99, 105, 3),
+            minute(15, 3, 105, 104, 4),
+        ];
+
+        let utc =
+            resample_candles(candles.clone(), Duration::hours(1), CandleAlignment::Utc).unwrap();
+
+        assert_eq!(utc.len(), 2);
+        assert_eq!(utc[0].candle_date_time_utc.hour(), 14);
+        assert_eq!(utc[0].opening_price, Number::from(100));
+        assert_eq!(utc[0].high_price, Number::from(102));
+        assert_eq!(utc[0].low_price, Number::from(98));
+        assert_eq!(utc[0].trade_price, Number::from(99));
+        assert_eq!(utc[0].candle_acc_trade_volume, Number::from(3));
+        assert_eq!(utc[0].candle_acc_trade_price, Number::from(299));
+        assert_eq!(utc[0].timestamp, candles[1].timestamp);
+        assert_eq!(utc[1].candle_date_time_kst.hour(), 0);
+        assert_eq!(
+            utc[1].interval,
+            CandleInterval::Resampled(Duration::hours(1))
+        );
+
+        // a KST day starts at 15:00 UTC
+        let kst = resample_candles(candles, Duration::days(1), CandleAlignment::Kst).unwrap();
+
+        assert_eq!(kst.len(), 2);
+        assert_eq!(kst[0].candle_date_time_kst.hour(), 0);
+        assert_eq!(kst[1].candle_date_time_utc.hour(), 15);
+        assert_eq!(kst[1].opening_price, Number::from(99));
+        assert_eq!(kst[1].trade_price, Number::from(104));
+        assert_eq!(kst[1].candle_acc_trade_volume, Number::from(7));
+
+        assert!(matches!(
+            resample_candles(kst, Duration::seconds(90), CandleAlignment::Utc),
+            Err(ResampleError::InvalidBucket { .. })
+        ));
+
+        // an interval differing only after the first candle is rejected as well
+        let mut min3 = minute(15, 6, 104, 105, 1);
+        min3.interval = CandleInterval::Minute(CandleMinute::Min3);
+        assert_eq!(
+            resample_candles(
+                vec![minute(15, 0, 99, 105, 3), minute(15, 3, 105, 104, 4), min3],
+                Duration::hours(1),
+                CandleAlignment::Utc
+            ),
+            Err(ResampleError::MixedInterval {
+                expected: CandleInterval::Minute(CandleMinute::Min1),
+                found: CandleInterval::Minute(CandleMinute::Min3),
+            })
+        );
+    }
+
+    #[test]
+    fn test_resample_candles_aligns_to_epoch() {
+        // 2024-01-01 is a Monday, but 7 day buckets start on Thursday as 1970-01-01 does
+        let mut candle = minute(0, 0, 100, 101, 1);
+        candle.candle_date_time_utc = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+
+        let week = resample_candles(vec![candle], Duration::days(7), CandleAlignment::Utc).unwrap();
+
+        assert_eq!(week[0].candle_date_time_utc.weekday(), Weekday::Thu);
+        assert_eq!(
+            week[0].candle_date_time_utc.date(),
+            NaiveDate::from_ymd_opt(2023, 12, 28).unwrap()
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use crate::client::UpbitClient;
+use crate::number::Number;
+use crate::response::ResponseError;
+
+use super::candle::{parse_candle_time, request_candles, CandleSource};
+use super::UrlAssociates;
+
+use serde::Deserialize;
+
+#[derive(Debug, Clone)]
+pub struct CandleChartSecond {
+    pub market: String,
+    pub candle_date_time_utc: chrono::NaiveDateTime,
+    pub candle_date_time_kst: chrono::NaiveDateTime,
+    pub opening_price: Number,
+    pub high_price: Number,
+    pub low_price: Number,
+    pub trade_price: Number,
+    pub timestamp: i64,
+    pub candle_acc_trade_price: Number,
+    pub candle_acc_trade_volume: Number,
+}
+
+/// Second candles have no fields of their own
+#[derive(Deserialize)]
+pub struct CandleSecondExtraSource {}
+
+pub type CandleChartSecondSource = CandleSource<CandleSecondExtraSource>;
+
+impl CandleChartSecond {
+    pub async fn request_candle(
+        client: &UpbitClient,
+        market_id: &str,
+        count: i32,
+        last_candle_time: Option<String>,
+    ) -> Result<Vec<Self>, ResponseError> {
+        request_candles(
+            client,
+            UrlAssociates::UrlCandleSecond,
+            market_id,
+            count,
+            last_candle_time,
+            &[],
+            Self::from_source,
+        )
+        .await
+    }
+
+    fn from_source(x: CandleChartSecondSource) -> Result<Self, ResponseError> {
+        Ok(Self {
+            candle_date_time_utc: parse_candle_time(&x.candle_date_time_utc)?,
+            candle_date_time_kst: parse_candle_time(&x.candle_date_time_kst)?,
+            market: x.market,
+            opening_price: x.opening_price,
+            high_price: x.high_price,
+            low_price: x.low_price,
+            trade_price: x.trade_price,
+            timestamp: x.timestamp,
+            candle_acc_trade_price: x.candle_acc_trade_price,
+            candle_acc_trade_volume: x.candle_acc_trade_volume,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_request_candle_second() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/candles/seconds"))
+            .and(query_param("market", "KRW-BTC"))
+            .and(query_param("count", "2"))
+            .and(query_param("to", "2025-01-08T03:42:00"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[{"market":"KRW-BTC","candle_date_time_utc":"2025-01-08T03:41:58","candle_date_time_kst":"2025-01-08T12:41:58","opening_price":145755000,"high_price":145760000,"low_price":145755000,"trade_price":145760000,"timestamp":1736307718901,"candle_acc_trade_price":2915110.0,"candle_acc_trade_volume":0.02},{"market":"KRW-BTC","candle_date_time_utc":"2025-01-08T03:41:57","candle_date_time_kst":"2025-01-08T12:41:57","opening_price":145754000,"high_price":145754000,"low_price":145754000,"trade_price":145754000,"timestamp":1736307717829,"candle_acc_trade_price":1457540.0,"candle_acc_trade_volume":0.01}]"#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key").with_server_url(&server.uri());
+        let candles = CandleChartSecond::request_candle(
+            &client,
+            "KRW-BTC",
+            2,
+            Some("2025-01-08T03:42:00".to_owned()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(candles.len(), 2);
+        assert_eq!(candles[0].market, "KRW-BTC");
+        assert_eq!(
+            candles[0].candle_date_time_kst,
+            parse_candle_time("2025-01-08T12:41:58").unwrap()
+        );
+        assert_eq!(candles[0].trade_price, Number::from(145_760_000));
+        assert_eq!(candles[1].timestamp, 1736307717829);
+    }
+}
@@ -0,0 +1,96 @@
+use crate::client::UpbitClient;
+use crate::number::Number;
+use crate::response::ResponseError;
+
+use super::candle::{parse_candle_time, request_candles, CandlePeriodExtraSource, CandleSource};
+use super::UrlAssociates;
+
+#[derive(Debug, Clone)]
+pub struct CandleChartYear {
+    pub market: String,
+    pub candle_date_time_utc: chrono::NaiveDateTime,
+    pub candle_date_time_kst: chrono::NaiveDateTime,
+    pub opening_price: Number,
+    pub high_price: Number,
+    pub low_price: Number,
+    pub trade_price: Number,
+    pub timestamp: i64,
+    pub candle_acc_trade_price: Number,
+    pub candle_acc_trade_volume: Number,
+    pub first_day_of_period: String,
+}
+
+pub type CandleChartYearSource = CandleSource<CandlePeriodExtraSource>;
+
+impl CandleChartYear {
+    pub async fn request_candle(
+        client: &UpbitClient,
+        market_id: &str,
+        count: i32,
+        last_candle_time: Option<String>,
+    ) -> Result<Vec<Self>, ResponseError> {
+        request_candles(
+            client,
+            UrlAssociates::UrlCandleYear,
+            market_id,
+            count,
+            last_candle_time,
+            &[],
+            Self::from_source,
+        )
+        .await
+    }
+
+    fn from_source(x: CandleChartYearSource) -> Result<Self, ResponseError> {
+        Ok(Self {
+            candle_date_time_utc: parse_candle_time(&x.candle_date_time_utc)?,
+            candle_date_time_kst: parse_candle_time(&x.candle_date_time_kst)?,
+            market: x.market,
+            opening_price: x.opening_price,
+            high_price: x.high_price,
+            low_price: x.low_price,
+            trade_price: x.trade_price,
+            timestamp: x.timestamp,
+            candle_acc_trade_price: x.candle_acc_trade_price,
+            candle_acc_trade_volume: x.candle_acc_trade_volume,
+            first_day_of_period: x.extra.first_day_of_period,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_request_candle_year() {
+        use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/candles/years"))
+            .and(query_param("market", "KRW-BTC"))
+            .and(query_param("count", "1"))
+            .and(query_param_is_missing("to"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[{"market":"KRW-BTC","candle_date_time_utc":"2025-01-01T00:00:00","candle_date_time_kst":"2025-01-01T09:00:00","opening_price":140887000,"high_price":148000000,"low_price":139900000,"trade_price":145754000,"timestamp":1736307717829,"candle_acc_trade_price":2133834089462.1035,"candle_acc_trade_volume":14788.56818495,"first_day_of_period":"2025-01-01"}]"#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key").with_server_url(&server.uri());
+        let candles = CandleChartYear::request_candle(&client, "KRW-BTC", 1, None)
+            .await
+            .unwrap();
+
+        assert_eq!(candles.len(), 1);
+        assert_eq!(candles[0].first_day_of_period, "2025-01-01");
+        assert_eq!(
+            candles[0].candle_date_time_utc,
+            parse_candle_time("2025-01-01T00:00:00").unwrap()
+        );
+        assert_eq!(candles[0].high_price, Number::from(148_000_000));
+    }
+}
@@ -2,7 +2,8 @@ use reqwest::header::ACCEPT;
 use reqwest::{Response, Url};
 use serde::{Deserialize, Serialize};
 
-use super::super::constant::{URL_MARKET_STATE, URL_SERVER};
+use super::super::constant::URL_MARKET_STATE;
+use crate::client::UpbitClient;
 use crate::response::ResponseError;
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -42,19 +43,12 @@ pub struct MarketStateSource {
 }
 
 impl MarketState {
-    pub async fn get_market_state(is_detailed: bool) -> Result<Vec<Self>, ResponseError> {
-        let res = Self::request(is_detailed).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+    pub async fn get_market_state(
+        client: &UpbitClient,
+        is_detailed: bool,
+    ) -> Result<Vec<Self>, ResponseError> {
+        let res = Self::request(client, is_detailed).await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         serde_json::from_str::<Vec<MarketStateSource>>(&res_serialized)
             .map(|x| {
@@ -71,18 +65,20 @@ impl MarketState {
             .map_err(crate::response::response_error_from_json)
     }
 
-    async fn request(is_detailed: bool) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_MARKET_STATE}"))
+    async fn request(client: &UpbitClient, is_detailed: bool) -> Result<Response, ResponseError> {
+        let mut url = Url::parse(&format!("{}{URL_MARKET_STATE}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
         url.query_pairs_mut()
             .append_pair("isDetails", is_detailed.to_string().as_str());
 
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .send()
+        client
+            .send(
+                client
+                    .http
+                    .get(url.as_str())
+                    .header(ACCEPT, "application/json"),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -94,12 +90,16 @@ mod tests {
 
     use crate::api_quotation::MarketState;
 
+    use crate::client::UpbitClient;
+
     #[tokio::test]
     async fn test_get_market_state() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
-        let res = MarketState::request(true).await.unwrap();
+        let res = MarketState::request(&client, true).await.unwrap();
         let res_serialized = res
             .text()
             .await
@@ -107,7 +107,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -145,7 +145,7 @@ mod tests {
                         "[test_get_market_state] Missing keys in item[{}]: {:?}",
                         index, missing_keys
                     );
-                    assert!(false, "Missing keys found");
+                    panic!("Missing keys found");
                 }
 
                 if !extra_keys.is_empty() {
@@ -153,14 +153,12 @@ mod tests {
                         "[test_get_market_state] Extra keys in item[{}]: {:?}",
                         index, extra_keys
                     );
-                    assert!(false, "Extra keys found");
+                    panic!("Extra keys found");
                 }
             }
         } else {
-            assert!(false, "Expected an array of objects in the response");
+            panic!("Expected an array of objects in the response");
         }
-
-        assert!(true);
     }
 
     fn compare_keys(
@@ -1,39 +1,49 @@
+pub mod candle;
 pub mod candle_day;
 pub mod candle_minute;
 pub mod candle_month;
+pub mod candle_range;
+pub mod candle_resample;
+pub mod candle_second;
 pub mod candle_week;
+pub mod candle_year;
 pub mod market_state;
 pub mod order_book;
 pub mod ticker_snapshot;
 pub mod trade_recent;
 
 use std::fmt::Display;
+use std::str::FromStr;
 
+pub use candle::{Candle, CandleExtra, CandleInterval};
 pub use candle_day::CandleChartDay;
 pub use candle_minute::CandleChartMinute;
 pub use candle_month::CandleChartMonth;
+pub use candle_range::CandleChart;
+pub use candle_resample::{fill_candle_gaps, resample_candles, CandleAlignment, ResampleError};
+pub use candle_second::CandleChartSecond;
 pub use candle_week::CandleChartWeek;
+pub use candle_year::CandleChartYear;
 pub use market_state::MarketState;
-pub use order_book::OrderBookInfo;
+pub use order_book::{OrderBookInfo, OrderBookUnit};
 use serde::Deserialize;
 pub use ticker_snapshot::TickerSnapshot;
 pub use trade_recent::TradeRecent;
 
-use crate::constant::{URL_CANDLE_DAY, URL_CANDLE_MINUTE, URL_CANDLE_MONTH, URL_CANDLE_WEEK};
-use crate::response::ResponseError;
+use chrono::{DateTime, Utc};
+use futures_util::stream::BoxStream;
 
-#[cfg(feature = "sqlx-type")]
-use sqlx::Type;
+use crate::client::UpbitClient;
+use crate::constant::ParseEnumError;
+use crate::constant::{
+    URL_CANDLE_DAY, URL_CANDLE_MINUTE, URL_CANDLE_MONTH, URL_CANDLE_SECOND, URL_CANDLE_WEEK,
+    URL_CANDLE_YEAR,
+};
+use crate::response::ResponseError;
+use crate::sqlx_type::impl_sqlx_type;
 
 /// Kind of change of ticker snapshot
-#[derive(Deserialize, Debug, Copy, Clone)]
-
-#[cfg_attr(
-    feature = "sqlx-type",
-    derive(sqlx::Type),
-    sqlx(type_name = "snapshot_change_type"),
-    sqlx(rename_all = "snake_case")
-)]
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
 pub enum SnapshotChangeType {
     /// 보합
     Even,
@@ -41,21 +51,66 @@ pub enum SnapshotChangeType {
     Rise,
     /// 하락
     Fall,
+    /// value unknown to this version of the crate
+    Unknown(String),
 }
 
-impl From<&str> for SnapshotChangeType {
-    fn from(value: &str) -> Self {
-        match value {
-            "EVEN" => Self::Even,
-            "FALL" => Self::Fall,
-            "RISE" => Self::Rise,
-            a @ _ => panic!("Unexpected value: {}", a),
+impl Display for SnapshotChangeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SnapshotChangeType::Even => write!(f, "EVEN"),
+            SnapshotChangeType::Fall => write!(f, "FALL"),
+            SnapshotChangeType::Rise => write!(f, "RISE"),
+            SnapshotChangeType::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
 
+impl FromStr for SnapshotChangeType {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "EVEN" => Ok(Self::Even),
+            "FALL" => Ok(Self::Fall),
+            "RISE" => Ok(Self::Rise),
+            a => Err(ParseEnumError::new("SnapshotChangeType", a)),
+        }
+    }
+}
+
+impl TryFrom<&str> for SnapshotChangeType {
+    type Error = ParseEnumError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl SnapshotChangeType {
+    /// Parse `value`, keeping values unknown to this version of the crate as [`SnapshotChangeType::Unknown`]
+    pub fn parse_or_unknown(value: &str) -> Self {
+        value
+            .parse()
+            .unwrap_or_else(|_| Self::Unknown(value.to_owned()))
+    }
+}
+
+impl_sqlx_type!(SnapshotChangeType, "snapshot_change_type");
+
+/// Key to rank tickers by with [`TickerSnapshot::rank`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TickerRank {
+    /// 24시간 누적 거래대금
+    TradePrice24h,
+    /// 전일 대비 변화율의 절대값. largest moves either way first
+    ChangeRate,
+    /// 부호가 있는 전일 대비 변화율. top gainers first
+    SignedChangeRate,
+}
+
 /// Kind of minute unit of minute candle chart
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CandleMinute {
     /// Into() coerces it into u8 sized 1
     Min1,
@@ -90,17 +145,39 @@ impl From<CandleMinute> for u8 {
     }
 }
 
+impl CandleMinute {
+    /// Get the unit of `minutes`, as in `unit` of a minute candle
+    pub fn from_minutes(minutes: i64) -> Option<Self> {
+        match minutes {
+            1 => Some(Self::Min1),
+            3 => Some(Self::Min3),
+            5 => Some(Self::Min5),
+            10 => Some(Self::Min10),
+            15 => Some(Self::Min15),
+            30 => Some(Self::Min30),
+            60 => Some(Self::Min60),
+            240 => Some(Self::Min240),
+            _ => None,
+        }
+    }
+}
+
 /// For the purpose of descripting the kind of candle chart time
 pub enum UrlAssociates {
+    UrlCandleSecond,
     UrlCandleMinute(CandleMinute),
     UrlCandleWeek,
     UrlCandleDay,
     UrlCandleMonth,
+    UrlCandleYear,
 }
 
 impl Display for UrlAssociates {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            UrlAssociates::UrlCandleSecond => {
+                write!(f, "{}", URL_CANDLE_SECOND)
+            }
             UrlAssociates::UrlCandleMinute(minute) => {
                 write!(f, "{URL_CANDLE_MINUTE}{}", Into::<u8>::into(*minute))
             }
@@ -113,18 +190,30 @@ impl Display for UrlAssociates {
             UrlAssociates::UrlCandleMonth => {
                 write!(f, "{}", URL_CANDLE_MONTH)
             }
+            UrlAssociates::UrlCandleYear => {
+                write!(f, "{}", URL_CANDLE_YEAR)
+            }
         }
     }
 }
 
+impl UrlAssociates {
+    /// Get full url of the endpoint on `server_url`
+    pub fn to_url(&self, server_url: &str) -> String {
+        format!("{server_url}{self}")
+    }
+}
+
 /// 호가 정보를 조회한다. (Inquiry bid price and offered price.)
 ///
 /// # Example
 /// ```rust
-/// let order_book_info = api_quotation::get_order_book_info(&["KRW-ETH"]).await;
+/// let order_book_info = api_quotation::get_order_book_info(&["KRW-BTC", "KRW-ETH"]).await;
 /// ```
 /// - parameters
 /// > `markets_id` ex) KRW-ETH<br>
+///  >> *  one [`OrderBookInfo`] is returned per market. lists longer than [`crate::constant::MAX_MARKETS_PER_REQUEST`] are sent in several requests. <br>
+///
 /// # Response
 ///  * orderbook_unit 리스트에는 15호가 정보가 들어가며 차례대로 1호가, 2호가 ... 15호가의 정보를 담고 있습니다.
 ///  * orderbook_unit list contains information of 15 quotes of bid/ask price, in order, 1st, 2nd .. 15th quote
@@ -213,18 +302,20 @@ impl Display for UrlAssociates {
 /// | bid_price | 매수호가 | Double |
 /// | ask_size | 매도 잔량 | Double |
 /// | bid_size | 매수 잔량 | Double |
-pub async fn get_order_book_info(markets_id: &[&str]) -> Result<OrderBookInfo, ResponseError> {
-    OrderBookInfo::get_orderbook_info(markets_id).await
+pub async fn get_order_book_info(markets_id: &[&str]) -> Result<Vec<OrderBookInfo>, ResponseError> {
+    UpbitClient::default().get_order_book_info(markets_id).await
 }
 
 /// 요청 당시 종목의 스냅샷을 반환한다. (Return the snapshot of the ticker at the moment of query.)
 ///
 /// # Example
 /// ```rust
-/// let ticker_snapshot = api_quotation::get_ticker_snapshot(&["KRW-ETH"]).await;
+/// let ticker_snapshot = api_quotation::get_ticker_snapshot(&["KRW-BTC", "KRW-ETH"]).await;
 /// ```
 /// - parameters
-/// > `market_id` ex) KRW-ETH<br>
+/// > `markets_id` ex) KRW-ETH<br>
+///  >> *  one [`TickerSnapshot`] is returned per market. lists longer than [`crate::constant::MAX_MARKETS_PER_REQUEST`] are sent in several requests. <br>
+///
 /// # Response
 /// * 아래 응답의 `change`, `change_price`, `change_rate`, `signed_change_price`, `signed_change_rate` 필드들은 전일종가 대비 값입니다.
 /// * The fields `change`, `change_price`, `change_rate`, `signed_change_price`, and `signed_change_rate` in the response below are values compared to the previous day’s closing price.
@@ -289,20 +380,84 @@ pub async fn get_order_book_info(markets_id: &[&str]) -> Result<OrderBookInfo, R
 /// | lowest_52_week_price | 52주 신저가 | Double |
 /// | lowest_52_week_date | 52주 신저가 달성일 <br> 포맷: yyyy-MM-dd | String |
 /// | timestamp | 타임스탬프 | Long |
-pub async fn get_ticker_snapshot(markets_id: &[&str]) -> Result<TickerSnapshot, ResponseError> {
-    TickerSnapshot::get_ticker_snapshot(markets_id).await
+pub async fn get_ticker_snapshot(
+    markets_id: &[&str],
+) -> Result<Vec<TickerSnapshot>, ResponseError> {
+    UpbitClient::default().get_ticker_snapshot(markets_id).await
+}
+
+/// 마켓 단위 종목들의 스냅샷을 반환한다. (Return the snapshots of every market of quote currencies.)
+///
+/// # Example
+/// ```no_run
+/// use upbit::api_quotation::{self, TickerRank, TickerSnapshot};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut tickers = api_quotation::get_ticker_all(&["KRW"]).await?;
+///
+/// TickerSnapshot::rank(&mut tickers, TickerRank::TradePrice24h);
+///
+/// for ticker in tickers.iter().take(10) {
+///     println!("{} {}", ticker.market, ticker.acc_trade_price_24h);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+/// - parameters
+/// > `quote_currencies` 마켓 기준 화폐 코드. ex) KRW, BTC, USDT<br>
+/// # Response
+/// Each item has the layout of [`get_ticker_snapshot`].
+/// ```json
+/// [
+///   {
+///     "market": "KRW-BTC",
+///     "trade_date": "20180418",
+///     "trade_time": "102340",
+///     "trade_date_kst": "20180418",
+///     "trade_time_kst": "192340",
+///     "trade_timestamp": 1524047020000,
+///     "opening_price": 8450000,
+///     "high_price": 8679000,
+///     "low_price": 8445000,
+///     "trade_price": 8621000,
+///     "prev_closing_price": 8450000,
+///     "change": "RISE",
+///     "change_price": 171000,
+///     "change_rate": 0.0202366864,
+///     "signed_change_price": 171000,
+///     "signed_change_rate": 0.0202366864,
+///     "trade_volume": 0.02467802,
+///     "acc_trade_price": 108024804862.58253,
+///     "acc_trade_price_24h": 232702901371.09308,
+///     "acc_trade_volume": 12603.53386105,
+///     "acc_trade_volume_24h": 27181.31137002,
+///     "highest_52_week_price": 28885000,
+///     "highest_52_week_date": "2018-01-06",
+///     "lowest_52_week_price": 4175000,
+///     "lowest_52_week_date": "2017-09-25",
+///     "timestamp": 1524047026072
+///   },
+///   ...
+/// ]
+/// ```
+pub async fn get_ticker_all(
+    quote_currencies: &[&str],
+) -> Result<Vec<TickerSnapshot>, ResponseError> {
+    UpbitClient::default()
+        .get_ticker_all(quote_currencies)
+        .await
 }
 
 /// 호가 정보를 조회한다. (Inquiry bid price and offered price.)
 ///
 /// # Example
 /// ```rust
-/// let recent_trade_list = api_quotation::get_trade_recent_list("KRW-ETH").await;
+/// let recent_trade_list = api_quotation::get_trade_recent_list("KRW-ETH", None, 10, "0", None).await;
 /// ```
 /// - parameters
 /// > `market_id` ex) KRW-ETH<br>
 /// > `hhmmss` format is "HHmmss" or "HH:mm:ss". if empty, latest data will be retrieved<br>
-/// > `count` count of trade<br>
+/// > `count` count of trade. every trade is returned, latest first<br>
 /// > `cursor` pagenation cursor. (sequential id)<br>
 /// > `days_ago`You can retrieve previous data within 7 days based on the recent transaction date. If left empty, the most recent transaction date is returned. (Range: 1 ~ 7))<br>
 /// # Response
@@ -342,8 +497,10 @@ pub async fn get_trade_recent_list(
     count: i32,
     cursor: &str,
     days_ago: Option<i32>,
-) -> Result<TradeRecent, ResponseError> {
-    TradeRecent::get_trade_recent_list(market_id, hhmmss, count, cursor, days_ago).await
+) -> Result<Vec<TradeRecent>, ResponseError> {
+    UpbitClient::default()
+        .get_trade_recent_list(market_id, hhmmss, count, cursor, days_ago)
+        .await
 }
 
 /// 업비트에서 거래 가능한 마켓 목록 (List of markets available on Upbit)
@@ -373,7 +530,65 @@ pub async fn get_trade_recent_list(
 /// | english_name | 거래 대상 디지털 자산 영문명 | String |
 /// | market_warning | 유의 종목 여부 <br> NONE: (해당 사항 없음), CAUTION(투자유의) | String |
 pub async fn get_market_state(is_detailed: bool) -> Result<Vec<MarketState>, ResponseError> {
-    MarketState::get_market_state(is_detailed).await
+    UpbitClient::default().get_market_state(is_detailed).await
+}
+
+/// 초봉 캔들 데이터를 요청한다. (inquire second-unit candle data.)
+///
+/// # Example
+/// ```no_run
+/// use upbit::api_quotation;
+///
+/// # async fn run() {
+/// let candle_of_second = api_quotation::get_candle_second("KRW-ETH", 10, None).await;
+/// # }
+/// ```
+/// - parameters
+/// > `market_id` ex) KRW-ETH<br>
+/// > `count` the number of candle to request. maximum value: `200`<br>
+/// > `last_candle_time` (optional) the time moment of the last candle (exclusive). if empty, latest candle will be retrived. <br>
+///  >> *  ISO8061 format (yyyy-MM-dd'T'HH:mm:ss'Z' or yyyy-MM-dd HH:mm:ss). <br>
+///  >> *  though it is commonly UTC time criteria, you can request KST time using like 2023-01-01T00:00:00+09:00 format. <br>
+///  >> *  only candles of the latest 3 months are served. <br>
+///
+/// # Response
+/// ```json
+/// [
+///   {
+///     "market": "KRW-BTC",
+///     "candle_date_time_utc": "2025-01-08T03:41:57",
+///     "candle_date_time_kst": "2025-01-08T12:41:57",
+///     "opening_price": 145754000,
+///     "high_price": 145754000,
+///     "low_price": 145754000,
+///     "trade_price": 145754000,
+///     "timestamp": 1736307717829,
+///     "candle_acc_trade_price": 1457540.0,
+///     "candle_acc_trade_volume": 0.01
+///   }
+/// ]
+/// ```
+/// # Response Description
+/// | field             | description                   | type         |
+/// |:------------------|:------------------------------|:-------------|
+/// | market | 마켓명 | String |
+/// | candle_date_time_utc | 캔들 기준 시각(UTC 기준) <br> 포맷: yyyy-MM-dd'T'HH:mm:ss | String |
+/// | candle_date_time_kst | 캔들 기준 시각(KST 기준) <br> 포맷: yyyy-MM-dd'T'HH:mm:ss | String |
+/// | opening_price | 시가 | Double |
+/// | high_price | 고가 | Double |
+/// | low_price | 저가 | Double |
+/// | trade_price | 종가 | Double |
+/// | timestamp | 마지막 틱이 저장된 시각 | Long |
+/// | candle_acc_trade_price | 누적 거래 금액 | Double |
+/// | candle_acc_trade_volume | 누적 거래량 | Double |
+pub async fn get_candle_second(
+    market_id: &str,
+    count: i32,
+    last_candle_time: Option<String>,
+) -> Result<Vec<CandleChartSecond>, ResponseError> {
+    UpbitClient::default()
+        .get_candle_second(market_id, count, last_candle_time)
+        .await
 }
 
 /// 분봉 캔들 데이터를 요청한다. (inquire minute-unit candle data.)
@@ -436,7 +651,9 @@ pub async fn get_candle_minute(
     count: i32,
     candle_minute: CandleMinute,
 ) -> Result<Vec<CandleChartMinute>, ResponseError> {
-    CandleChartMinute::request_candle(market_id, to, count, candle_minute).await
+    UpbitClient::default()
+        .get_candle_minute(market_id, to, count, candle_minute)
+        .await
 }
 
 /// 일봉 캔들 데이터를 요청한다. (inquire day-unit candle data.)
@@ -496,7 +713,9 @@ pub async fn get_candle_day(
     last_candle_time: Option<String>,
     price_unit: Option<String>,
 ) -> Result<Vec<CandleChartDay>, ResponseError> {
-    CandleChartDay::request_candle(market_id, count, last_candle_time, price_unit).await
+    UpbitClient::default()
+        .get_candle_day(market_id, count, last_candle_time, price_unit)
+        .await
 }
 
 /// 주봉 캔들 데이터를 요청한다. (inquire week-unit candle data.)
@@ -549,7 +768,9 @@ pub async fn get_candle_week(
     count: i32,
     last_candle_time: Option<String>,
 ) -> Result<Vec<CandleChartWeek>, ResponseError> {
-    CandleChartWeek::request_candle(market_id, count, last_candle_time).await
+    UpbitClient::default()
+        .get_candle_week(market_id, count, last_candle_time)
+        .await
 }
 
 /// 월봉 캔들 데이터를 요청한다. (inquire month-unit candle data.)
@@ -602,5 +823,312 @@ pub async fn get_candle_month(
     count: i32,
     last_candle_time: Option<String>,
 ) -> Result<Vec<CandleChartMonth>, ResponseError> {
-    CandleChartMonth::request_candle(market_id, count, last_candle_time).await
+    UpbitClient::default()
+        .get_candle_month(market_id, count, last_candle_time)
+        .await
+}
+
+/// 연봉 캔들 데이터를 요청한다. (inquire year-unit candle data.)
+///
+/// # Example
+/// ```no_run
+/// use upbit::api_quotation;
+///
+/// # async fn run() {
+/// let candle_of_year = api_quotation::get_candle_year("KRW-ETH", 10, None).await;
+/// # }
+/// ```
+/// - parameters
+/// > `market_id` ex) KRW-ETH<br>
+/// > `count` the number of candle to request. maximum value: `200`<br>
+/// > `last_candle_time` (optional) the time moment of the last candle (exclusive). if empty, latest candle will be retrived. <br>
+///  >> *  ISO8061 format (yyyy-MM-dd'T'HH:mm:ss'Z' or yyyy-MM-dd HH:mm:ss). <br>
+///  >> *  though it is commonly UTC time criteria, you can request KST time using like 2023-01-01T00:00:00+09:00 format. <br>
+///
+/// # Response
+/// ```json
+/// [
+///   {
+///     "market": "KRW-BTC",
+///     "candle_date_time_utc": "2025-01-01T00:00:00",
+///     "candle_date_time_kst": "2025-01-01T09:00:00",
+///     "opening_price": 140887000,
+///     "high_price": 148000000,
+///     "low_price": 139900000,
+///     "trade_price": 145754000,
+///     "timestamp": 1736307717829,
+///     "candle_acc_trade_price": 2133834089462.1035,
+///     "candle_acc_trade_volume": 14788.56818495,
+///     "first_day_of_period": "2025-01-01"
+///   }
+/// ]
+/// ```
+/// # Response Description
+/// | field             | description                   | type         |
+/// |:------------------|:------------------------------|:-------------|
+/// | market | 마켓명 | String |
+/// | candle_date_time_utc | 캔들 기준 시각(UTC 기준) <br> 포맷: yyyy-MM-dd'T'HH:mm:ss | String |
+/// | candle_date_time_kst | 캔들 기준 시각(KST 기준) <br> 포맷: yyyy-MM-dd'T'HH:mm:ss | String |
+/// | opening_price | 시가 | Double |
+/// | high_price | 고가 | Double |
+/// | low_price | 저가 | Double |
+/// | trade_price | 종가 | Double |
+/// | timestamp | 마지막 틱이 저장된 시각 | Long |
+/// | candle_acc_trade_price | 누적 거래 금액 | Double |
+/// | candle_acc_trade_volume | 누적 거래량 | Double |
+/// | first_day_of_period | 캔들 기간의 가장 첫 날 | String |
+pub async fn get_candle_year(
+    market_id: &str,
+    count: i32,
+    last_candle_time: Option<String>,
+) -> Result<Vec<CandleChartYear>, ResponseError> {
+    UpbitClient::default()
+        .get_candle_year(market_id, count, last_candle_time)
+        .await
+}
+
+/// 캔들 데이터를 요청한다. (inquire candle data of any interval.)
+///
+/// # Example
+/// ```no_run
+/// use upbit::api_quotation::{self, CandleInterval, CandleMinute};
+/// use upbit::Number;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let candles = api_quotation::get_candles("KRW-ETH", CandleInterval::Minute(CandleMinute::Min15), 50, None).await?;
+/// let closes: Vec<Number> = candles.iter().map(|x| x.trade_price).collect();
+///
+/// let days = api_quotation::get_candles("KRW-ETH", CandleInterval::Day, 10, None).await?;
+/// let prev_closing_price = days[0].prev_closing_price();
+/// # Ok(())
+/// # }
+/// ```
+/// - parameters
+/// > `market_id` ex) KRW-ETH<br>
+/// > `interval` interval of the candles
+///  >> *  `CandleInterval::Second`<br>
+///  >> *  `CandleInterval::Minute(CandleMinute::Min1)` ... `CandleInterval::Minute(CandleMinute::Min240)`<br>
+///  >> *  `CandleInterval::Day`<br>
+///  >> *  `CandleInterval::Week`<br>
+///  >> *  `CandleInterval::Month`<br>
+///  >> *  `CandleInterval::Year`<br>
+///
+/// > `count` the number of candle to request. maximum value: `200`<br>
+/// > `to` (optional) the time moment of the last candle (exclusive). if empty, latest candle will be retrived. <br>
+///  >> format: `yyyy-MM-dd'T'HH:mm:ss'Z'` or `yyyy-MM-dd HH:mm:ss`.<br>
+/// # Response
+/// * OHLCV and times of [`get_candle_second`], [`get_candle_minute`], [`get_candle_day`], [`get_candle_week`], [`get_candle_month`] or [`get_candle_year`] in a single [`Candle`] type.
+/// * fields only some intervals have are in [`Candle::extra`].
+/// * day candles are requested without `price_unit`.
+/// * `CandleInterval::Resampled` is not served and returns `InvalidParameter`. see [`resample_candles`].
+pub async fn get_candles(
+    market_id: &str,
+    interval: CandleInterval,
+    count: i32,
+    to: Option<String>,
+) -> Result<Vec<Candle>, ResponseError> {
+    UpbitClient::default()
+        .get_candles(market_id, interval, count, to)
+        .await
+}
+
+/// 기간 내의 캔들 데이터를 모두 요청한다. (inquire every candle of a period.)
+///
+/// # Example
+/// ```no_run
+/// use chrono::Utc;
+/// use futures_util::TryStreamExt;
+/// use upbit::api_quotation::{self, Candle, CandleChartMinute, CandleChartWeek, CandleInterval, CandleMinute};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let to = Utc::now();
+/// let from = to - chrono::Duration::days(7);
+///
+/// let candles: Vec<CandleChartMinute> =
+///     api_quotation::get_candle_range("KRW-ETH", CandleMinute::Min1, from, to)
+///         .try_collect()
+///         .await?;
+///
+/// let weeks = api_quotation::get_candle_range::<CandleChartWeek>("KRW-ETH", (), from, to);
+/// let hours = api_quotation::get_candle_range::<Candle>("KRW-ETH", CandleInterval::Minute(CandleMinute::Min60), from, to);
+/// # Ok(())
+/// # }
+/// ```
+/// - parameters
+/// > `market_id` ex) KRW-ETH<br>
+/// > `unit` series of the candle type. see [`CandleChart::Unit`]
+///  >> *  [`CandleChartMinute`]: `CandleMinute::Min1` ... `CandleMinute::Min240`<br>
+///  >> *  [`CandleChartSecond`], [`CandleChartDay`], [`CandleChartWeek`], [`CandleChartMonth`], [`CandleChartYear`]: `()`<br>
+///  >> *  [`Candle`]: `CandleInterval::Day`, `CandleInterval::Minute(CandleMinute::Min1)`, ...<br>
+///
+/// > `from` start of the period (inclusive)<br>
+/// > `to` end of the period (exclusive)<br>
+/// # Stream
+/// * candles starting in `from..to`, latest first, each yielded once.
+/// * pages of [`candle_range::CANDLE_PAGE_SIZE`] candles are requested as the stream is polled, moving `to` back to the oldest candle received.
+/// * requests go through the rate limiter of the client, or the default quota of the `candles` group if the client has none.
+/// * the stream ends after yielding an error.
+pub fn get_candle_range<C: CandleChart>(
+    market_id: &str,
+    unit: C::Unit,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> BoxStream<'static, Result<C, ResponseError>> {
+    UpbitClient::default().get_candle_range(market_id, unit, from, to)
+}
+
+impl UpbitClient {
+    /// 호가 정보를 조회한다. (Inquiry bid price and offered price.)
+    ///
+    /// See [`get_order_book_info`].
+    pub async fn get_order_book_info(
+        &self,
+        markets_id: &[&str],
+    ) -> Result<Vec<OrderBookInfo>, ResponseError> {
+        OrderBookInfo::get_orderbook_info(self, markets_id).await
+    }
+
+    /// 요청 당시 종목의 스냅샷을 반환한다. (Return the snapshot of the ticker at the moment of query.)
+    ///
+    /// See [`get_ticker_snapshot`].
+    pub async fn get_ticker_snapshot(
+        &self,
+        markets_id: &[&str],
+    ) -> Result<Vec<TickerSnapshot>, ResponseError> {
+        TickerSnapshot::get_ticker_snapshot(self, markets_id).await
+    }
+
+    /// 마켓 단위 종목들의 스냅샷을 반환한다. (Return the snapshots of every market of quote currencies.)
+    ///
+    /// See [`get_ticker_all`].
+    pub async fn get_ticker_all(
+        &self,
+        quote_currencies: &[&str],
+    ) -> Result<Vec<TickerSnapshot>, ResponseError> {
+        TickerSnapshot::get_ticker_all(self, quote_currencies).await
+    }
+
+    /// 호가 정보를 조회한다. (Inquiry bid price and offered price.)
+    ///
+    /// See [`get_trade_recent_list`].
+    pub async fn get_trade_recent_list(
+        &self,
+        market_id: &str,
+        hhmmss: Option<&str>,
+        count: i32,
+        cursor: &str,
+        days_ago: Option<i32>,
+    ) -> Result<Vec<TradeRecent>, ResponseError> {
+        TradeRecent::get_trade_recent_list(self, market_id, hhmmss, count, cursor, days_ago).await
+    }
+
+    /// 업비트에서 거래 가능한 마켓 목록 (List of markets available on Upbit)
+    ///
+    /// See [`get_market_state`].
+    pub async fn get_market_state(
+        &self,
+        is_detailed: bool,
+    ) -> Result<Vec<MarketState>, ResponseError> {
+        MarketState::get_market_state(self, is_detailed).await
+    }
+
+    /// 초봉 캔들 데이터를 요청한다. (inquire second-unit candle data.)
+    ///
+    /// See [`get_candle_second`].
+    pub async fn get_candle_second(
+        &self,
+        market_id: &str,
+        count: i32,
+        last_candle_time: Option<String>,
+    ) -> Result<Vec<CandleChartSecond>, ResponseError> {
+        CandleChartSecond::request_candle(self, market_id, count, last_candle_time).await
+    }
+
+    /// 분봉 캔들 데이터를 요청한다. (inquire minute-unit candle data.)
+    ///
+    /// See [`get_candle_minute`].
+    pub async fn get_candle_minute(
+        &self,
+        market_id: &str,
+        to: Option<String>,
+        count: i32,
+        candle_minute: CandleMinute,
+    ) -> Result<Vec<CandleChartMinute>, ResponseError> {
+        CandleChartMinute::request_candle(self, market_id, to, count, candle_minute).await
+    }
+
+    /// 일봉 캔들 데이터를 요청한다. (inquire day-unit candle data.)
+    ///
+    /// See [`get_candle_day`].
+    pub async fn get_candle_day(
+        &self,
+        market_id: &str,
+        count: i32,
+        last_candle_time: Option<String>,
+        price_unit: Option<String>,
+    ) -> Result<Vec<CandleChartDay>, ResponseError> {
+        CandleChartDay::request_candle(self, market_id, count, last_candle_time, price_unit).await
+    }
+
+    /// 주봉 캔들 데이터를 요청한다. (inquire week-unit candle data.)
+    ///
+    /// See [`get_candle_week`].
+    pub async fn get_candle_week(
+        &self,
+        market_id: &str,
+        count: i32,
+        last_candle_time: Option<String>,
+    ) -> Result<Vec<CandleChartWeek>, ResponseError> {
+        CandleChartWeek::request_candle(self, market_id, count, last_candle_time).await
+    }
+
+    /// 월봉 캔들 데이터를 요청한다. (inquire month-unit candle data.)
+    ///
+    /// See [`get_candle_month`].
+    pub async fn get_candle_month(
+        &self,
+        market_id: &str,
+        count: i32,
+        last_candle_time: Option<String>,
+    ) -> Result<Vec<CandleChartMonth>, ResponseError> {
+        CandleChartMonth::request_candle(self, market_id, count, last_candle_time).await
+    }
+
+    /// 연봉 캔들 데이터를 요청한다. (inquire year-unit candle data.)
+    ///
+    /// See [`get_candle_year`].
+    pub async fn get_candle_year(
+        &self,
+        market_id: &str,
+        count: i32,
+        last_candle_time: Option<String>,
+    ) -> Result<Vec<CandleChartYear>, ResponseError> {
+        CandleChartYear::request_candle(self, market_id, count, last_candle_time).await
+    }
+
+    /// 캔들 데이터를 요청한다. (inquire candle data of any interval.)
+    ///
+    /// See [`get_candles`].
+    pub async fn get_candles(
+        &self,
+        market_id: &str,
+        interval: CandleInterval,
+        count: i32,
+        to: Option<String>,
+    ) -> Result<Vec<Candle>, ResponseError> {
+        Candle::request_candle(self, market_id, interval, count, to).await
+    }
+
+    /// 기간 내의 캔들 데이터를 모두 요청한다. (inquire every candle of a period.)
+    ///
+    /// See [`get_candle_range`].
+    pub fn get_candle_range<C: CandleChart>(
+        &self,
+        market_id: &str,
+        unit: C::Unit,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> BoxStream<'static, Result<C, ResponseError>> {
+        candle_range::stream_candle_range(self, market_id, unit, from, to)
+    }
 }
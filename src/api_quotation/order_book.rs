@@ -1,85 +1,66 @@
+use crate::number::Number;
 use crate::response::ResponseError;
 
-use super::super::constant::{URL_ORDERBOOK, URL_SERVER};
+use super::super::constant::{MAX_MARKETS_PER_REQUEST, URL_ORDERBOOK};
 
+use crate::client::UpbitClient;
 use reqwest::header::ACCEPT;
 use reqwest::{Response, Url};
 use serde::Deserialize;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct OrderBookInfo {
-    market: String,
-    timestamp: i64,
-    total_ask_size: f64,
-    total_bid_size: f64,
-    orderbook_units: Vec<OrderBookUnit>,
+    pub market: String,
+    pub timestamp: i64,
+    pub total_ask_size: Number,
+    pub total_bid_size: Number,
+    /// price unit the units are aggregated by. 0 when not aggregated
+    #[serde(default)]
+    pub level: Number,
+    pub orderbook_units: Vec<OrderBookUnit>,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct OrderBookUnit {
-    ask_price: f64,
-    bid_price: f64,
-    ask_size: f64,
-    bid_size: f64,
+    pub ask_price: Number,
+    pub bid_price: Number,
+    pub ask_size: Number,
+    pub bid_size: Number,
 }
 
 impl OrderBookInfo {
-    pub async fn get_orderbook_info(markets_id: &[&str]) -> Result<Self, ResponseError> {
-        let res = Self::request(markets_id).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
+    pub async fn get_orderbook_info(
+        client: &UpbitClient,
+        markets_id: &[&str],
+    ) -> Result<Vec<Self>, ResponseError> {
+        let mut orderbooks = Vec::with_capacity(markets_id.len());
+
+        for markets_id in markets_id.chunks(MAX_MARKETS_PER_REQUEST) {
+            let res = Self::request(client, markets_id).await?;
+            let res_serialized = crate::response::response_text(res).await?;
+            let chunk: Vec<Self> = serde_json::from_str(&res_serialized)
+                .map_err(crate::response::response_error_from_json)?;
+
+            orderbooks.extend(chunk);
         }
 
-        serde_json::from_str(&res_serialized)
-            .map(|mut i: Vec<Self>| {
-                let x = i.pop().ok_or_else(|| crate::response::ResponseError {
-                    state: crate::response::ResponseErrorState::CustomErrorNoDataPresent,
-                    error: crate::response::ResponseErrorBody {
-                        name: "custom_error_no_data_present".to_owned(),
-                        message: "No data present in the response".to_owned(),
-                    },
-                })?;
-
-                Ok(Self {
-                    market: x.market,
-                    timestamp: x.timestamp,
-                    total_ask_size: x.total_ask_size,
-                    total_bid_size: x.total_bid_size,
-                    orderbook_units: x
-                        .orderbook_units
-                        .into_iter()
-                        .map(|unit| OrderBookUnit {
-                            ask_price: unit.ask_price,
-                            bid_price: unit.bid_price,
-                            ask_size: unit.ask_size,
-                            bid_size: unit.bid_size,
-                        })
-                        .collect(),
-                })
-            })
-            .map_err(crate::response::response_error_from_json)?
+        Ok(orderbooks)
     }
 
-    async fn request(markets_id: &[&str]) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_ORDERBOOK}"))
+    async fn request(client: &UpbitClient, markets_id: &[&str]) -> Result<Response, ResponseError> {
+        let mut url = Url::parse(&format!("{}{URL_ORDERBOOK}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
         url.query_pairs_mut()
             .append_pair("markets", &markets_id.join(","));
 
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .send()
+        client
+            .send(
+                client
+                    .http
+                    .get(url.as_str())
+                    .header(ACCEPT, "application/json"),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -90,13 +71,18 @@ mod tests {
     use serde_json::{json, Value};
 
     use crate::api_quotation::order_book::OrderBookInfo;
+    use crate::number::Number;
+
+    use crate::client::UpbitClient;
 
     #[tokio::test]
     async fn test_get_order_book() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
-        let res = OrderBookInfo::request(&["KRW-ETH"]).await.unwrap();
+        let res = OrderBookInfo::request(&client, &["KRW-ETH"]).await.unwrap();
         let res_serialized = res
             .text()
             .await
@@ -104,7 +90,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -137,11 +123,10 @@ mod tests {
                     compare_keys(item, &expected_structure, &format!("item[{}].", index));
 
                 if !missing_keys.is_empty() {
-                    println!(
+                    panic!(
                         "[test_get_order_status_closed] Missing keys in item[{}]: {:?}",
                         index, missing_keys
                     );
-                    assert!(false);
                 } else {
                     println!(
                         "[test_get_order_status_closed] No keys are missing in item[{}]",
@@ -150,11 +135,10 @@ mod tests {
                 }
 
                 if !extra_keys.is_empty() {
-                    println!(
+                    panic!(
                         "[test_get_order_status_closed] Extra keys in item[{}]: {:?}",
                         index, extra_keys
                     );
-                    assert!(false);
                 } else {
                     println!(
                         "[test_get_order_status_closed] No extra keys found in item[{}]",
@@ -163,10 +147,39 @@ mod tests {
                 }
             }
         } else {
-            assert!(false, "Expected an array of objects in the response");
+            panic!("Expected an array of objects in the response");
         }
+    }
+
+    #[tokio::test]
+    async fn test_get_order_book_returns_every_market() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/orderbook"))
+            .and(query_param("markets", "KRW-BTC,KRW-ETH"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"[{"market":"KRW-BTC","timestamp":1529910247984,"total_ask_size":8.83621228,"total_bid_size":2.43976741,"orderbook_units":[{"ask_price":6956000,"bid_price":6954000,"ask_size":0.24078656,"bid_size":0.00718341}],"level":0},{"market":"KRW-ETH","timestamp":1529910247984,"total_ask_size":1.5,"total_bid_size":2.5,"orderbook_units":[{"ask_price":3000000,"bid_price":2999000,"ask_size":1.5,"bid_size":2.5}],"level":1000}]"#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key").with_server_url(&server.uri());
+        let orderbooks = OrderBookInfo::get_orderbook_info(&client, &["KRW-BTC", "KRW-ETH"])
+            .await
+            .unwrap();
 
-        assert!(true);
+        assert_eq!(orderbooks.len(), 2);
+        assert_eq!(orderbooks[0].market, "KRW-BTC");
+        assert_eq!(orderbooks[1].market, "KRW-ETH");
+        assert_eq!(orderbooks[1].level, Number::from(1000));
+        assert_eq!(
+            orderbooks[1].orderbook_units[0].bid_size,
+            "2.5".parse::<Number>().unwrap()
+        );
     }
 
     fn compare_keys(
@@ -178,12 +191,12 @@ mod tests {
         let mut extra_keys = Vec::new();
 
         if let Some(actual_map) = json.as_object() {
-            for (key, _) in expected {
+            for key in expected.keys() {
                 if !actual_map.contains_key(*key) {
                     missing_keys.push(format!("{}{}", path, key));
                 }
             }
-            for (key, _) in actual_map {
+            for key in actual_map.keys() {
                 if !expected.contains_key(key.as_str()) {
                     extra_keys.push(format!("{}{}", path, key));
                 }
@@ -1,13 +1,15 @@
+use crate::number::Number;
 use crate::response::ResponseError;
 
-use super::super::constant::{URL_SERVER, URL_TICKER};
-use super::SnapshotChangeType;
+use super::super::constant::{MAX_MARKETS_PER_REQUEST, URL_TICKER, URL_TICKER_ALL};
+use super::{SnapshotChangeType, TickerRank};
 
+use crate::client::UpbitClient;
 use reqwest::header::ACCEPT;
 use reqwest::Url;
 use serde::Deserialize;
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct TickerSnapshot {
     pub market: String,
     pub trade_date: String,
@@ -15,24 +17,24 @@ pub struct TickerSnapshot {
     pub trade_date_kst: String,
     pub trade_time_kst: String,
     pub trade_timestamp: i64,
-    pub opening_price: f64,
-    pub high_price: f64,
-    pub low_price: f64,
-    pub trade_price: f64,
-    pub prev_closing_price: f64,
+    pub opening_price: Number,
+    pub high_price: Number,
+    pub low_price: Number,
+    pub trade_price: Number,
+    pub prev_closing_price: Number,
     pub change: SnapshotChangeType, //EVEN, RISE, FALL
-    pub change_price: f64,
-    pub change_rate: f64,
-    pub signed_change_price: f64,
-    pub signed_change_rate: f64,
-    pub trade_volume: f64,
-    pub acc_trade_price: f64,
-    pub acc_trade_price_24h: f64,
-    pub acc_trade_volume: f64,
-    pub acc_trade_volume_24h: f64,
-    pub highest_52_week_price: f64,
+    pub change_price: Number,
+    pub change_rate: Number,
+    pub signed_change_price: Number,
+    pub signed_change_rate: Number,
+    pub trade_volume: Number,
+    pub acc_trade_price: Number,
+    pub acc_trade_price_24h: Number,
+    pub acc_trade_volume: Number,
+    pub acc_trade_volume_24h: Number,
+    pub highest_52_week_price: Number,
     pub highest_52_week_date: String,
-    pub lowest_52_week_price: f64,
+    pub lowest_52_week_price: Number,
     pub lowest_52_week_date: String,
     pub timestamp: i64,
 }
@@ -45,97 +47,143 @@ pub struct TickerSnapshotSource {
     trade_date_kst: String,
     trade_time_kst: String,
     trade_timestamp: i64,
-    opening_price: f64,
-    high_price: f64,
-    low_price: f64,
-    trade_price: f64,
-    prev_closing_price: f64,
+    opening_price: Number,
+    high_price: Number,
+    low_price: Number,
+    trade_price: Number,
+    prev_closing_price: Number,
     change: String, //EVEN, RISE, FALL
-    change_price: f64,
-    change_rate: f64,
-    signed_change_price: f64,
-    signed_change_rate: f64,
-    trade_volume: f64,
-    acc_trade_price: f64,
-    acc_trade_price_24h: f64,
-    acc_trade_volume: f64,
-    acc_trade_volume_24h: f64,
-    highest_52_week_price: f64,
+    change_price: Number,
+    change_rate: Number,
+    signed_change_price: Number,
+    signed_change_rate: Number,
+    trade_volume: Number,
+    acc_trade_price: Number,
+    acc_trade_price_24h: Number,
+    acc_trade_volume: Number,
+    acc_trade_volume_24h: Number,
+    highest_52_week_price: Number,
     highest_52_week_date: String,
-    lowest_52_week_price: f64,
+    lowest_52_week_price: Number,
     lowest_52_week_date: String,
     timestamp: i64,
 }
 
+impl From<TickerSnapshotSource> for TickerSnapshot {
+    fn from(x: TickerSnapshotSource) -> Self {
+        Self {
+            market: x.market,
+            trade_date: x.trade_date,
+            trade_time: x.trade_time,
+            trade_date_kst: x.trade_date_kst,
+            trade_time_kst: x.trade_time_kst,
+            trade_timestamp: x.trade_timestamp,
+            opening_price: x.opening_price,
+            high_price: x.high_price,
+            low_price: x.low_price,
+            trade_price: x.trade_price,
+            prev_closing_price: x.prev_closing_price,
+            change: SnapshotChangeType::parse_or_unknown(&x.change),
+            change_price: x.change_price,
+            change_rate: x.change_rate,
+            signed_change_price: x.signed_change_price,
+            signed_change_rate: x.signed_change_rate,
+            trade_volume: x.trade_volume,
+            acc_trade_price: x.acc_trade_price,
+            acc_trade_price_24h: x.acc_trade_price_24h,
+            acc_trade_volume: x.acc_trade_volume,
+            acc_trade_volume_24h: x.acc_trade_volume_24h,
+            highest_52_week_price: x.highest_52_week_price,
+            highest_52_week_date: x.highest_52_week_date,
+            lowest_52_week_price: x.lowest_52_week_price,
+            lowest_52_week_date: x.lowest_52_week_date,
+            timestamp: x.timestamp,
+        }
+    }
+}
+
 impl TickerSnapshot {
-    pub async fn get_ticker_snapshot(markets_id: &[&str]) -> Result<Self, ResponseError> {
-        let res = Self::request(markets_id).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
+    pub async fn get_ticker_snapshot(
+        client: &UpbitClient,
+        markets_id: &[&str],
+    ) -> Result<Vec<Self>, ResponseError> {
+        let mut ticker_snapshots = Vec::with_capacity(markets_id.len());
 
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
+        for markets_id in markets_id.chunks(MAX_MARKETS_PER_REQUEST) {
+            let res = Self::request(client, markets_id).await?;
+            let res_serialized = crate::response::response_text(res).await?;
+            let sources: Vec<TickerSnapshotSource> = serde_json::from_str(&res_serialized)
+                .map_err(crate::response::response_error_from_json)?;
+
+            ticker_snapshots.extend(sources.into_iter().map(Self::from));
         }
 
-        serde_json::from_str(&res_serialized)
-            .map(|mut i: Vec<TickerSnapshotSource>| {
-                let x = i.pop().ok_or_else(|| crate::response::ResponseError {
-                    state: crate::response::ResponseErrorState::CustomErrorNoDataPresent,
-                    error: crate::response::ResponseErrorBody {
-                        name: "custom_error_no_data_present".to_owned(),
-                        message: "No data present in the response".to_owned(),
-                    },
-                })?;
-
-                Ok(Self {
-                    market: x.market,
-                    trade_date: x.trade_date,
-                    trade_time: x.trade_time,
-                    trade_date_kst: x.trade_date_kst,
-                    trade_time_kst: x.trade_time_kst,
-                    trade_timestamp: x.trade_timestamp,
-                    opening_price: x.opening_price,
-                    high_price: x.high_price,
-                    low_price: x.low_price,
-                    trade_price: x.trade_price,
-                    prev_closing_price: x.prev_closing_price,
-                    change: x.change.as_str().into(),
-                    change_price: x.change_price,
-                    change_rate: x.change_rate,
-                    signed_change_price: x.signed_change_price,
-                    signed_change_rate: x.signed_change_rate,
-                    trade_volume: x.trade_volume,
-                    acc_trade_price: x.acc_trade_price,
-                    acc_trade_price_24h: x.acc_trade_price_24h,
-                    acc_trade_volume: x.acc_trade_volume,
-                    acc_trade_volume_24h: x.acc_trade_volume_24h,
-                    highest_52_week_price: x.highest_52_week_price,
-                    highest_52_week_date: x.highest_52_week_date,
-                    lowest_52_week_price: x.lowest_52_week_price,
-                    lowest_52_week_date: x.lowest_52_week_date,
-                    timestamp: x.timestamp,
-                })
-            })
-            .map_err(crate::response::response_error_from_json)?
+        Ok(ticker_snapshots)
+    }
+
+    pub async fn get_ticker_all(
+        client: &UpbitClient,
+        quote_currencies: &[&str],
+    ) -> Result<Vec<Self>, ResponseError> {
+        let res = Self::request_all(client, quote_currencies).await?;
+        let res_serialized = crate::response::response_text(res).await?;
+        let sources: Vec<TickerSnapshotSource> = serde_json::from_str(&res_serialized)
+            .map_err(crate::response::response_error_from_json)?;
+
+        Ok(sources.into_iter().map(Self::from).collect())
+    }
+
+    /// Sort `tickers` by `rank`, highest first
+    pub fn rank(tickers: &mut [Self], rank: TickerRank) {
+        let key = |x: &Self| match rank {
+            TickerRank::TradePrice24h => x.acc_trade_price_24h,
+            TickerRank::ChangeRate => x.change_rate,
+            TickerRank::SignedChangeRate => x.signed_change_rate,
+        };
+
+        tickers.sort_by(|a, b| {
+            key(b)
+                .partial_cmp(&key(a))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
     }
 
-    async fn request(markets_id: &[&str]) -> Result<reqwest::Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_TICKER}"))
+    async fn request_all(
+        client: &UpbitClient,
+        quote_currencies: &[&str],
+    ) -> Result<reqwest::Response, ResponseError> {
+        let mut url = Url::parse(&format!("{}{URL_TICKER_ALL}", client.server_url))
+            .map_err(crate::response::response_error_internal_url_parse_error)?;
+        url.query_pairs_mut()
+            .append_pair("quote_currencies", &quote_currencies.join(","));
+
+        client
+            .send(
+                client
+                    .http
+                    .get(url.as_str())
+                    .header(ACCEPT, "application/json"),
+            )
+            .await
+    }
+
+    async fn request(
+        client: &UpbitClient,
+        markets_id: &[&str],
+    ) -> Result<reqwest::Response, ResponseError> {
+        let mut url = Url::parse(&format!("{}{URL_TICKER}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
         url.query_pairs_mut()
             .append_pair("markets", &markets_id.join(","));
 
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .send()
+        client
+            .send(
+                client
+                    .http
+                    .get(url.as_str())
+                    .header(ACCEPT, "application/json"),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -149,10 +197,14 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_ticker_snapshot() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
-        let res = TickerSnapshot::request(&["KRW-ETH"]).await.unwrap();
+        let res = TickerSnapshot::request(&client, &["KRW-ETH"])
+            .await
+            .unwrap();
         let res_serialized = res
             .text()
             .await
@@ -160,7 +212,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -206,11 +258,10 @@ mod tests {
                     compare_keys(item, &expected_structure, &format!("item[{}].", index));
 
                 if !missing_keys.is_empty() {
-                    println!(
+                    panic!(
                         "[test_get_ticker_snapshot] Missing keys in item[{}]: {:?}",
                         index, missing_keys
                     );
-                    assert!(false);
                 } else {
                     println!(
                         "[test_get_ticker_snapshot] No keys are missing in item[{}]",
@@ -219,11 +270,10 @@ mod tests {
                 }
 
                 if !extra_keys.is_empty() {
-                    println!(
+                    panic!(
                         "[test_get_ticker_snapshot] Extra keys in item[{}]: {:?}",
                         index, extra_keys
                     );
-                    assert!(false);
                 } else {
                     println!(
                         "[test_get_ticker_snapshot] No extra keys found in item[{}]",
@@ -232,10 +282,108 @@ mod tests {
                 }
             }
         } else {
-            assert!(false, "Expected an array of objects in the response");
+            panic!("Expected an array of objects in the response");
         }
+    }
+
+    #[tokio::test]
+    async fn test_get_ticker_snapshot_returns_every_market() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+        const TICKER: &str = r#"{"market":"","trade_date":"20180418","trade_time":"102340","trade_date_kst":"20180418","trade_time_kst":"192340","trade_timestamp":1524047020000,"opening_price":8450000,"high_price":8679000,"low_price":8445000,"trade_price":8621000,"prev_closing_price":8450000,"change":"RISE","change_price":171000,"change_rate":0.0202366864,"signed_change_price":171000,"signed_change_rate":0.0202366864,"trade_volume":0.02467802,"acc_trade_price":108024804862.58253,"acc_trade_price_24h":232702901371.09308,"acc_trade_volume":12603.53386105,"acc_trade_volume_24h":27181.31137002,"highest_52_week_price":28885000,"highest_52_week_date":"2018-01-06","lowest_52_week_price":4175000,"lowest_52_week_date":"2017-09-25","timestamp":1524047026072}"#;
+
+        // answers a ticker for each requested market
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/ticker"))
+            .respond_with(|request: &Request| {
+                let markets = request
+                    .url
+                    .query_pairs()
+                    .find(|(k, _)| k == "markets")
+                    .map(|(_, v)| v.into_owned())
+                    .unwrap_or_default();
+                let body = markets
+                    .split(',')
+                    .map(|market| {
+                        TICKER.replace(r#""market":"""#, &format!(r#""market":"{market}""#))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                ResponseTemplate::new(200).set_body_string(format!("[{body}]"))
+            })
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key").with_server_url(&server.uri());
+        let markets = (0..MAX_MARKETS_PER_REQUEST + 20)
+            .map(|i| format!("KRW-C{i}"))
+            .collect::<Vec<_>>();
+        let markets = markets.iter().map(String::as_str).collect::<Vec<_>>();
+
+        let ticker_snapshots = TickerSnapshot::get_ticker_snapshot(&client, &markets)
+            .await
+            .unwrap();
+
+        assert_eq!(ticker_snapshots.len(), markets.len());
+        assert_eq!(ticker_snapshots[0].market, "KRW-C0");
+        assert_eq!(
+            ticker_snapshots.last().unwrap().market,
+            format!("KRW-C{}", MAX_MARKETS_PER_REQUEST + 19)
+        );
+        assert_eq!(ticker_snapshots[1].change, SnapshotChangeType::Rise);
+    }
+
+    #[tokio::test]
+    async fn test_get_ticker_all_and_rank() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let ticker = |market: &str, acc_trade_price_24h: &str, signed_change_rate: &str| {
+            format!(
+                r#"{{"market":"{market}","trade_date":"20180418","trade_time":"102340","trade_date_kst":"20180418","trade_time_kst":"192340","trade_timestamp":1524047020000,"opening_price":8450000,"high_price":8679000,"low_price":8445000,"trade_price":8621000,"prev_closing_price":8450000,"change":"RISE","change_price":171000,"change_rate":{},"signed_change_price":171000,"signed_change_rate":{signed_change_rate},"trade_volume":0.02467802,"acc_trade_price":108024804862.58253,"acc_trade_price_24h":{acc_trade_price_24h},"acc_trade_volume":12603.53386105,"acc_trade_volume_24h":27181.31137002,"highest_52_week_price":28885000,"highest_52_week_date":"2018-01-06","lowest_52_week_price":4175000,"lowest_52_week_date":"2017-09-25","timestamp":1524047026072}}"#,
+                signed_change_rate.trim_start_matches('-')
+            )
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/ticker/all"))
+            .and(query_param("quote_currencies", "KRW,BTC"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(format!(
+                "[{},{},{}]",
+                ticker("KRW-BTC", "300000000000", "0.01"),
+                ticker("KRW-ETH", "100000000000", "-0.05"),
+                ticker("BTC-XRP", "10", "0.03"),
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key").with_server_url(&server.uri());
+        let mut tickers = TickerSnapshot::get_ticker_all(&client, &["KRW", "BTC"])
+            .await
+            .unwrap();
+        let markets = |tickers: &[TickerSnapshot]| {
+            tickers
+                .iter()
+                .map(|x| x.market.to_owned())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(tickers.len(), 3);
+
+        TickerSnapshot::rank(&mut tickers, TickerRank::TradePrice24h);
+        assert_eq!(markets(&tickers), ["KRW-BTC", "KRW-ETH", "BTC-XRP"]);
+
+        TickerSnapshot::rank(&mut tickers, TickerRank::ChangeRate);
+        assert_eq!(markets(&tickers), ["KRW-ETH", "BTC-XRP", "KRW-BTC"]);
 
-        assert!(true);
+        TickerSnapshot::rank(&mut tickers, TickerRank::SignedChangeRate);
+        assert_eq!(markets(&tickers), ["BTC-XRP", "KRW-BTC", "KRW-ETH"]);
     }
 
     fn compare_keys(
@@ -247,12 +395,12 @@ mod tests {
         let mut extra_keys = Vec::new();
 
         if let Some(actual_map) = json.as_object() {
-            for (key, _) in expected {
+            for key in expected.keys() {
                 if !actual_map.contains_key(*key) {
                     missing_keys.push(format!("{}{}", path, key));
                 }
             }
-            for (key, _) in actual_map {
+            for key in actual_map.keys() {
                 if !expected.contains_key(key.as_str()) {
                     extra_keys.push(format!("{}{}", path, key));
                 }
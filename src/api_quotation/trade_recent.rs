@@ -1,80 +1,51 @@
+use crate::number::Number;
 use crate::response::ResponseError;
 
-use super::super::constant::{URL_SERVER, URL_TRADES_TICKS};
+use super::super::constant::URL_TRADES_TICKS;
 
+use crate::client::UpbitClient;
 use reqwest::header::ACCEPT;
 use reqwest::{Response, Url};
 use serde::Deserialize;
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct TradeRecent {
-    market: String,
-    trade_date_utc: String,
-    trade_time_utc: String,
-    timestamp: i64,
-    trade_price: f64,
-    trade_volume: f64,
-    prev_closing_price: f64,
-    change_price: f64,
-    ask_bid: String,
-    sequential_id: i64,
+    pub market: String,
+    pub trade_date_utc: String,
+    pub trade_time_utc: String,
+    pub timestamp: i64,
+    pub trade_price: Number,
+    pub trade_volume: Number,
+    pub prev_closing_price: Number,
+    pub change_price: Number,
+    pub ask_bid: String,
+    pub sequential_id: i64,
 }
 
 impl TradeRecent {
     pub async fn get_trade_recent_list(
+        client: &UpbitClient,
         market_id: &str,
         hhmmss: Option<&str>,
         count: i32,
         cursor: &str,
         days_ago: Option<i32>,
-    ) -> Result<Self, ResponseError> {
-        let res = Self::request(market_id, hhmmss, count, cursor, days_ago).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+    ) -> Result<Vec<Self>, ResponseError> {
+        let res = Self::request(client, market_id, hhmmss, count, cursor, days_ago).await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
-        serde_json::from_str(&res_serialized)
-            .map(|mut i: Vec<Self>| {
-                let x = i.pop().ok_or_else(|| crate::response::ResponseError {
-                    state: crate::response::ResponseErrorState::CustomErrorNoDataPresent,
-                    error: crate::response::ResponseErrorBody {
-                        name: "custom_error_no_data_present".to_owned(),
-                        message: "No data present in the response".to_owned(),
-                    },
-                })?;
-
-                Ok(Self {
-                    market: x.market,
-                    trade_date_utc: x.trade_date_utc,
-                    trade_time_utc: x.trade_time_utc,
-                    timestamp: x.timestamp,
-                    trade_price: x.trade_price,
-                    trade_volume: x.trade_volume,
-                    prev_closing_price: x.prev_closing_price,
-                    change_price: x.change_price,
-                    ask_bid: x.ask_bid,
-                    sequential_id: x.sequential_id,
-                })
-            })
-            .map_err(crate::response::response_error_from_json)?
+        serde_json::from_str(&res_serialized).map_err(crate::response::response_error_from_json)
     }
 
     async fn request(
+        client: &UpbitClient,
         market_id: &str,
         hhmmss: Option<&str>,
         count: i32,
         cursor: &str,
         days_ago: Option<i32>,
     ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_TRADES_TICKS}"))
+        let mut url = Url::parse(&format!("{}{URL_TRADES_TICKS}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
         url.query_pairs_mut()
             .append_pair("market", market_id)
@@ -90,12 +61,14 @@ impl TradeRecent {
                 .append_pair("daysAgo", days_ago.to_string().as_str());
         }
 
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .send()
+        client
+            .send(
+                client
+                    .http
+                    .get(url.as_str())
+                    .header(ACCEPT, "application/json"),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -107,12 +80,16 @@ mod tests {
 
     use crate::api_quotation::TradeRecent;
 
+    use crate::client::UpbitClient;
+
     #[tokio::test]
     async fn test_get_trade_recent_list() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
-        let res = TradeRecent::request("KRW-ETH", Some("120101"), 1, "0", None)
+        let res = TradeRecent::request(&client, "KRW-ETH", Some("120101"), 1, "0", None)
             .await
             .unwrap();
         let res_serialized = res
@@ -122,7 +99,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -152,11 +129,10 @@ mod tests {
                     compare_keys(item, &expected_structure, &format!("item[{}].", index));
 
                 if !missing_keys.is_empty() {
-                    println!(
+                    panic!(
                         "[test_get_trade_recent_list] Missing keys in item[{}]: {:?}",
                         index, missing_keys
                     );
-                    assert!(false);
                 } else {
                     println!(
                         "[test_get_trade_recent_list] No keys are missing in item[{}]",
@@ -165,11 +141,10 @@ mod tests {
                 }
 
                 if !extra_keys.is_empty() {
-                    println!(
+                    panic!(
                         "[test_get_trade_recent_list] Extra keys in item[{}]: {:?}",
                         index, extra_keys
                     );
-                    assert!(false);
                 } else {
                     println!(
                         "[test_get_trade_recent_list] No extra keys found in item[{}]",
@@ -178,10 +153,8 @@ mod tests {
                 }
             }
         } else {
-            assert!(false, "Expected an array of objects in the response");
+            panic!("Expected an array of objects in the response");
         }
-
-        assert!(true);
     }
 
     fn compare_keys(
@@ -193,12 +166,12 @@ mod tests {
         let mut extra_keys = Vec::new();
 
         if let Some(actual_map) = json.as_object() {
-            for (key, _) in expected {
+            for key in expected.keys() {
                 if !actual_map.contains_key(*key) {
                     missing_keys.push(format!("{}{}", path, key));
                 }
             }
-            for (key, _) in actual_map {
+            for key in actual_map.keys() {
                 if !expected.contains_key(key.as_str()) {
                     extra_keys.push(format!("{}{}", path, key));
                 }
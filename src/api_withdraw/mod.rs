@@ -5,26 +5,20 @@ mod withdraw_info;
 mod withdraw_info_list;
 mod withdraw_krw;
 
+use crate::number::Number;
 use core::fmt::Display;
+use std::str::FromStr;
 
 use super::response::{ResponseError, TransactionInfo, TransactionInfoDerived};
 use crate::{
-    constant::{OrderBy, TransactionType, TwoFactorType},
+    client::UpbitClient,
+    constant::{OrderBy, ParseEnumError, TransactionType, TwoFactorType},
     response::{WithdrawChance, WithdrawCoinAddress},
+    sqlx_type::impl_sqlx_type,
 };
 
-#[cfg(feature = "sqlx-type")]
-use sqlx::Type;
-
 /// List of withdraw state
-#[derive(Debug, Copy, Clone)]
-
-#[cfg_attr(
-    feature = "sqlx-type",
-    derive(sqlx::Type),
-    sqlx(type_name = "withdraw_state"),
-    sqlx(rename_all = "snake_case")
-)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WithdrawState {
     /// 대기중
     Waiting,
@@ -38,6 +32,8 @@ pub enum WithdrawState {
     Canceled,
     /// 거절됨
     Rejected,
+    /// value unknown to this version of the crate
+    Unknown(String),
 }
 
 impl Display for WithdrawState {
@@ -49,24 +45,46 @@ impl Display for WithdrawState {
             WithdrawState::Failed => write!(f, "FAILED"),
             WithdrawState::Canceled => write!(f, "CANCELED"),
             WithdrawState::Rejected => write!(f, "REJECTED"),
+            WithdrawState::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
 
-impl From<&str> for WithdrawState {
-    fn from(value: &str) -> Self {
-        match value {
-            "waiting" => WithdrawState::Waiting,
-            "processing" => WithdrawState::Processing,
-            "done" => WithdrawState::Done,
-            "failed" => WithdrawState::Failed,
-            "canceled" => WithdrawState::Canceled,
-            "rejected" => WithdrawState::Rejected,
-            a @ _ => panic!("Unexpected value: {}", a),
+impl FromStr for WithdrawState {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "waiting" => Ok(Self::Waiting),
+            "processing" => Ok(Self::Processing),
+            "done" => Ok(Self::Done),
+            "failed" => Ok(Self::Failed),
+            "canceled" => Ok(Self::Canceled),
+            "rejected" => Ok(Self::Rejected),
+            a => Err(ParseEnumError::new("WithdrawState", a)),
         }
     }
 }
 
+impl TryFrom<&str> for WithdrawState {
+    type Error = ParseEnumError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl WithdrawState {
+    /// Parse `value`, keeping values unknown to this version of the crate as [`WithdrawState::Unknown`]
+    pub fn parse_or_unknown(value: &str) -> Self {
+        value
+            .parse()
+            .unwrap_or_else(|_| Self::Unknown(value.to_owned()))
+    }
+}
+
+impl_sqlx_type!(WithdrawState, "withdraw_state");
+
 /// 출금 기록을 조회한다. (inquiry the records of withdraws.)
 ///
 /// # Example
@@ -150,7 +168,8 @@ pub async fn get_withdraw_info_list(
     page: u32,
     order_by: OrderBy,
 ) -> Result<Vec<TransactionInfo>, ResponseError> {
-    TransactionInfo::get_withdraw_info_list(currency, state, uuids, txids, limit, page, order_by)
+    UpbitClient::default()
+        .get_withdraw_info_list(currency, state, uuids, txids, limit, page, order_by)
         .await
 }
 
@@ -201,7 +220,9 @@ pub async fn get_withdraw_info(
     uuid: Option<&str>,
     txid: Option<&str>,
 ) -> Result<TransactionInfo, ResponseError> {
-    TransactionInfo::get_withdraw_info(currency, uuid, txid).await
+    UpbitClient::default()
+        .get_withdraw_info(currency, uuid, txid)
+        .await
 }
 
 /// 출금 가능 정보를 조회한다.
@@ -290,7 +311,9 @@ pub async fn get_withdraw_chance(
     currency: &str,
     net_type: &str,
 ) -> Result<WithdrawChance, ResponseError> {
-    WithdrawChance::get_withdraw_chance(currency, net_type).await
+    UpbitClient::default()
+        .get_withdraw_chance(currency, net_type)
+        .await
 }
 
 /// 가상화폐를 출금한다.
@@ -342,20 +365,21 @@ pub async fn get_withdraw_chance(
 pub async fn withdraw_coin(
     currency: &str,
     net_type: &str,
-    amount: f64,
+    amount: Number,
     address: &str,
     secondary_address: Option<&str>,
     transaction_type: TransactionType,
 ) -> Result<TransactionInfoDerived, ResponseError> {
-    TransactionInfoDerived::withdraw_coin(
-        currency,
-        net_type,
-        amount,
-        address,
-        secondary_address,
-        transaction_type,
-    )
-    .await
+    UpbitClient::default()
+        .withdraw_coin(
+            currency,
+            net_type,
+            amount,
+            address,
+            secondary_address,
+            transaction_type,
+        )
+        .await
 }
 
 /// 원화를 출금한다.
@@ -400,10 +424,12 @@ pub async fn withdraw_coin(
 /// | fee| 출금 수수료 | NumberString |
 /// | transaction_type| 출금 유형 | String |
 pub async fn withdraw_krw(
-    amount: f64,
+    amount: Number,
     two_factor_type: TwoFactorType,
 ) -> Result<TransactionInfo, ResponseError> {
-    TransactionInfo::withdraw_krw(amount, two_factor_type).await
+    UpbitClient::default()
+        .withdraw_krw(amount, two_factor_type)
+        .await
 }
 
 /// 출금 허용 주소 리스트 조회
@@ -433,5 +459,94 @@ pub async fn withdraw_krw(
 /// | withdraw_address | 출금 주소 | String |
 /// | secondary_address | 2차 출금 주소 (필요한 디지털 자산에 한해서) | String |
 pub async fn get_withdraw_address_list() -> Result<Vec<WithdrawCoinAddress>, ResponseError> {
-    WithdrawCoinAddress::get_withdraw_address_list().await
+    UpbitClient::default().get_withdraw_address_list().await
+}
+
+impl UpbitClient {
+    /// 출금 기록을 조회한다. (inquiry the records of withdraws.)
+    ///
+    /// See [`get_withdraw_info_list`].
+    #[allow(clippy::too_many_arguments)]
+    pub async fn get_withdraw_info_list(
+        &self,
+        currency: &str,
+        state: WithdrawState,
+        uuids: Option<&[&str]>,
+        txids: Option<&[&str]>,
+        limit: u32,
+        page: u32,
+        order_by: OrderBy,
+    ) -> Result<Vec<TransactionInfo>, ResponseError> {
+        TransactionInfo::get_withdraw_info_list(
+            self, currency, state, uuids, txids, limit, page, order_by,
+        )
+        .await
+    }
+
+    /// 개별 출금 조회.
+    ///
+    /// See [`get_withdraw_info`].
+    pub async fn get_withdraw_info(
+        &self,
+        currency: Option<&str>,
+        uuid: Option<&str>,
+        txid: Option<&str>,
+    ) -> Result<TransactionInfo, ResponseError> {
+        TransactionInfo::get_withdraw_info(self, currency, uuid, txid).await
+    }
+
+    /// 출금 가능 정보를 조회한다.
+    ///
+    /// See [`get_withdraw_chance`].
+    pub async fn get_withdraw_chance(
+        &self,
+        currency: &str,
+        net_type: &str,
+    ) -> Result<WithdrawChance, ResponseError> {
+        WithdrawChance::get_withdraw_chance(self, currency, net_type).await
+    }
+
+    /// 가상화폐를 출금한다.
+    ///
+    /// See [`withdraw_coin`].
+    pub async fn withdraw_coin(
+        &self,
+        currency: &str,
+        net_type: &str,
+        amount: Number,
+        address: &str,
+        secondary_address: Option<&str>,
+        transaction_type: TransactionType,
+    ) -> Result<TransactionInfoDerived, ResponseError> {
+        TransactionInfoDerived::withdraw_coin(
+            self,
+            currency,
+            net_type,
+            amount,
+            address,
+            secondary_address,
+            transaction_type,
+        )
+        .await
+    }
+
+    /// 원화를 출금한다.
+    ///
+    /// See [`withdraw_krw`].
+    pub async fn withdraw_krw(
+        &self,
+        amount: Number,
+        two_factor_type: TwoFactorType,
+    ) -> Result<TransactionInfo, ResponseError> {
+        TransactionInfo::withdraw_krw(self, amount, two_factor_type).await
+    }
+
+    /// 출금 허용 주소 리스트 조회
+    ///
+    /// See [`get_withdraw_address_list`].
+    pub async fn get_withdraw_address_list(
+        &self,
+    ) -> Result<Vec<WithdrawCoinAddress>, ResponseError> {
+        WithdrawCoinAddress::get_withdraw_address_list(self).await
+    }
 }
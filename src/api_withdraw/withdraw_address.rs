@@ -3,27 +3,18 @@ use reqwest::{
     Response, Url,
 };
 
-use crate::request::Request;
-
 use super::{
-    super::constant::{URL_SERVER, URL_WITHDRAWS_COIN_ADDRESS},
+    super::constant::URL_WITHDRAWS_COIN_ADDRESS,
     super::response::{ResponseError, WithdrawCoinAddress},
 };
+use crate::client::UpbitClient;
 
 impl WithdrawCoinAddress {
-    pub async fn get_withdraw_address_list() -> Result<Vec<Self>, ResponseError> {
-        let res = Self::request().await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+    pub async fn get_withdraw_address_list(
+        client: &UpbitClient,
+    ) -> Result<Vec<Self>, ResponseError> {
+        let res = Self::request(client).await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         serde_json::from_str(&res_serialized)
             .map(|x: Vec<Self>| {
@@ -40,18 +31,23 @@ impl WithdrawCoinAddress {
             .map_err(crate::response::response_error_from_json)
     }
 
-    async fn request() -> Result<Response, ResponseError> {
-        let url = Url::parse(&format!("{URL_SERVER}{URL_WITHDRAWS_COIN_ADDRESS}"))
-            .map_err(crate::response::response_error_internal_url_parse_error)?;
-        let token_string = Self::set_token()?;
-
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+    async fn request(client: &UpbitClient) -> Result<Response, ResponseError> {
+        let url = Url::parse(&format!(
+            "{}{URL_WITHDRAWS_COIN_ADDRESS}",
+            client.server_url
+        ))
+        .map_err(crate::response::response_error_internal_url_parse_error)?;
+        let token_string = client.set_token()?;
+
+        client
+            .send(
+                client
+                    .http
+                    .get(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -63,12 +59,16 @@ mod tests {
 
     use crate::response::WithdrawCoinAddress;
 
+    use crate::client::UpbitClient;
+
     #[tokio::test]
     async fn test_get_withdraw_address() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
-        let res = WithdrawCoinAddress::request().await.unwrap();
+        let res = WithdrawCoinAddress::request(&client).await.unwrap();
         let res_serialized = res
             .text()
             .await
@@ -76,7 +76,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -98,23 +98,19 @@ mod tests {
         let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
 
         if !missing_keys.is_empty() {
-            println!(
+            panic!(
                 "[test_get_withdraw_address] Missing keys: {:?}",
                 missing_keys
             );
-            assert!(false);
         } else {
             println!("[test_get_withdraw_address] No keys are missing");
         }
 
         if !extra_keys.is_empty() {
-            println!("[test_get_withdraw_address] Extra keys: {:?}", extra_keys);
-            assert!(false);
+            panic!("[test_get_withdraw_address] Extra keys: {:?}", extra_keys);
         } else {
             println!("[test_get_withdraw_address] No extra keys found.");
         }
-
-        assert!(true);
     }
 
     fn compare_keys(
@@ -4,32 +4,24 @@ use reqwest::{Response, Url};
 use crate::request::RequestWithQuery;
 
 use super::{
-    super::constant::{URL_SERVER, URL_WITHDRAWS_CHANCE},
+    super::constant::URL_WITHDRAWS_CHANCE,
     super::response::{
         AccountsInfo, MemberLevel, ResponseError, WithdrawChance, WithdrawChanceSource,
         WithdrawCurrency, WithdrawLimit,
     },
 };
+use crate::client::UpbitClient;
 
 impl RequestWithQuery for WithdrawChance {}
 impl WithdrawChance {
     #[allow(deprecated)]
     pub async fn get_withdraw_chance(
+        client: &UpbitClient,
         currency: &str,
         net_type: &str,
     ) -> Result<Self, ResponseError> {
-        let res = Self::request(currency, net_type).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+        let res = Self::request(client, currency, net_type).await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         serde_json::from_str(&res_serialized)
             .map(|x: WithdrawChanceSource| {
@@ -76,21 +68,27 @@ impl WithdrawChance {
             .map_err(crate::response::response_error_from_json)
     }
 
-    async fn request(currency: &str, net_type: &str) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_WITHDRAWS_CHANCE}"))
+    async fn request(
+        client: &UpbitClient,
+        currency: &str,
+        net_type: &str,
+    ) -> Result<Response, ResponseError> {
+        let mut url = Url::parse(&format!("{}{URL_WITHDRAWS_CHANCE}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
         url.query_pairs_mut().append_pair("currency", currency);
         url.query_pairs_mut().append_pair("net_type", net_type);
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
+        let token_string = client.set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        client
+            .send(
+                client
+                    .http
+                    .get(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -104,10 +102,14 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_withdraw_chance() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
-        let res = WithdrawChance::request("ETH", "ETH").await.unwrap();
+        let res = WithdrawChance::request(&client, "ETH", "ETH")
+            .await
+            .unwrap();
         let res_serialized = res
             .text()
             .await
@@ -115,7 +117,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -171,23 +173,19 @@ mod tests {
         let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
 
         if !missing_keys.is_empty() {
-            println!(
+            panic!(
                 "[test_get_withdraw_chance] Missing keys: {:?}",
                 missing_keys
             );
-            assert!(false);
         } else {
             println!("[test_get_withdraw_chance] No keys are missing");
         }
 
         if !extra_keys.is_empty() {
-            println!("[test_get_withdraw_chance] Extra keys: {:?}", extra_keys);
-            assert!(false);
+            panic!("[test_get_withdraw_chance] Extra keys: {:?}", extra_keys);
         } else {
             println!("[test_get_withdraw_chance] No extra keys found.");
         }
-
-        assert!(true);
     }
 
     fn compare_keys(
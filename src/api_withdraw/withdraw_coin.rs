@@ -1,26 +1,30 @@
+use crate::number::Number;
 use reqwest::header::{ACCEPT, AUTHORIZATION};
 use reqwest::{Response, Url};
 
 use super::{
     super::{
-        constant::{URL_SERVER, URL_WITHDRAWS_COIN},
+        constant::URL_WITHDRAWS_COIN,
         request::RequestWithQuery,
         response::{ResponseError, TransactionInfoDerived, TransactionInfoDerivedSource},
     },
     TransactionType,
 };
+use crate::client::UpbitClient;
 
 impl RequestWithQuery for TransactionInfoDerived {}
 impl TransactionInfoDerived {
     pub async fn withdraw_coin(
+        client: &UpbitClient,
         currency: &str,
         net_type: &str,
-        amount: f64,
+        amount: Number,
         address: &str,
         secondary_address: Option<&str>,
         transaction_type: TransactionType,
     ) -> Result<Self, ResponseError> {
         let res = Self::request_withdraw_coin(
+            client,
             currency,
             net_type,
             amount,
@@ -29,17 +33,7 @@ impl TransactionInfoDerived {
             transaction_type,
         )
         .await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+        let res_serialized = crate::response::response_text(res).await?;
 
         serde_json::from_str(&res_serialized)
             .map(|x: TransactionInfoDerivedSource| {
@@ -62,20 +56,21 @@ impl TransactionInfoDerived {
     }
 
     async fn request_withdraw_coin(
+        client: &UpbitClient,
         currency: &str,
         net_type: &str,
-        amount: f64,
+        amount: Number,
         address: &str,
         secondary_address: Option<&str>,
         transaction_type: TransactionType,
     ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_WITHDRAWS_COIN}"))
+        let mut url = Url::parse(&format!("{}{URL_WITHDRAWS_COIN}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
         url.query_pairs_mut()
             .append_pair("net_type", net_type)
             .append_pair("currency", currency)
-            .append_pair("amount", &format!("{amount}"))
+            .append_pair("amount", &crate::number::to_wire(amount))
             .append_pair("address", address)
             .append_pair("transaction_type", &transaction_type.to_string());
 
@@ -84,17 +79,19 @@ impl TransactionInfoDerived {
                 .append_pair("secondary_address", secondary_address);
         }
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
-
-        reqwest::Client::new()
-            .post(url.as_str())
-            // .json(&asdf)
-            .header(ACCEPT, "application/json")
-            // .header(CONTENT_TYPE, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        let token_string = client.set_token_with_query(url.as_str())?;
+
+        client
+            .send(
+                client
+                    .http
+                    .post(url.as_str())
+                    // .json(&asdf)
+                    .header(ACCEPT, "application/json")
+                    // .header(CONTENT_TYPE, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -106,15 +103,20 @@ mod tests {
 
     use crate::{constant::TransactionType, response::TransactionInfoDerived};
 
+    use crate::client::UpbitClient;
+
     #[tokio::test]
     async fn test_get_withdraw_coin() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
         let res = TransactionInfoDerived::request_withdraw_coin(
+            &client,
             "ETH",
             "ETH",
-            0.02,
+            "0.02".parse().unwrap(),
             "0x40268F1e99F76b658c6D52d89166EE289EfC225d",
             None,
             TransactionType::Default,
@@ -129,7 +131,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -158,20 +160,16 @@ mod tests {
         let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
 
         if !missing_keys.is_empty() {
-            println!("[test_get_withdraw_coin] Missing keys: {:?}", missing_keys);
-            assert!(false);
+            panic!("[test_get_withdraw_coin] Missing keys: {:?}", missing_keys);
         } else {
             println!("[test_get_withdraw_coin] No keys are missing");
         }
 
         if !extra_keys.is_empty() {
-            println!("[test_get_withdraw_coin] Extra keys: {:?}", extra_keys);
-            assert!(false);
+            panic!("[test_get_withdraw_coin] Extra keys: {:?}", extra_keys);
         } else {
             println!("[test_get_withdraw_coin] No extra keys found.");
         }
-
-        assert!(true);
     }
 
     fn compare_keys(
@@ -1,32 +1,20 @@
 use reqwest::header::{ACCEPT, AUTHORIZATION};
 use reqwest::{Response, Url};
 
-use crate::request::RequestWithQuery;
 use crate::response::{TransactionInfo, TransactionInfoSource};
 
-use super::{
-    super::constant::{URL_SERVER, URL_WITHDRAW},
-    super::response::ResponseError,
-};
+use super::{super::constant::URL_WITHDRAW, super::response::ResponseError};
+use crate::client::UpbitClient;
 
 impl TransactionInfo {
     pub async fn get_withdraw_info(
+        client: &UpbitClient,
         currency: Option<&str>,
         uuid: Option<&str>,
         txid: Option<&str>,
     ) -> Result<Self, ResponseError> {
-        let res = Self::request_get_withdraw_info(currency, uuid, txid).await?;
-        let res_serialized = res
-            .text()
-            .await
-            .map_err(crate::response::response_error_from_reqwest)?;
-
-        if res_serialized.contains("error") {
-            return Err(serde_json::from_str(&res_serialized)
-                .map(crate::response::response_error)
-                .ok()
-                .unwrap());
-        }
+        let res = Self::request_get_withdraw_info(client, currency, uuid, txid).await?;
+        let res_serialized = crate::response::response_text(res).await?;
 
         serde_json::from_str(&res_serialized)
             .map(|x: TransactionInfoSource| Self {
@@ -59,11 +47,12 @@ impl TransactionInfo {
     }
 
     async fn request_get_withdraw_info(
+        client: &UpbitClient,
         currency: Option<&str>,
         uuid: Option<&str>,
         txid: Option<&str>,
     ) -> Result<Response, ResponseError> {
-        let mut url = Url::parse(&format!("{URL_SERVER}{URL_WITHDRAW}"))
+        let mut url = Url::parse(&format!("{}{URL_WITHDRAW}", client.server_url))
             .map_err(crate::response::response_error_internal_url_parse_error)?;
 
         if let Some(currency) = currency {
@@ -78,15 +67,17 @@ impl TransactionInfo {
             url.query_pairs_mut().append_pair("txid", txid);
         }
 
-        let token_string = Self::set_token_with_query(url.as_str())?;
+        let token_string = client.set_token_with_query(url.as_str())?;
 
-        reqwest::Client::new()
-            .get(url.as_str())
-            .header(ACCEPT, "application/json")
-            .header(AUTHORIZATION, &token_string)
-            .send()
+        client
+            .send(
+                client
+                    .http
+                    .get(url.as_str())
+                    .header(ACCEPT, "application/json")
+                    .header(AUTHORIZATION, &token_string),
+            )
             .await
-            .map_err(crate::response::response_error_from_reqwest)
     }
 }
 
@@ -98,12 +89,16 @@ mod tests {
 
     use crate::response::TransactionInfo;
 
+    use crate::client::UpbitClient;
+
     #[tokio::test]
     async fn test_get_withdraw_info() {
-        crate::set_access_key(&std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"));
-        crate::set_secret_key(&std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"));
+        let client = UpbitClient::new(
+            &std::env::var("TEST_ACCESS_KEY").expect("TEST_ACCESS_KEY not set"),
+            &std::env::var("TEST_SECRET_KEY").expect("TEST_SECRET_KEY not set"),
+        );
 
-        let res = TransactionInfo::request_get_withdraw_info(Some("ETH"), None, None)
+        let res = TransactionInfo::request_get_withdraw_info(&client, Some("ETH"), None, None)
             .await
             .unwrap();
         let res_serialized = res
@@ -113,7 +108,7 @@ mod tests {
             .unwrap();
 
         if res_serialized.contains("error") {
-            assert!(false, "Error response: {res_serialized}");
+            panic!("Error response: {res_serialized}");
         }
 
         let json = serde_json::from_str::<Value>(&res_serialized).unwrap();
@@ -141,20 +136,16 @@ mod tests {
         let (missing_keys, extra_keys) = compare_keys(&json, &expected_structure, "");
 
         if !missing_keys.is_empty() {
-            println!("[test_get_withdraw_info] Missing keys: {:?}", missing_keys);
-            assert!(false);
+            panic!("[test_get_withdraw_info] Missing keys: {:?}", missing_keys);
         } else {
             println!("[test_get_withdraw_info] No keys are missing");
         }
 
         if !extra_keys.is_empty() {
-            println!("[test_get_withdraw_info] Extra keys: {:?}", extra_keys);
-            assert!(false);
+            panic!("[test_get_withdraw_info] Extra keys: {:?}", extra_keys);
         } else {
             println!("[test_get_withdraw_info] No extra keys found.");
         }
-
-        assert!(true);
     }
 
     fn compare_keys(
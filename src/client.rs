@@ -0,0 +1,659 @@
+use std::fmt::Debug;
+use std::sync::{OnceLock, RwLock};
+use std::time::Duration;
+
+use reqwest::header::{HeaderValue, AUTHORIZATION};
+use reqwest::{Request, RequestBuilder, Response, StatusCode};
+
+use crate::constant::{URL_SERVER, URL_WEBSOCKET};
+use crate::rate_limit::{rate_group, RateLimiter, RemainingReq, RemainingReqStore};
+use crate::request::{token, token_with_query};
+use crate::response::{response_error_from_reqwest, ResponseError};
+use crate::retry::RetryPolicy;
+
+static SHARED_HTTP_CLIENT: RwLock<Option<reqwest::Client>> = RwLock::new(None);
+static SHARED_RATE_LIMITER: RwLock<Option<RateLimiter>> = RwLock::new(None);
+static FALLBACK_RATE_LIMITER: OnceLock<RateLimiter> = OnceLock::new();
+static SHARED_REMAINING_REQ: OnceLock<RemainingReqStore> = OnceLock::new();
+static SHARED_RETRY_POLICY: RwLock<Option<RetryPolicy>> = RwLock::new(None);
+
+/// Get HTTP client shared by every [`UpbitClient`] created without its own [`HttpConfig`]
+///
+/// Clones share one connection pool, so TLS sessions and keep-alive connections are reused.
+pub(crate) fn shared_http_client() -> reqwest::Client {
+    if let Some(http) = SHARED_HTTP_CLIENT
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+    {
+        return http.clone();
+    }
+
+    let mut shared = SHARED_HTTP_CLIENT
+        .write()
+        .unwrap_or_else(|e| e.into_inner());
+    shared
+        .get_or_insert_with(|| {
+            HttpConfig::default()
+                .build()
+                .unwrap_or_else(|_| reqwest::Client::new())
+        })
+        .clone()
+}
+
+/// Replace HTTP client shared by the api functions and newly created [`UpbitClient`]s
+pub(crate) fn set_shared_http_client(http: reqwest::Client) {
+    *SHARED_HTTP_CLIENT
+        .write()
+        .unwrap_or_else(|e| e.into_inner()) = Some(http);
+}
+
+/// Get rate limiter shared by the api functions and newly created [`UpbitClient`]s
+pub(crate) fn shared_rate_limiter() -> Option<RateLimiter> {
+    SHARED_RATE_LIMITER
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// Get rate limiter for requests sent back to back by a client with no limiter of its own
+///
+/// The shared limiter if one is set, otherwise one created on first use for the whole process, so
+/// concurrent callers such as candle range streams split the quota of a group between them.
+pub(crate) fn fallback_rate_limiter() -> RateLimiter {
+    shared_rate_limiter()
+        .unwrap_or_else(|| FALLBACK_RATE_LIMITER.get_or_init(RateLimiter::new).clone())
+}
+
+/// Replace rate limiter shared by the api functions and newly created [`UpbitClient`]s
+pub(crate) fn set_shared_rate_limiter(rate_limiter: Option<RateLimiter>) {
+    *SHARED_RATE_LIMITER
+        .write()
+        .unwrap_or_else(|e| e.into_inner()) = rate_limiter;
+}
+
+/// Get `Remaining-Req` store shared by the api functions
+///
+/// Only [`UpbitClient::default`] records into it, as clients made by [`UpbitClient::new`] may
+/// belong to other accounts with quotas of their own.
+pub(crate) fn shared_remaining_req() -> RemainingReqStore {
+    SHARED_REMAINING_REQ
+        .get_or_init(RemainingReqStore::default)
+        .clone()
+}
+
+/// Get retry policy shared by the api functions and newly created [`UpbitClient`]s
+pub(crate) fn shared_retry_policy() -> RetryPolicy {
+    SHARED_RETRY_POLICY
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .unwrap_or_default()
+}
+
+/// Replace retry policy shared by the api functions and newly created [`UpbitClient`]s
+pub(crate) fn set_shared_retry_policy(retry_policy: RetryPolicy) {
+    *SHARED_RETRY_POLICY
+        .write()
+        .unwrap_or_else(|e| e.into_inner()) = Some(retry_policy);
+}
+
+/// Settings of the underlying HTTP client
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+///
+/// use upbit::HttpConfig;
+///
+/// let config = HttpConfig::default()
+///     .with_timeout(Duration::from_secs(5))
+///     .with_pool_max_idle_per_host(8)
+///     .with_proxy("http://127.0.0.1:3128");
+///
+/// upbit::set_http_config(&config)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct HttpConfig {
+    /// total timeout of a request. `None` waits indefinitely
+    pub timeout: Option<Duration>,
+    /// timeout of establishing a connection
+    pub connect_timeout: Option<Duration>,
+    /// maximum idle connections kept alive per host
+    pub pool_max_idle_per_host: usize,
+    /// how long an idle connection is kept alive
+    pub pool_idle_timeout: Option<Duration>,
+    /// value of the `User-Agent` header
+    pub user_agent: String,
+    /// proxy every request is sent through. ex) http://127.0.0.1:3128
+    pub proxy: Option<String>,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Some(Duration::from_secs(30)),
+            connect_timeout: Some(Duration::from_secs(10)),
+            pool_max_idle_per_host: usize::MAX,
+            pool_idle_timeout: Some(Duration::from_secs(90)),
+            user_agent: concat!("upbit/", env!("CARGO_PKG_VERSION")).to_owned(),
+            proxy: None,
+        }
+    }
+}
+
+impl HttpConfig {
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    pub fn with_pool_max_idle_per_host(mut self, pool_max_idle_per_host: usize) -> Self {
+        self.pool_max_idle_per_host = pool_max_idle_per_host;
+        self
+    }
+
+    pub fn with_pool_idle_timeout(mut self, pool_idle_timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(pool_idle_timeout);
+        self
+    }
+
+    pub fn with_user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = user_agent.to_owned();
+        self
+    }
+
+    pub fn with_proxy(mut self, proxy: &str) -> Self {
+        self.proxy = Some(proxy.to_owned());
+        self
+    }
+
+    /// Build a `reqwest::Client` from the settings
+    pub fn build(&self) -> Result<reqwest::Client, ResponseError> {
+        let mut builder = reqwest::Client::builder()
+            .pool_max_idle_per_host(self.pool_max_idle_per_host)
+            .pool_idle_timeout(self.pool_idle_timeout)
+            .user_agent(&self.user_agent);
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+
+        if let Some(proxy) = &self.proxy {
+            builder =
+                builder.proxy(reqwest::Proxy::all(proxy).map_err(response_error_from_reqwest)?);
+        }
+
+        builder.build().map_err(response_error_from_reqwest)
+    }
+}
+
+/// Upbit API client holding its own credentials and HTTP connection pool
+///
+/// Every free function in `api_exchange`, `api_deposit`, `api_withdraw` and `api_quotation`
+/// has a method of the same name on this type. Several clients with different keys can be
+/// used side by side in one process.
+///
+/// # Example
+/// ```no_run
+/// use upbit::UpbitClient;
+///
+/// # async fn run() {
+/// let client = UpbitClient::new("access_key", "secret_key");
+///
+/// let account_info = client.get_account_info().await;
+/// let order_chance = client.get_order_chance("KRW-ETH").await;
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct UpbitClient {
+    pub(crate) access_key: String,
+    pub(crate) secret_key: String,
+    pub(crate) server_url: String,
+    pub(crate) websocket_url: String,
+    pub(crate) http: reqwest::Client,
+    pub(crate) rate_limiter: Option<RateLimiter>,
+    /// set by [`UpbitClient::without_rate_limiter`], so no fallback limiter is used either
+    pub(crate) is_rate_limiter_disabled: bool,
+    pub(crate) remaining_req: RemainingReqStore,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) dry_run: bool,
+}
+
+impl UpbitClient {
+    /// Create a client signing its requests with `access_key` and `secret_key`
+    pub fn new(access_key: &str, secret_key: &str) -> Self {
+        Self {
+            access_key: access_key.to_owned(),
+            secret_key: secret_key.to_owned(),
+            server_url: URL_SERVER.to_owned(),
+            websocket_url: URL_WEBSOCKET.to_owned(),
+            http: shared_http_client(),
+            rate_limiter: shared_rate_limiter(),
+            is_rate_limiter_disabled: false,
+            remaining_req: RemainingReqStore::default(),
+            retry_policy: shared_retry_policy(),
+            dry_run: false,
+        }
+    }
+
+    /// Set server domain address the client sends its requests to
+    ///
+    /// Useful for regional Upbit hosts or a local stand-in server.
+    /// # Example
+    /// ```
+    /// use upbit::UpbitClient;
+    ///
+    /// let client = UpbitClient::new("access_key", "secret_key")
+    ///     .with_server_url("http://127.0.0.1:8080");
+    /// ```
+    pub fn with_server_url(mut self, server_url: &str) -> Self {
+        self.server_url = server_url.trim_end_matches('/').to_owned();
+        self
+    }
+
+    /// Set address of the WebSocket server the client subscribes to
+    ///
+    /// # Example
+    /// ```
+    /// use upbit::UpbitClient;
+    ///
+    /// let client = UpbitClient::new("access_key", "secret_key")
+    ///     .with_websocket_url("ws://127.0.0.1:8080");
+    /// ```
+    pub fn with_websocket_url(mut self, websocket_url: &str) -> Self {
+        self.websocket_url = websocket_url.to_owned();
+        self
+    }
+
+    /// Use a dedicated HTTP client built from `config` instead of the shared one
+    pub fn with_http_config(mut self, config: &HttpConfig) -> Result<Self, ResponseError> {
+        self.http = config.build()?;
+        Ok(self)
+    }
+
+    /// Use `http` for sending requests
+    pub fn with_http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// Queue requests per `Remaining-Req` group with `rate_limiter` instead of getting 429
+    ///
+    /// Clones of the limiter share their quotas, so one limiter can be given to several clients
+    /// using the same keys.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self.is_rate_limiter_disabled = false;
+        self
+    }
+
+    /// Send requests without waiting for a rate limiter
+    ///
+    /// Also turns off the limiter candle range streams fall back on.
+    pub fn without_rate_limiter(mut self) -> Self {
+        self.rate_limiter = None;
+        self.is_rate_limiter_disabled = true;
+        self
+    }
+
+    /// Resend failed requests according to `retry_policy`
+    ///
+    /// Orders and withdrawals are retried only if [`RetryPolicy::with_non_idempotent`] is set.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Send every order placed by the client to the order test API instead
+    ///
+    /// Orders are validated by Upbit and answered as if placed, but never reach the order book.
+    /// Cancellations and queries are sent as usual.
+    /// # Example
+    /// ```no_run
+    /// use upbit::api_exchange::OrderRequest;
+    /// use upbit::{Number, UpbitClient};
+    ///
+    /// # async fn run() {
+    /// let client = UpbitClient::new("access_key", "secret_key").with_dry_run(true);
+    /// let order_info = client.place_order(&OrderRequest::market_bid("KRW-ETH", Number::from(10_000))).await;
+    /// # }
+    /// ```
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Get server domain address the client sends its requests to
+    pub fn server_url(&self) -> &str {
+        &self.server_url
+    }
+
+    /// Get address of the WebSocket server the client subscribes to
+    pub fn websocket_url(&self) -> &str {
+        &self.websocket_url
+    }
+
+    /// Check orders placed by the client go to the order test API
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Get the last `Remaining-Req` the server reported for `group`
+    ///
+    /// ex) default, order, market, candles, crix-trades, ticker, orderbook
+    pub fn remaining_req(&self, group: &str) -> Option<RemainingReq> {
+        self.remaining_req.get(group)
+    }
+
+    /// Get the `Remaining-Req` of the most recent response
+    pub fn last_remaining_req(&self) -> Option<RemainingReq> {
+        self.remaining_req.last()
+    }
+
+    /// Send `request` through the rate limiter and record its `Remaining-Req`
+    ///
+    /// Failed attempts are resent according to the [`RetryPolicy`] of the client.
+    pub(crate) async fn send(&self, request: RequestBuilder) -> Result<Response, ResponseError> {
+        let mut request = request.build().map_err(response_error_from_reqwest)?;
+        let group = rate_group(request.method(), request.url().path());
+        let max_attempts = if self.retry_policy.allows(request.method()) {
+            self.retry_policy.max_attempts
+        } else {
+            1
+        };
+        let mut attempt = 1;
+
+        loop {
+            let retry = if attempt < max_attempts {
+                self.resign(&request)?
+            } else {
+                None
+            };
+
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire(group).await;
+            }
+
+            match self.http.execute(request).await {
+                Ok(res) => {
+                    if let Some(remaining_req) = RemainingReq::from_response(&res) {
+                        self.remaining_req.update(&remaining_req);
+
+                        if let Some(rate_limiter) = &self.rate_limiter {
+                            rate_limiter.update(&remaining_req);
+                        }
+                    } else if res.status() == StatusCode::TOO_MANY_REQUESTS {
+                        if let Some(rate_limiter) = &self.rate_limiter {
+                            rate_limiter.block(group, Duration::from_secs(1));
+                        }
+                    }
+
+                    match retry {
+                        Some(next) if RetryPolicy::is_retryable_status(res.status()) => {
+                            request = next
+                        }
+                        _ => return Ok(res),
+                    }
+                }
+                Err(e) => match retry {
+                    Some(next) if RetryPolicy::is_retryable_error(&e) => request = next,
+                    _ => return Err(response_error_from_reqwest(e)),
+                },
+            }
+
+            tokio::time::sleep(self.retry_policy.delay(attempt)).await;
+            attempt += 1;
+        }
+    }
+
+    /// Copy `request` for another attempt, signing it with a fresh nonce
+    fn resign(&self, request: &Request) -> Result<Option<Request>, ResponseError> {
+        let Some(mut request) = request.try_clone() else {
+            return Ok(None);
+        };
+
+        if request.headers().contains_key(AUTHORIZATION) {
+            let token_string = match request.url().query() {
+                Some(_) => self.set_token_with_query(request.url().as_str())?,
+                None => self.set_token()?,
+            };
+            let token_header = HeaderValue::from_str(&token_string)
+                .map_err(crate::response::response_error_internal_token_encode_error)?;
+
+            request.headers_mut().insert(AUTHORIZATION, token_header);
+        }
+
+        Ok(Some(request))
+    }
+
+    pub(crate) fn set_token(&self) -> Result<String, ResponseError> {
+        token(&self.access_key, &self.secret_key)
+    }
+
+    pub(crate) fn set_token_with_query(&self, url: &str) -> Result<String, ResponseError> {
+        token_with_query(&self.access_key, &self.secret_key, url)
+    }
+}
+
+/// Client using the keys registered by [`crate::set_access_key`] and [`crate::set_secret_key`]
+/// and the servers registered by [`crate::set_server_url`] and [`crate::set_websocket_url`]
+impl Default for UpbitClient {
+    fn default() -> Self {
+        Self {
+            remaining_req: shared_remaining_req(),
+            ..Self::new(
+                &envmnt::get_or("ACCESS_KEY", ""),
+                &envmnt::get_or("SECRET_KEY", ""),
+            )
+            .with_server_url(&envmnt::get_or("SERVER_URL", URL_SERVER))
+            .with_websocket_url(&envmnt::get_or("WEBSOCKET_URL", URL_WEBSOCKET))
+        }
+    }
+}
+
+impl Debug for UpbitClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UpbitClient")
+            .field("access_key", &self.access_key)
+            .field("secret_key", &"<hidden>")
+            .field("server_url", &self.server_url)
+            .field("websocket_url", &self.websocket_url)
+            .field("rate_limiter", &self.rate_limiter)
+            .field("retry_policy", &self.retry_policy)
+            .field("dry_run", &self.dry_run)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api_quotation::{CandleMinute, UrlAssociates};
+
+    const MARKET_ALL: &str = r#"[{"market":"KRW-BTC","korean_name":"비트코인","english_name":"Bitcoin","market_warning":"NONE","market_event":{"warning":false,"caution":{"PRICE_FLUCTUATIONS":false,"TRADING_VOLUME_SOARING":false,"DEPOSIT_AMOUNT_SOARING":false,"GLOBAL_PRICE_DIFFERENCES":false,"CONCENTRATION_OF_SMALL_ACCOUNTS":false}}}]"#;
+
+    #[test]
+    fn test_with_server_url() {
+        let client = UpbitClient::new("access_key", "secret_key");
+        assert_eq!(client.server_url(), URL_SERVER);
+
+        let client = client.with_server_url("http://127.0.0.1:8080/");
+        assert_eq!(client.server_url(), "http://127.0.0.1:8080");
+        assert_eq!(
+            UrlAssociates::UrlCandleMinute(CandleMinute::Min10).to_url(client.server_url()),
+            "http://127.0.0.1:8080/v1/candles/minutes/10"
+        );
+    }
+
+    #[test]
+    fn test_http_config_build() {
+        let config = HttpConfig::default()
+            .with_timeout(Duration::from_secs(5))
+            .with_connect_timeout(Duration::from_secs(1))
+            .with_pool_max_idle_per_host(4)
+            .with_user_agent("upbit-test")
+            .with_proxy("http://127.0.0.1:3128");
+
+        assert!(config.build().is_ok());
+        assert!(UpbitClient::new("access_key", "secret_key")
+            .with_http_config(&config)
+            .is_ok());
+
+        let error = HttpConfig::default()
+            .with_proxy("not a proxy url")
+            .build()
+            .unwrap_err();
+        assert!(matches!(
+            error.state,
+            crate::response::ResponseErrorState::InternalReqwestError
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_send_records_remaining_req() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/market/all"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Remaining-Req", "group=market; min=573; sec=9")
+                    .set_body_string(MARKET_ALL),
+            )
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key")
+            .with_server_url(&server.uri())
+            .with_rate_limiter(RateLimiter::new());
+
+        let market_state = client.get_market_state(true).await;
+        assert!(market_state.is_ok());
+
+        let remaining_req = client.remaining_req("market").unwrap();
+        assert_eq!(remaining_req.min, Some(573));
+        assert_eq!(remaining_req.sec, 9);
+        assert_eq!(client.last_remaining_req(), Some(remaining_req));
+    }
+
+    #[tokio::test]
+    async fn test_remaining_req_is_kept_per_client() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mut clients = Vec::new();
+        for (access_key, remaining_req) in [
+            ("access_key_a", "group=market; min=573; sec=9"),
+            ("access_key_b", "group=market; min=1200; sec=3"),
+        ] {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path("/v1/market/all"))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .insert_header("Remaining-Req", remaining_req)
+                        .set_body_string(MARKET_ALL),
+                )
+                .mount(&server)
+                .await;
+
+            let client = UpbitClient::new(access_key, "secret_key")
+                .with_server_url(&server.uri())
+                .without_rate_limiter();
+
+            assert!(client.get_market_state(true).await.is_ok());
+            clients.push((client, server));
+        }
+
+        assert_eq!(clients[0].0.remaining_req("market").unwrap().sec, 9);
+        assert_eq!(clients[1].0.remaining_req("market").unwrap().sec, 3);
+        assert_eq!(
+            clients[1].0.remaining_req("market").unwrap().min,
+            Some(1200)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_retries_idempotent_request() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/market/all"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/market/all"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(MARKET_ALL))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key")
+            .with_server_url(&server.uri())
+            .with_retry_policy(RetryPolicy::default().with_base_delay(Duration::ZERO));
+
+        assert!(client.get_market_state(true).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_send_does_not_retry_order_by_default() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/orders"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key")
+            .with_server_url(&server.uri())
+            .with_retry_policy(RetryPolicy::default().with_base_delay(Duration::ZERO));
+
+        let res = client
+            .send(client.http.post(format!("{}/v1/orders", client.server_url)))
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let client = client.with_retry_policy(
+            RetryPolicy::default()
+                .with_base_delay(Duration::ZERO)
+                .with_non_idempotent(true),
+        );
+        server.verify().await;
+        server.reset().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/orders"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let res = client
+            .send(client.http.post(format!("{}/v1/orders", client.server_url)))
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
@@ -1,18 +1,24 @@
 use std::fmt::Display;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "sqlx-type")]
-use sqlx::Type;
+use crate::sqlx_type::impl_sqlx_type;
 
 /// Server domain address
 pub const URL_SERVER: &str = "https://api.upbit.com";
+/// Address of WebSocket server
+pub const URL_WEBSOCKET: &str = "wss://api.upbit.com/websocket/v1";
 
 /// URL of API getting account info
 pub const URL_ACCOUNTS: &str = "/v1/accounts";
 
 /// URL of API getting order info  
 pub const URL_ORDER: &str = "/v1/orders";
+/// URL of API testing an order without placing it
+pub const URL_ORDER_TEST: &str = "/v1/orders/test";
+/// URL of API cancelling an order and placing another in its stead
+pub const URL_ORDER_CANCEL_AND_NEW: &str = "/v1/orders/cancel_and_new";
 /// URL of API getting order chance
 pub const URL_ORDER_CHANCE: &str = "/v1/orders/chance";
 /// URL of API getting order status
@@ -26,16 +32,25 @@ pub const URL_ORDER_STATUS_BY_UUID: &str = "/v1/orders/uuids";
 pub const URL_ORDER_STATUS_OPEN: &str = "/v1/orders/open";
 /// URL of API getting order status closed
 pub const URL_ORDER_STATUS_CLOSED: &str = "/v1/orders/closed";
+/// URL of API cancelling orders by uuids or identifiers
+pub const URL_ORDER_CANCEL_BY_UUID: &str = "/v1/orders/uuids";
+/// URL of API cancelling open orders
+pub const URL_ORDER_CANCEL_OPEN: &str = "/v1/orders/open";
 
 /// URL of API getting order book
 pub const URL_ORDERBOOK: &str = "/v1/orderbook";
 /// URL of API getting ticker
 pub const URL_TICKER: &str = "/v1/ticker";
+/// URL of API getting tickers of every market by quote currency
+pub const URL_TICKER_ALL: &str = "/v1/ticker/all";
 /// URL of API getting trandes ticks
 pub const URL_TRADES_TICKS: &str = "/v1/trades/ticks";
 /// URL of API getting market state
 pub const URL_MARKET_STATE: &str = "/v1/market/all";
 
+/// Markets sent in one request of APIs taking a market list. longer lists are split
+pub const MAX_MARKETS_PER_REQUEST: usize = 100;
+
 /// URL of API getting withdraw info
 pub const URL_WITHDRAW: &str = "/v1/withdraw";
 /// URL of API getting withdraw info list
@@ -62,6 +77,8 @@ pub const URL_DEPOSITS_COIN_ADDRESSES: &str = "/v1/deposits/coin_addresses";
 /// URL of API requesting to deposit KRW
 pub const URL_DEPOSITS_KRW: &str = "/v1/deposits/krw";
 
+/// URL of API listing candle data of second unit
+pub const URL_CANDLE_SECOND: &str = "/v1/candles/seconds";
 /// URL of API listing candle data of minute unit
 pub const URL_CANDLE_MINUTE: &str = "/v1/candles/minutes/";
 /// URL of API listing candle data of day unit
@@ -70,13 +87,44 @@ pub const URL_CANDLE_DAY: &str = "/v1/candles/days";
 pub const URL_CANDLE_WEEK: &str = "/v1/candles/weeks";
 /// URL of API listing candle data of month unit
 pub const URL_CANDLE_MONTH: &str = "/v1/candles/months";
+/// URL of API listing candle data of year unit
+pub const URL_CANDLE_YEAR: &str = "/v1/candles/years";
+
+/// Error returned when a string is not a known value of an enum
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEnumError {
+    /// name of the enum
+    pub type_name: &'static str,
+    /// value failed to parse
+    pub value: String,
+}
+
+impl ParseEnumError {
+    pub fn new(type_name: &'static str, value: &str) -> Self {
+        Self {
+            type_name,
+            value: value.to_owned(),
+        }
+    }
+}
+
+impl Display for ParseEnumError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unexpected value of {}: {}", self.type_name, self.value)
+    }
+}
+
+impl std::error::Error for ParseEnumError {}
 
 /// Kind of order
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OrderBy {
     /// 오름차순 (Ascending)
     Asc,
     /// 내림차순 (Descending)
     Desc,
+    /// value unknown to this version of the crate
+    Unknown(String),
 }
 
 impl Display for OrderBy {
@@ -84,33 +132,49 @@ impl Display for OrderBy {
         match self {
             OrderBy::Asc => write!(f, "asc"),
             OrderBy::Desc => write!(f, "desc"),
+            OrderBy::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
 
-impl From<&str> for OrderBy {
-    fn from(value: &str) -> Self {
-        match value {
-            "asc" => Self::Asc,
-            "desc" => Self::Desc,
-            a @ _ => panic!("Unexpected value: {}", a),
+impl FromStr for OrderBy {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "asc" => Ok(Self::Asc),
+            "desc" => Ok(Self::Desc),
+            a => Err(ParseEnumError::new("OrderBy", a)),
         }
     }
 }
 
+impl TryFrom<&str> for OrderBy {
+    type Error = ParseEnumError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl OrderBy {
+    /// Parse `value`, keeping values unknown to this version of the crate as [`OrderBy::Unknown`]
+    pub fn parse_or_unknown(value: &str) -> Self {
+        value
+            .parse()
+            .unwrap_or_else(|_| Self::Unknown(value.to_owned()))
+    }
+}
+
 /// Kind of transaction type
-#[derive(Debug, Copy, Clone)]
-#[cfg_attr(
-    feature = "sqlx-type",
-    derive(sqlx::Type),
-    sqlx(type_name = "transaction_type"),
-    sqlx(rename_all = "snake_case")
-)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TransactionType {
     /// 일반 입출금(general withdrawal or deposit)
     Default,
     /// 바로 입출금(instant withdrawal or deposit)
     Internal,
+    /// value unknown to this version of the crate
+    Unknown(String),
 }
 
 impl Display for TransactionType {
@@ -118,20 +182,42 @@ impl Display for TransactionType {
         match self {
             TransactionType::Default => write!(f, "default"),
             TransactionType::Internal => write!(f, "internal"),
+            TransactionType::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
 
-impl From<&str> for TransactionType {
-    fn from(value: &str) -> Self {
-        match value {
-            "default" => Self::Default,
-            "internal" => Self::Internal,
-            a @ _ => panic!("Unexpected value: {}", a),
+impl FromStr for TransactionType {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(Self::Default),
+            "internal" => Ok(Self::Internal),
+            a => Err(ParseEnumError::new("TransactionType", a)),
         }
     }
 }
 
+impl TryFrom<&str> for TransactionType {
+    type Error = ParseEnumError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TransactionType {
+    /// Parse `value`, keeping values unknown to this version of the crate as [`TransactionType::Unknown`]
+    pub fn parse_or_unknown(value: &str) -> Self {
+        value
+            .parse()
+            .unwrap_or_else(|_| Self::Unknown(value.to_owned()))
+    }
+}
+
+impl_sqlx_type!(TransactionType, "transaction_type");
+
 /// Kind of tow factor type
 ///
 pub enum TwoFactorType {
@@ -155,18 +241,14 @@ impl Display for TwoFactorType {
 }
 
 /// List of transaction type
-#[cfg_attr(feature = "sqlx-type", derive(sqlx::Type))]
-#[cfg_attr(
-    feature = "sqlx-type",
-    sqlx(type_name = "transfer_type"),
-    sqlx(rename_all = "snake_case")
-)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TransferType {
     /// 출금
     Withdraw,
     /// 입금
     Deposit,
+    /// value unknown to this version of the crate
+    Unknown(String),
 }
 
 impl Display for TransferType {
@@ -174,27 +256,43 @@ impl Display for TransferType {
         match self {
             TransferType::Withdraw => write!(f, "withdraw"),
             TransferType::Deposit => write!(f, "deposit"),
+            TransferType::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
 
-impl From<&str> for TransferType {
-    fn from(value: &str) -> Self {
-        match value {
-            "withdraw" => Self::Withdraw,
-            "deposit" => Self::Deposit,
-            a @ _ => panic!("Unexpected value: {}", a),
+impl FromStr for TransferType {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "withdraw" => Ok(Self::Withdraw),
+            "deposit" => Ok(Self::Deposit),
+            a => Err(ParseEnumError::new("TransferType", a)),
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
-#[cfg_attr(feature = "sqlx-type", derive(sqlx::Type))]
-#[cfg_attr(
-    feature = "sqlx-type",
-    sqlx(type_name = "ask_type"),
-    sqlx(rename_all = "snake_case")
-)]
+impl TryFrom<&str> for TransferType {
+    type Error = ParseEnumError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TransferType {
+    /// Parse `value`, keeping values unknown to this version of the crate as [`TransferType::Unknown`]
+    pub fn parse_or_unknown(value: &str) -> Self {
+        value
+            .parse()
+            .unwrap_or_else(|_| Self::Unknown(value.to_owned()))
+    }
+}
+
+impl_sqlx_type!(TransferType, "transfer_type");
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub enum AskType {
     BestFOK,
     BestIOC,
@@ -202,6 +300,8 @@ pub enum AskType {
     LimitFOK,
     LimitIOC,
     Market,
+    /// value unknown to this version of the crate
+    Unknown(String),
 }
 
 impl Display for AskType {
@@ -213,17 +313,12 @@ impl Display for AskType {
             AskType::LimitFOK => write!(f, "limit_fok"),
             AskType::LimitIOC => write!(f, "limit_ioc"),
             AskType::Market => write!(f, "market"),
+            AskType::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
-#[cfg_attr(feature = "sqlx-type", derive(sqlx::Type))]
-#[cfg_attr(
-    feature = "sqlx-type",
-    sqlx(type_name = "bid_type"),
-    sqlx(rename_all = "snake_case")
-)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub enum BidType {
     BestFOK,
     BestIOC,
@@ -231,6 +326,8 @@ pub enum BidType {
     LimitFOK,
     LimitIOC,
     Price,
+    /// value unknown to this version of the crate
+    Unknown(String),
 }
 
 impl Display for BidType {
@@ -242,38 +339,81 @@ impl Display for BidType {
             BidType::LimitFOK => write!(f, "limit_fok"),
             BidType::LimitIOC => write!(f, "limit_ioc"),
             BidType::Price => write!(f, "price"),
+            BidType::Unknown(value) => write!(f, "{value}"),
         }
     }
 }
 
-impl From<&str> for AskType {
-    fn from(value: &str) -> Self {
-        match value {
-            "best_fok" => Self::BestFOK,
-            "best_ioc" => Self::BestIOC,
-            "limit" => Self::Limit,
-            "limit_fok" => Self::LimitFOK,
-            "limit_ioc" => Self::LimitIOC,
-            "market" => Self::Market,
-            a @ _ => panic!("Unexpected value: {}", a),
+impl FromStr for AskType {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "best_fok" => Ok(Self::BestFOK),
+            "best_ioc" => Ok(Self::BestIOC),
+            "limit" => Ok(Self::Limit),
+            "limit_fok" => Ok(Self::LimitFOK),
+            "limit_ioc" => Ok(Self::LimitIOC),
+            "market" => Ok(Self::Market),
+            a => Err(ParseEnumError::new("AskType", a)),
         }
     }
 }
 
-impl From<&str> for BidType {
-    fn from(value: &str) -> Self {
-        match value {
-            "best_fok" => Self::BestFOK,
-            "best_ioc" => Self::BestIOC,
-            "limit" => Self::Limit,
-            "limit_fok" => Self::LimitFOK,
-            "limit_ioc" => Self::LimitIOC,
-            "price" => Self::Price,
-            a @ _ => panic!("Unexpected value: {}", a),
+impl TryFrom<&str> for AskType {
+    type Error = ParseEnumError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl AskType {
+    /// Parse `value`, keeping values unknown to this version of the crate as [`AskType::Unknown`]
+    pub fn parse_or_unknown(value: &str) -> Self {
+        value
+            .parse()
+            .unwrap_or_else(|_| Self::Unknown(value.to_owned()))
+    }
+}
+
+impl_sqlx_type!(AskType, "ask_type");
+
+impl FromStr for BidType {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "best_fok" => Ok(Self::BestFOK),
+            "best_ioc" => Ok(Self::BestIOC),
+            "limit" => Ok(Self::Limit),
+            "limit_fok" => Ok(Self::LimitFOK),
+            "limit_ioc" => Ok(Self::LimitIOC),
+            "price" => Ok(Self::Price),
+            a => Err(ParseEnumError::new("BidType", a)),
         }
     }
 }
 
+impl TryFrom<&str> for BidType {
+    type Error = ParseEnumError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl BidType {
+    /// Parse `value`, keeping values unknown to this version of the crate as [`BidType::Unknown`]
+    pub fn parse_or_unknown(value: &str) -> Self {
+        value
+            .parse()
+            .unwrap_or_else(|_| Self::Unknown(value.to_owned()))
+    }
+}
+
+impl_sqlx_type!(BidType, "bid_type");
+
 // pub enum Currency {
 //     KRW,
 //     BTC,
@@ -287,3 +427,40 @@ impl From<&str> for BidType {
 // }
 
 // pub struct MarketType(Currency, CurrencyCrypto);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api_exchange::{OrderSide, OrderState, OrderType};
+    use crate::response::OrderInfoSource;
+
+    #[test]
+    fn test_parse_known_value() {
+        assert_eq!("limit_ioc".parse::<AskType>(), Ok(AskType::LimitIOC));
+        assert_eq!(OrderBy::try_from("desc"), Ok(OrderBy::Desc));
+        assert_eq!(OrderSide::parse_or_unknown("bid"), OrderSide::Bid);
+    }
+
+    #[test]
+    fn test_parse_unknown_value() {
+        let error = "best_gtc".parse::<BidType>().unwrap_err();
+
+        assert_eq!(error, ParseEnumError::new("BidType", "best_gtc"));
+        assert_eq!(error.to_string(), "unexpected value of BidType: best_gtc");
+
+        let ord_type = OrderType::parse_or_unknown("twap");
+        assert_eq!(ord_type, OrderType::Unknown("twap".to_owned()));
+        assert_eq!(ord_type.to_string(), "twap");
+    }
+
+    #[test]
+    fn test_source_with_unknown_value() {
+        let source = serde_json::from_str::<OrderInfoSource>(
+            r#"{"uuid":"9ca023a5-851b-4fec-9f0a-48cd83c2eaae","side":"ask","ord_type":"twap","price":"4280000.0","state":"scheduled","market":"KRW-BTC","created_at":"2019-03-25T14:10:53+09:00","volume":"1.0","remaining_volume":"1.0","reserved_fee":"0.0","remaining_fee":"0.0","paid_fee":"0.0","locked":"1.0","executed_volume":"0.0","trades_count":0}"#,
+        )
+        .unwrap();
+
+        assert_eq!(source.ord_type(), OrderType::Unknown("twap".to_owned()));
+        assert_eq!(source.state(), OrderState::Unknown("scheduled".to_owned()));
+    }
+}
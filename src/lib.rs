@@ -6,12 +6,32 @@ pub mod api_exchange;
 pub mod api_quotation;
 /// Module for withdrawal APIs
 pub mod api_withdraw;
+/// Module of API client
+pub mod client;
 /// Set of constants
 pub mod constant;
+/// Numeric type of monetary values
+pub mod number;
+/// Module for request quota tracking and rate limiting
+pub mod rate_limit;
 /// Set of concrete request bodies
 pub mod request;
 /// Set of concrete response bodies
 pub mod response;
+/// Module for retrying failed requests
+pub mod retry;
+/// Database type support of enums
+mod sqlx_type;
+/// Module for valid order price units
+pub mod tick_size;
+/// Module for real-time WebSocket streams
+pub mod websocket;
+
+pub use client::{HttpConfig, UpbitClient};
+pub use number::Number;
+pub use rate_limit::{RateLimiter, RemainingReq};
+pub use retry::RetryPolicy;
+pub use tick_size::TickSize;
 
 /// function for setting secret key
 pub fn set_secret_key(secret_key: &str) {
@@ -22,3 +42,45 @@ pub fn set_secret_key(secret_key: &str) {
 pub fn set_access_key(access_key: &str) {
     envmnt::set("ACCESS_KEY", access_key);
 }
+
+/// function for setting server domain address used by the api functions
+///
+/// defaults to [`constant::URL_SERVER`]
+pub fn set_server_url(server_url: &str) {
+    envmnt::set("SERVER_URL", server_url);
+}
+
+/// function for setting WebSocket server address used by the websocket functions
+///
+/// defaults to [`constant::URL_WEBSOCKET`]
+pub fn set_websocket_url(websocket_url: &str) {
+    envmnt::set("WEBSOCKET_URL", websocket_url);
+}
+
+/// function for setting HTTP client settings shared by the api functions
+///
+/// the connection pool is reused by every request made after the call
+pub fn set_http_config(config: &HttpConfig) -> Result<(), response::ResponseError> {
+    client::set_shared_http_client(config.build()?);
+    Ok(())
+}
+
+/// function for enabling a rate limiter shared by the api functions
+///
+/// requests over the quota of their group wait instead of getting `429 Too Many Requests`.
+/// `None` disables rate limiting
+pub fn set_rate_limiter(rate_limiter: Option<RateLimiter>) {
+    client::set_shared_rate_limiter(rate_limiter);
+}
+
+/// function for setting the retry policy of the api functions
+///
+/// idempotent `GET` requests are retried 3 times by default. see [`RetryPolicy`]
+pub fn set_retry_policy(retry_policy: RetryPolicy) {
+    client::set_shared_retry_policy(retry_policy);
+}
+
+/// function for getting the last `Remaining-Req` reported for `group` to the api functions
+pub fn remaining_req(group: &str) -> Option<RemainingReq> {
+    client::shared_remaining_req().get(group)
+}
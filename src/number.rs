@@ -0,0 +1,102 @@
+/// Numeric type of prices, volumes, amounts and fees
+///
+/// [`f64`] by default. With the `rust_decimal` feature it becomes [`rust_decimal::Decimal`], so
+/// the decimal strings Upbit sends and expects are kept without rounding errors.
+///
+/// Fields Upbit sends as JSON numbers rather than strings, such as those of tickers, candles,
+/// orderbooks and websocket messages, are read as [`f64`] by `serde_json` before they become a
+/// [`rust_decimal::Decimal`], so they carry the rounding of [`f64`] with either type.
+#[cfg(not(feature = "rust_decimal"))]
+pub type Number = f64;
+
+/// Numeric type of prices, volumes, amounts and fees
+///
+/// [`f64`] by default. With the `rust_decimal` feature it becomes [`rust_decimal::Decimal`], so
+/// the decimal strings Upbit sends and expects are kept without rounding errors.
+///
+/// Fields Upbit sends as JSON numbers rather than strings, such as those of tickers, candles,
+/// orderbooks and websocket messages, are read as [`f64`] by `serde_json` before they become a
+/// [`rust_decimal::Decimal`], so they carry the rounding of [`f64`] with either type.
+#[cfg(feature = "rust_decimal")]
+pub type Number = rust_decimal::Decimal;
+
+/// Build `mantissa * 10^-scale`. exact with `rust_decimal`, nearest value with [`f64`]
+pub(crate) fn from_parts(mantissa: u32, scale: u32) -> Number {
+    Number::from(mantissa) / Number::from(10u32.pow(scale))
+}
+
+/// Parse a number string of a response, in plain or exponent notation
+#[cfg(not(feature = "rust_decimal"))]
+pub(crate) fn from_wire(value: &str) -> Result<Number, std::num::ParseFloatError> {
+    value.parse()
+}
+
+/// Parse a number string of a response, in plain or exponent notation
+///
+/// `Decimal::from_str` rejects exponent notation [`f64`] reads, so it falls back to
+/// `Decimal::from_scientific` to accept the same strings.
+#[cfg(feature = "rust_decimal")]
+pub(crate) fn from_wire(value: &str) -> Result<Number, rust_decimal::Error> {
+    value.parse().or_else(|_| Number::from_scientific(value))
+}
+
+/// Format a number as Upbit expects in a request: plain notation, no trailing zeros
+///
+/// [`f64`] values are rounded to 8 decimal places first, which is the finest unit Upbit accepts.
+#[cfg(not(feature = "rust_decimal"))]
+pub(crate) fn to_wire(number: Number) -> String {
+    let formatted = format!("{number:.8}");
+
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_owned()
+}
+
+/// Format a number as Upbit expects in a request: plain notation, no trailing zeros
+///
+/// Digits past 8 decimal places, the finest unit Upbit accepts, are dropped.
+#[cfg(feature = "rust_decimal")]
+pub(crate) fn to_wire(number: Number) -> String {
+    number
+        .round_dp_with_strategy(8, rust_decimal::RoundingStrategy::ToZero)
+        .normalize()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_wire() {
+        assert_eq!(to_wire(from_parts(10000, 0)), "10000");
+        assert_eq!(to_wire(from_parts(1, 1) + from_parts(2, 1)), "0.3");
+        assert_eq!(to_wire(from_parts(12345, 8)), "0.00012345");
+        assert_eq!(to_wire(from_parts(1_500_000, 0)), "1500000");
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_to_wire_drops_digits_past_8_decimal_places() {
+        assert_eq!(to_wire(from_parts(1, 0) / from_parts(3, 0)), "0.33333333");
+        assert_eq!(to_wire(from_parts(2, 0) / from_parts(3, 0)), "0.66666666");
+    }
+
+    #[test]
+    fn test_from_wire() {
+        assert_eq!(from_wire("0.00012345").unwrap(), from_parts(12345, 8));
+        assert_eq!(from_wire("1.2345E-4").unwrap(), from_parts(12345, 8));
+        assert_eq!(from_wire("1.5e6").unwrap(), from_parts(1_500_000, 0));
+        assert!(from_wire("").is_err());
+        assert!(from_wire("krw").is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        let number: Number = "0.00012345".parse().unwrap();
+
+        assert_eq!(number, from_parts(12345, 8));
+        assert_eq!(to_wire("1500000.0".parse().unwrap()), "1500000");
+    }
+}
@@ -0,0 +1,449 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use reqwest::Method;
+use tokio::time::Instant;
+
+/// Name of the header carrying the remaining request quota
+pub const HEADER_REMAINING_REQ: &str = "Remaining-Req";
+
+/// Length of the window quotas are counted over, unless a group sets its own
+const RATE_WINDOW: Duration = Duration::from_secs(1);
+
+/// Parsed value of the `Remaining-Req` response header
+///
+/// ex) `group=default; min=1800; sec=29`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemainingReq {
+    /// request group the quota belongs to. ex) default, order, market, candles
+    pub group: String,
+    /// remaining requests in the current minute. no longer sent by every group
+    pub min: Option<u32>,
+    /// remaining requests in the current second
+    pub sec: u32,
+}
+
+impl RemainingReq {
+    /// Parse the header of `res`, if present
+    pub fn from_response(res: &reqwest::Response) -> Option<Self> {
+        res.headers()
+            .get(HEADER_REMAINING_REQ)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok())
+    }
+}
+
+impl Display for RemainingReq {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "group={}", self.group)?;
+
+        if let Some(min) = self.min {
+            write!(f, "; min={min}")?;
+        }
+
+        write!(f, "; sec={}", self.sec)
+    }
+}
+
+/// Error returned when a `Remaining-Req` header value is malformed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemainingReqParseError(pub String);
+
+impl Display for RemainingReqParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid Remaining-Req header: {}", self.0)
+    }
+}
+
+impl std::error::Error for RemainingReqParseError {}
+
+impl FromStr for RemainingReq {
+    type Err = RemainingReqParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut group = None;
+        let mut min = None;
+        let mut sec = None;
+
+        for pair in s.split(';') {
+            let Some((key, value)) = pair.split_once('=') else {
+                continue;
+            };
+
+            match key.trim() {
+                "group" => group = Some(value.trim().to_owned()),
+                "min" => min = value.trim().parse().ok(),
+                "sec" => sec = value.trim().parse().ok(),
+                _ => {}
+            }
+        }
+
+        match (group, sec) {
+            (Some(group), Some(sec)) => Ok(Self { group, min, sec }),
+            _ => Err(RemainingReqParseError(s.to_owned())),
+        }
+    }
+}
+
+/// Get the request group of an endpoint
+///
+/// | group            | endpoint                      |
+/// |:-----------------|:------------------------------|
+/// | market           | /v1/market/all                |
+/// | candles          | /v1/candles/*                 |
+/// | crix-trades      | /v1/trades/ticks              |
+/// | ticker           | /v1/ticker, /v1/ticker/all    |
+/// | orderbook        | /v1/orderbook                 |
+/// | order            | POST /v1/orders               |
+/// | order-test       | POST /v1/orders/test          |
+/// | order-cancel-all | DELETE /v1/orders/open        |
+/// | default          | every other exchange endpoint |
+pub fn rate_group(method: &Method, path: &str) -> &'static str {
+    if path.starts_with("/v1/market/") {
+        "market"
+    } else if path.starts_with("/v1/candles/") {
+        "candles"
+    } else if path.starts_with("/v1/trades/") {
+        "crix-trades"
+    } else if path.starts_with("/v1/ticker") {
+        "ticker"
+    } else if path.starts_with("/v1/orderbook") {
+        "orderbook"
+    } else if method == Method::DELETE && path.starts_with("/v1/orders/open") {
+        "order-cancel-all"
+    } else if method == Method::POST && path.starts_with("/v1/orders/test") {
+        "order-test"
+    } else if method == Method::POST && path.starts_with("/v1/orders") {
+        "order"
+    } else {
+        "default"
+    }
+}
+
+/// Last `Remaining-Req` seen for each request group
+#[derive(Debug, Clone, Default)]
+pub struct RemainingReqStore {
+    inner: Arc<Mutex<RemainingReqStoreInner>>,
+}
+
+#[derive(Debug, Default)]
+struct RemainingReqStoreInner {
+    groups: HashMap<String, RemainingReq>,
+    last: Option<RemainingReq>,
+}
+
+impl RemainingReqStore {
+    pub(crate) fn update(&self, remaining_req: &RemainingReq) {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+
+        inner
+            .groups
+            .insert(remaining_req.group.clone(), remaining_req.clone());
+        inner.last = Some(remaining_req.clone());
+    }
+
+    /// Get the last `Remaining-Req` of `group`
+    pub fn get(&self, group: &str) -> Option<RemainingReq> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .groups
+            .get(group)
+            .cloned()
+    }
+
+    /// Get the `Remaining-Req` of the most recent response
+    pub fn last(&self) -> Option<RemainingReq> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .last
+            .clone()
+    }
+}
+
+/// Per-group async rate limiter
+///
+/// Requests over the quota of a group wait for the next one-second window instead of being
+/// answered with `429 Too Many Requests`. The window is also closed early whenever the server
+/// reports `sec=0` for the group.
+///
+/// # Example
+/// ```
+/// use upbit::{RateLimiter, UpbitClient};
+///
+/// let limiter = RateLimiter::new().with_quota("order", 5);
+/// let client = UpbitClient::new("access_key", "secret_key").with_rate_limiter(limiter);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    inner: Arc<Mutex<RateLimiterInner>>,
+}
+
+#[derive(Debug)]
+struct RateLimiterInner {
+    quotas: HashMap<String, u32>,
+    window_lengths: HashMap<String, Duration>,
+    windows: HashMap<String, RateWindow>,
+}
+
+impl RateLimiterInner {
+    fn window_length(&self, group: &str) -> Duration {
+        self.window_lengths
+            .get(group)
+            .copied()
+            .unwrap_or(RATE_WINDOW)
+    }
+}
+
+#[derive(Debug)]
+struct RateWindow {
+    started_at: Instant,
+    used: u32,
+    blocked_until: Option<Instant>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RateLimiter {
+    /// Create a limiter with the documented quota of each group
+    ///
+    /// | group            | quota         |
+    /// |:-----------------|:--------------|
+    /// | order            | 8 per second  |
+    /// | order-test       | 8 per second  |
+    /// | order-cancel-all | 1 per 2 secs  |
+    /// | default          | 30 per second |
+    /// | market           | 10 per second |
+    /// | candles          | 10 per second |
+    /// | crix-trades      | 10 per second |
+    /// | ticker           | 10 per second |
+    /// | orderbook        | 10 per second |
+    pub fn new() -> Self {
+        let quotas = [
+            ("order", 8),
+            ("order-test", 8),
+            ("order-cancel-all", 1),
+            ("default", 30),
+            ("market", 10),
+            ("candles", 10),
+            ("crix-trades", 10),
+            ("ticker", 10),
+            ("orderbook", 10),
+        ]
+        .into_iter()
+        .map(|(group, quota)| (group.to_owned(), quota))
+        .collect();
+        let window_lengths = [("order-cancel-all", Duration::from_secs(2))]
+            .into_iter()
+            .map(|(group, length)| (group.to_owned(), length))
+            .collect();
+
+        Self {
+            inner: Arc::new(Mutex::new(RateLimiterInner {
+                quotas,
+                window_lengths,
+                windows: HashMap::new(),
+            })),
+        }
+    }
+
+    /// Set the quota of `group` in requests per window, one second unless set by [`Self::with_window`]
+    pub fn with_quota(self, group: &str, per_window: u32) -> Self {
+        self.inner
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .quotas
+            .insert(group.to_owned(), per_window.max(1));
+        self
+    }
+
+    /// Set the length of the window the quota of `group` is counted over
+    pub fn with_window(self, group: &str, length: Duration) -> Self {
+        self.inner
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .window_lengths
+            .insert(group.to_owned(), length);
+        self
+    }
+
+    /// Get the quota of `group` in requests per window
+    pub fn quota(&self, group: &str) -> Option<u32> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .quotas
+            .get(group)
+            .copied()
+    }
+
+    /// Get the length of the window the quota of `group` is counted over
+    pub fn window(&self, group: &str) -> Duration {
+        self.inner
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .window_length(group)
+    }
+
+    /// Check `self` and `other` are clones sharing their quotas
+    #[cfg(test)]
+    pub(crate) fn shares_quotas_with(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Wait until a request of `group` may be sent
+    pub async fn acquire(&self, group: &str) {
+        loop {
+            let wait_until = {
+                let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+                let quota = inner.quotas.get(group).copied();
+                let window_length = inner.window_length(group);
+                let now = Instant::now();
+                let window = inner
+                    .windows
+                    .entry(group.to_owned())
+                    .or_insert_with(|| RateWindow {
+                        started_at: now,
+                        used: 0,
+                        blocked_until: None,
+                    });
+
+                match window.blocked_until {
+                    Some(blocked_until) if blocked_until > now => Some(blocked_until),
+                    _ => {
+                        window.blocked_until = None;
+
+                        if now.duration_since(window.started_at) >= window_length {
+                            window.started_at = now;
+                            window.used = 0;
+                        }
+
+                        match quota {
+                            Some(quota) if window.used >= quota => {
+                                Some(window.started_at + window_length)
+                            }
+                            _ => {
+                                window.used += 1;
+                                None
+                            }
+                        }
+                    }
+                }
+            };
+
+            match wait_until {
+                Some(wait_until) => tokio::time::sleep_until(wait_until).await,
+                None => return,
+            }
+        }
+    }
+
+    /// Feed the quota reported by the server
+    pub fn update(&self, remaining_req: &RemainingReq) {
+        if remaining_req.sec == 0 {
+            self.block(&remaining_req.group, self.window(&remaining_req.group));
+        }
+    }
+
+    /// Hold every request of `group` for `duration`, e.g. after `429 Too Many Requests`
+    pub fn block(&self, group: &str, duration: Duration) {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        let window = inner
+            .windows
+            .entry(group.to_owned())
+            .or_insert_with(|| RateWindow {
+                started_at: now,
+                used: 0,
+                blocked_until: None,
+            });
+
+        window.blocked_until = Some(now + duration);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_remaining_req() {
+        let remaining_req = "group=default; min=1800; sec=29"
+            .parse::<RemainingReq>()
+            .unwrap();
+
+        assert_eq!(remaining_req.group, "default");
+        assert_eq!(remaining_req.min, Some(1800));
+        assert_eq!(remaining_req.sec, 29);
+        assert_eq!(remaining_req.to_string(), "group=default; min=1800; sec=29");
+
+        let remaining_req = "group=order; sec=7".parse::<RemainingReq>().unwrap();
+        assert_eq!(remaining_req.min, None);
+
+        assert!("group=order".parse::<RemainingReq>().is_err());
+        assert!("".parse::<RemainingReq>().is_err());
+    }
+
+    #[test]
+    fn test_rate_group() {
+        assert_eq!(rate_group(&Method::GET, "/v1/candles/minutes/1"), "candles");
+        assert_eq!(rate_group(&Method::GET, "/v1/ticker/all"), "ticker");
+        assert_eq!(rate_group(&Method::POST, "/v1/orders"), "order");
+        assert_eq!(rate_group(&Method::POST, "/v1/orders/test"), "order-test");
+        assert_eq!(rate_group(&Method::GET, "/v1/orders/open"), "default");
+        assert_eq!(
+            rate_group(&Method::DELETE, "/v1/orders/open"),
+            "order-cancel-all"
+        );
+        assert_eq!(rate_group(&Method::GET, "/v1/accounts"), "default");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limiter_queues_over_quota() {
+        let limiter = RateLimiter::new().with_quota("candles", 2);
+        let started_at = Instant::now();
+
+        for _ in 0..5 {
+            limiter.acquire("candles").await;
+        }
+
+        assert!(Instant::now().duration_since(started_at) >= Duration::from_secs(2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limiter_queues_over_quota_of_longer_window() {
+        let limiter = RateLimiter::new();
+        let started_at = Instant::now();
+
+        assert_eq!(limiter.window("order-cancel-all"), Duration::from_secs(2));
+
+        for _ in 0..3 {
+            limiter.acquire("order-cancel-all").await;
+        }
+
+        assert!(Instant::now().duration_since(started_at) >= Duration::from_secs(4));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limiter_blocks_on_exhausted_header() {
+        let limiter = RateLimiter::new();
+        let started_at = Instant::now();
+
+        limiter.update(&RemainingReq {
+            group: "order".to_owned(),
+            min: None,
+            sec: 0,
+        });
+        limiter.acquire("order").await;
+
+        assert!(Instant::now().duration_since(started_at) >= RATE_WINDOW);
+    }
+}
@@ -13,22 +13,8 @@ pub trait Request {
     fn set_token() -> Result<String, ResponseError> {
         let access_key = envmnt::get_or_panic("ACCESS_KEY");
         let secret_key = envmnt::get_or_panic("SECRET_KEY");
-        let alg = Algorithm::new_hmac(AlgorithmID::HS256, secret_key)
-            .map_err(response_error_internal_hmac_error)?;
 
-        let header = json!({
-            "alg": alg.name()
-        });
-
-        let payload = json!({
-            "access_key": access_key,
-            "nonce": Uuid::new_v4(),
-        });
-
-        let token = jwt::encode(&header, &payload, &alg)
-            .map_err(response_error_internal_token_encode_error)?;
-
-        Ok(format!("Bearer {token}"))
+        token(&access_key, &secret_key)
     }
 }
 
@@ -36,31 +22,60 @@ pub trait RequestWithQuery {
     fn set_token_with_query(url: &str) -> Result<String, ResponseError> {
         let access_key = envmnt::get_or_panic("ACCESS_KEY");
         let secret_key = envmnt::get_or_panic("SECRET_KEY");
-        let url =
-            Url::parse(url).map_err(crate::response::response_error_internal_url_parse_error)?;
-        let url_parsed = url.query().unwrap_or("");
 
-        let mut hasher = Sha512::new();
-        hasher.update(url_parsed.as_bytes());
+        token_with_query(&access_key, &secret_key, url)
+    }
+}
 
-        let hasher_hex = format!("{:x}", hasher.finalize());
-        let alg = Algorithm::new_hmac(AlgorithmID::HS256, secret_key)
-            .map_err(response_error_internal_hmac_error)?;
+/// Build a bearer token for requests without query parameters
+pub(crate) fn token(access_key: &str, secret_key: &str) -> Result<String, ResponseError> {
+    let alg = Algorithm::new_hmac(AlgorithmID::HS256, secret_key)
+        .map_err(response_error_internal_hmac_error)?;
 
-        let header = json!({
-            "alg": alg.name()
-        });
+    let header = json!({
+        "alg": alg.name()
+    });
 
-        let payload = json!({
-            "access_key": access_key,
-            "nonce": Uuid::new_v4(),
-            "query_hash": hasher_hex,
-            "query_hash_alg": "SHA512",
-        });
+    let payload = json!({
+        "access_key": access_key,
+        "nonce": Uuid::new_v4(),
+    });
 
-        let token = jwt::encode(&header, &payload, &alg)
-            .map_err(response_error_internal_token_encode_error)?;
+    let token =
+        jwt::encode(&header, &payload, &alg).map_err(response_error_internal_token_encode_error)?;
 
-        Ok(format!("Bearer {token}"))
-    }
+    Ok(format!("Bearer {token}"))
+}
+
+/// Build a bearer token signing the query string of `url`
+pub(crate) fn token_with_query(
+    access_key: &str,
+    secret_key: &str,
+    url: &str,
+) -> Result<String, ResponseError> {
+    let url = Url::parse(url).map_err(crate::response::response_error_internal_url_parse_error)?;
+    let url_parsed = url.query().unwrap_or("");
+
+    let mut hasher = Sha512::new();
+    hasher.update(url_parsed.as_bytes());
+
+    let hasher_hex = format!("{:x}", hasher.finalize());
+    let alg = Algorithm::new_hmac(AlgorithmID::HS256, secret_key)
+        .map_err(response_error_internal_hmac_error)?;
+
+    let header = json!({
+        "alg": alg.name()
+    });
+
+    let payload = json!({
+        "access_key": access_key,
+        "nonce": Uuid::new_v4(),
+        "query_hash": hasher_hex,
+        "query_hash_alg": "SHA512",
+    });
+
+    let token =
+        jwt::encode(&header, &payload, &alg).map_err(response_error_internal_token_encode_error)?;
+
+    Ok(format!("Bearer {token}"))
 }
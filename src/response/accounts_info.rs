@@ -1,13 +1,14 @@
+use crate::number::Number;
 use crate::request::Request;
 use serde::{Deserialize, Serialize};
 
 /// Deserialized and parsed account info data
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AccountsInfo {
     pub currency: String,
-    pub balance: f64,
-    pub locked: f64,
-    pub avg_buy_price: f64,
+    pub balance: Number,
+    pub locked: Number,
+    pub avg_buy_price: Number,
     pub avg_buy_price_modified: bool,
     pub unit_currency: String,
 }
@@ -30,17 +31,17 @@ impl AccountsInfoSource {
     pub fn currency(&self) -> String {
         self.currency.to_owned()
     }
-    /// Convert type of balance into [f64]
-    pub fn balance(&self) -> f64 {
-        self.balance.parse().unwrap()
+    /// Convert type of balance into [Number]
+    pub fn balance(&self) -> Number {
+        crate::number::from_wire(&self.balance).unwrap()
     }
-    /// Convert type of locked into [f64]
-    pub fn locked(&self) -> f64 {
-        self.locked.parse().unwrap()
+    /// Convert type of locked into [Number]
+    pub fn locked(&self) -> Number {
+        crate::number::from_wire(&self.locked).unwrap()
     }
-    /// Convert type of avg_buy_price into [f64]
-    pub fn avg_buy_price(&self) -> f64 {
-        self.avg_buy_price.parse().unwrap()
+    /// Convert type of avg_buy_price into [Number]
+    pub fn avg_buy_price(&self) -> Number {
+        crate::number::from_wire(&self.avg_buy_price).unwrap()
     }
     /// Convert type of avg_buy_price_modified into [bool]
     pub fn avg_buy_price_modified(&self) -> bool {
@@ -51,3 +52,23 @@ impl AccountsInfoSource {
         self.unit_currency.to_owned()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accounts_info_source_reads_exponent_notation() {
+        let source = serde_json::from_str::<AccountsInfoSource>(
+            r#"{"currency":"BTC","balance":"1.5E-7","locked":"0.0","avg_buy_price":"101000000","avg_buy_price_modified":false,"unit_currency":"KRW"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            source.balance(),
+            crate::number::from_wire("0.00000015").unwrap()
+        );
+        assert_eq!(source.locked(), Number::default());
+        assert_eq!(source.avg_buy_price(), Number::from(101_000_000));
+    }
+}
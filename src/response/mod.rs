@@ -1,9 +1,13 @@
 /// Module of account info
 mod accounts_info;
+/// Module of order cancel result
+mod order_cancel_result;
 /// Module of order chance
 mod order_chance;
 /// Module of order info
 mod order_info;
+/// Module of order replacement
+mod order_replacement;
 /// Module of order status
 mod order_status;
 /// Module of response error
@@ -12,8 +16,10 @@ mod response_error;
 mod transaction_info;
 
 pub use accounts_info::*;
+pub use order_cancel_result::*;
 pub use order_chance::*;
 pub use order_info::*;
+pub use order_replacement::*;
 pub use order_status::*;
 pub use response_error::*;
 pub use transaction_info::*;
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+/// Deserialized order of [OrderCancelResult]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct OrderCancelItem {
+    pub uuid: String,
+    pub market: String,
+    pub identifier: Option<String>,
+}
+
+/// Deserialized result of cancelling orders at once
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct OrderCancelResult {
+    /// orders cancelled
+    pub success: Vec<OrderCancelItem>,
+    /// orders which couldn't be cancelled, such as orders already filled
+    pub failed: Vec<OrderCancelItem>,
+}
+
+impl OrderCancelResult {
+    /// Check every order was cancelled
+    pub fn is_all_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Raw OrderCancelResult data from serialized data
+#[derive(Deserialize)]
+pub struct OrderCancelResultSource {
+    #[serde(default)]
+    success: OrderCancelGroupSource,
+    #[serde(default)]
+    failed: OrderCancelGroupSource,
+}
+
+/// Raw success or failed group of [OrderCancelResultSource]
+#[derive(Deserialize, Default)]
+pub struct OrderCancelGroupSource {
+    #[serde(default)]
+    orders: Vec<OrderCancelItem>,
+}
+
+impl From<OrderCancelResultSource> for OrderCancelResult {
+    fn from(x: OrderCancelResultSource) -> Self {
+        Self {
+            success: x.success.orders,
+            failed: x.failed.orders,
+        }
+    }
+}
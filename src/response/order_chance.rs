@@ -3,12 +3,13 @@ use serde::{Deserialize, Serialize};
 use crate::{
     api_exchange::OrderSide,
     constant::{AskType, BidType},
+    number::Number,
 };
 
 use super::accounts_info::*;
 
 /// Deserialized ObjectMarket data of [OrderChance].
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ObjectMarket {
     pub id: String,
     pub name: String,
@@ -18,28 +19,28 @@ pub struct ObjectMarket {
     pub order_sides: Vec<OrderSide>,
     pub bid: ObjectAskBid,
     pub ask: ObjectAskBid,
-    pub max_total: u64,
+    pub max_total: Number,
     pub state: String,
 }
 
 /// Deserialized ObjectAskBid info of [ObjectMarket].
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ObjectAskBid {
     pub currency: String,
     pub price_unit: Option<String>,
-    pub min_total: u32,
+    pub min_total: Number,
 }
 
 /// Raw OrderChance data from serialized data
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct OrderChance {
-    pub bid_fee: f32,
-    pub ask_fee: f32,
+    pub bid_fee: Number,
+    pub ask_fee: Number,
     pub market: ObjectMarket,
     pub bid_account: AccountsInfo,
     pub ask_account: AccountsInfo,
-    pub maker_bid_fee: f32,
-    pub maker_ask_fee: f32,
+    pub maker_bid_fee: Number,
+    pub maker_ask_fee: Number,
 }
 
 /// Raw ObjectAskBidSource data of [OrderChanceSource]
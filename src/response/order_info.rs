@@ -1,4 +1,5 @@
 use crate::api_exchange::OrderSide;
+use crate::number::Number;
 use crate::{
     api_exchange::{OrderCondition, OrderState, OrderType},
     request::{Request, RequestWithQuery},
@@ -11,18 +12,18 @@ pub struct OrderInfo {
     pub uuid: String,
     pub side: OrderSide,
     pub ord_type: OrderType,
-    pub price: Option<f64>,
+    pub price: Option<Number>,
     pub state: OrderState,
     pub market: String,
     pub created_at: chrono::NaiveDateTime,
-    pub volume: f64,
-    pub remaining_volume: f64,
-    pub reserved_fee: f64,
-    pub remaining_fee: f64,
-    pub paid_fee: f64,
-    pub locked: f64,
-    pub executed_volume: f64,
-    pub executed_funds: Option<f64>,
+    pub volume: Number,
+    pub remaining_volume: Number,
+    pub reserved_fee: Number,
+    pub remaining_fee: Number,
+    pub paid_fee: Number,
+    pub locked: Number,
+    pub executed_volume: Number,
+    pub executed_funds: Option<Number>,
     pub trades_count: i64,
     pub time_in_force: Option<OrderCondition>,
 }
@@ -40,8 +41,11 @@ pub struct OrderInfoSource {
     state: String,
     market: String,
     created_at: String,
-    volume: String,
-    remaining_volume: String,
+    /// null for bids placed by funds
+    #[serde(default)]
+    volume: Option<String>,
+    #[serde(default)]
+    remaining_volume: Option<String>,
     reserved_fee: String,
     remaining_fee: String,
     paid_fee: String,
@@ -59,19 +63,21 @@ impl OrderInfoSource {
     }
     /// Convert [String] type of side into [OrderSide]
     pub fn side(&self) -> OrderSide {
-        self.side.as_str().into()
+        OrderSide::parse_or_unknown(&self.side)
     }
     /// Convert [String] type of ord_type into [OrderType]
     pub fn ord_type(&self) -> OrderType {
-        self.ord_type.as_str().into()
+        OrderType::parse_or_unknown(&self.ord_type)
     }
-    /// Convert [String] type of price into [f64]
-    pub fn price(&self) -> Option<f64> {
-        self.price.as_ref().and_then(|x| x.parse().ok())
+    /// Convert [String] type of price into [Number]
+    pub fn price(&self) -> Option<Number> {
+        self.price
+            .as_ref()
+            .and_then(|x| crate::number::from_wire(x).ok())
     }
     /// Convert [String] type of state into [OrderState]
     pub fn state(&self) -> OrderState {
-        self.state.as_str().into()
+        OrderState::parse_or_unknown(&self.state)
     }
     /// Get market
     pub fn market(&self) -> String {
@@ -83,39 +89,47 @@ impl OrderInfoSource {
             .unwrap()
             .naive_local()
     }
-    /// Convert [String] type of volume into [f64]
-    pub fn volume(&self) -> f64 {
-        self.volume.parse().unwrap()
+    /// Convert [String] type of volume into [Number]. 0 for bids placed by funds
+    pub fn volume(&self) -> Number {
+        self.volume
+            .as_ref()
+            .map(|x| crate::number::from_wire(x).unwrap())
+            .unwrap_or_default()
     }
-    /// Convert [String] type of remaining_volume into [f64]
-    pub fn remaining_volume(&self) -> f64 {
-        self.remaining_volume.parse().unwrap()
+    /// Convert [String] type of remaining_volume into [Number]. 0 for bids placed by funds
+    pub fn remaining_volume(&self) -> Number {
+        self.remaining_volume
+            .as_ref()
+            .map(|x| crate::number::from_wire(x).unwrap())
+            .unwrap_or_default()
     }
-    /// Convert [String] type of reserved_fee into [f64]
-    pub fn reserved_fee(&self) -> f64 {
-        self.reserved_fee.parse().unwrap()
+    /// Convert [String] type of reserved_fee into [Number]
+    pub fn reserved_fee(&self) -> Number {
+        crate::number::from_wire(&self.reserved_fee).unwrap()
     }
-    /// Convert [String] type of remaining_fee into [f64]
-    pub fn remaining_fee(&self) -> f64 {
-        self.remaining_fee.parse().unwrap()
+    /// Convert [String] type of remaining_fee into [Number]
+    pub fn remaining_fee(&self) -> Number {
+        crate::number::from_wire(&self.remaining_fee).unwrap()
     }
-    /// Convert [String] type of paid_fee into [f64]
-    pub fn paid_fee(&self) -> f64 {
-        self.paid_fee.parse().unwrap()
+    /// Convert [String] type of paid_fee into [Number]
+    pub fn paid_fee(&self) -> Number {
+        crate::number::from_wire(&self.paid_fee).unwrap()
     }
-    /// Convert [String] type of locked into [f64]
-    pub fn locked(&self) -> f64 {
-        self.locked.parse().unwrap()
+    /// Convert [String] type of locked into [Number]
+    pub fn locked(&self) -> Number {
+        crate::number::from_wire(&self.locked).unwrap()
     }
-    /// Convert [String] type of executed_volume into [f64]
-    pub fn executed_volume(&self) -> f64 {
-        self.executed_volume.parse().unwrap()
+    /// Convert [String] type of executed_volume into [Number]
+    pub fn executed_volume(&self) -> Number {
+        crate::number::from_wire(&self.executed_volume).unwrap()
     }
-    /// Convert [String] type of executed_funds into [f64]
-    pub fn executed_funds(&self) -> Option<f64> {
-        self.executed_funds.as_ref().and_then(|x| x.parse().ok())
+    /// Convert [String] type of executed_funds into [Number]
+    pub fn executed_funds(&self) -> Option<Number> {
+        self.executed_funds
+            .as_ref()
+            .and_then(|x| crate::number::from_wire(x).ok())
     }
-    /// Convert [String] type of trades_count into [f64]
+    /// Convert [String] type of trades_count into [Number]
     pub fn trades_count(&self) -> i64 {
         self.trades_count
     }
@@ -123,6 +137,30 @@ impl OrderInfoSource {
     pub fn time_in_force(&self) -> Option<OrderCondition> {
         self.time_in_force
             .as_ref()
-            .map(|x| OrderCondition::from(x.as_str()))
+            .map(|x| OrderCondition::parse_or_unknown(x))
+    }
+}
+
+impl From<OrderInfoSource> for OrderInfo {
+    fn from(x: OrderInfoSource) -> Self {
+        Self {
+            uuid: x.uuid(),
+            side: x.side(),
+            ord_type: x.ord_type(),
+            price: x.price(),
+            state: x.state(),
+            market: x.market(),
+            created_at: x.created_at(),
+            volume: x.volume(),
+            remaining_volume: x.remaining_volume(),
+            reserved_fee: x.reserved_fee(),
+            remaining_fee: x.remaining_fee(),
+            paid_fee: x.paid_fee(),
+            locked: x.locked(),
+            executed_volume: x.executed_volume(),
+            executed_funds: x.executed_funds(),
+            trades_count: x.trades_count(),
+            time_in_force: x.time_in_force(),
+        }
     }
 }
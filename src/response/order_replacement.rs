@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use super::{OrderInfo, OrderInfoSource};
+
+/// Deserialized result of cancelling an order and placing another in its stead
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OrderReplacement {
+    /// order cancelled
+    pub cancelled: OrderInfo,
+    /// uuid of the order placed
+    pub new_order_uuid: String,
+    /// identifier of the order placed, if it was given one
+    pub new_order_identifier: Option<String>,
+}
+
+/// Raw OrderReplacement data from serialized data
+#[derive(Deserialize)]
+pub struct OrderReplacementSource {
+    #[serde(flatten)]
+    cancelled: OrderInfoSource,
+    new_order_uuid: String,
+    new_order_identifier: Option<String>,
+}
+
+impl From<OrderReplacementSource> for OrderReplacement {
+    fn from(x: OrderReplacementSource) -> Self {
+        Self {
+            cancelled: x.cancelled.into(),
+            new_order_uuid: x.new_order_uuid,
+            new_order_identifier: x.new_order_identifier,
+        }
+    }
+}
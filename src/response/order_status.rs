@@ -1,4 +1,5 @@
 use super::order_info::*;
+use crate::number::Number;
 use serde::{Deserialize, Serialize};
 
 /// Deserialized ObjectTrades data of [OrderStatus].
@@ -6,9 +7,9 @@ use serde::{Deserialize, Serialize};
 pub struct ObjectTrades {
     pub market: String,
     pub uuid: String,
-    pub price: f64,
-    pub volume: f64,
-    pub funds: f64,
+    pub price: Number,
+    pub volume: Number,
+    pub funds: Number,
     pub side: String,
     pub created_at: String,
 }
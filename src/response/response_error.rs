@@ -1,8 +1,13 @@
+use std::fmt::Display;
+
+use reqwest::{Response, StatusCode};
 use serde::Deserialize;
 use serde_json::Error;
 
+use crate::rate_limit::RemainingReq;
+
 /// List of response error item
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
 pub enum ResponseErrorState {
     /// "internal_reqwest_error"
     InternalReqwestError,
@@ -16,6 +21,8 @@ pub enum ResponseErrorState {
     InternalUrlParseError,
     /// "internal_date_format_parse_error"
     InternalDateFormatParseError,
+    /// "internal_websocket_error"
+    InternalWebSocketError,
     /// "custom_error_no_data_present"
     CustomErrorNoDataPresent,
     /// "JWT 헤더의 페이로드가 올바르지 않습니다."
@@ -78,6 +85,14 @@ pub enum ResponseErrorState {
     ValidationError,
     /// "서버 에러"
     ServerError,
+    /// "요청 수 제한을 초과했습니다."
+    TooManyRequests,
+    /// "주문 가능 금액이 올바르지 않습니다."
+    InvalidFunds,
+    /// "출금 수량의 소수점 자리수가 허용 범위를 초과했습니다."
+    WithdrawDecimalPlaceLimit,
+    /// "2차 인증이 필요합니다."
+    TwoFactorAuthRequired,
     /// unhandled error
     UnexpectedError,
 }
@@ -91,8 +106,9 @@ impl From<&str> for ResponseErrorState {
             "internal_json_parse_error" => Self::InternalJsonParseError,
             "internal_url_parse_error" => Self::InternalUrlParseError,
             "internal_date_format_parse_error" => Self::InternalDateFormatParseError,
+            "internal_websocket_error" => Self::InternalWebSocketError,
             "custom_error_no_data_present" => Self::CustomErrorNoDataPresent,
-            "jwt_verification" => Self::JwtVerificationError,
+            "jwt_verification" | "INVALID_AUTH" => Self::JwtVerificationError,
             "expired_access_key" => Self::ExpiredAccessKey,
             "invalid_query_payload" => Self::InvalidQueryPayload,
             "invalid_access_key" => Self::InvalidAccessKey,
@@ -111,7 +127,9 @@ impl From<&str> for ResponseErrorState {
             "no_authorization_i_p" => Self::NoAuthorizationIp,
             "out_of_scope" => Self::OutOfScope,
             "withdraw_not_found" => Self::WithdrawNotFound,
-            "withdraw_address_not_registered" => Self::WithdrawAddressNotRegisterd,
+            "withdraw_address_not_registered" | "withdraw_address_not_registerd" => {
+                Self::WithdrawAddressNotRegisterd
+            }
             "withdraw_insufficient_balance" => Self::WithdrawInsufficientBalance,
             "withdraw_amount_too_small" => Self::WithdrawAmountTooSmall,
             "order_not_found" => Self::OrderNotFound,
@@ -119,18 +137,197 @@ impl From<&str> for ResponseErrorState {
             "notfoundmarket" => Self::NotFoundMarket,
             "validation_error" => Self::ValidationError,
             "server_error" => Self::ServerError,
+            "too_many_requests" => Self::TooManyRequests,
+            "invalid_funds" => Self::InvalidFunds,
+            "withdraw_decimal_place_limit" => Self::WithdrawDecimalPlaceLimit,
+            "two_factor_auth_required" => Self::TwoFactorAuthRequired,
             _ => Self::UnexpectedError,
         }
     }
 }
 
+/// Category of a response error, for reacting to errors generically
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseErrorCategory {
+    /// temporary failure. the same request may succeed later
+    Retryable,
+    /// credentials are invalid, expired or not allowed to do the request
+    Auth,
+    /// parameters of the request are invalid
+    Validation,
+    /// not enough balance to fulfill the request
+    Balance,
+    /// requested item does not exist
+    NotFound,
+    /// failure inside the crate before or after the request
+    Internal,
+    /// not classified
+    Other,
+}
+
+impl ResponseErrorState {
+    /// Get category of the error
+    pub fn category(&self) -> ResponseErrorCategory {
+        match self {
+            Self::ServerError | Self::NonceUsed | Self::TooManyRequests => {
+                ResponseErrorCategory::Retryable
+            }
+            Self::InvalidQueryPayload
+            | Self::InvalidAccessKey
+            | Self::JwtVerificationError
+            | Self::ExpiredAccessKey
+            | Self::NoAuthorizationIp
+            | Self::OutOfScope
+            | Self::TwoFactorAuthRequired => ResponseErrorCategory::Auth,
+            Self::InvalideVolumeBid
+            | Self::InvalidPriceBid
+            | Self::InvalidParameter
+            | Self::UnderMinTotalAsk
+            | Self::UnderMinTotalBid
+            | Self::CreateAskError
+            | Self::CreateBidError
+            | Self::DuplicatedIdentifier
+            | Self::WithdrawAddressNotRegisterd
+            | Self::WithdrawAmountTooSmall
+            | Self::WithdrawDecimalPlaceLimit
+            | Self::NotSupportedOrdType
+            | Self::ValidationError => ResponseErrorCategory::Validation,
+            Self::InsufficientFundsAsk
+            | Self::InsufficientFundsBid
+            | Self::WithdrawInsufficientBalance
+            | Self::InvalidFunds => ResponseErrorCategory::Balance,
+            Self::OrderNotFound
+            | Self::CoinAddressNotFound
+            | Self::WithdrawNotFound
+            | Self::NotFoundMarket
+            | Self::CustomErrorNoDataPresent => ResponseErrorCategory::NotFound,
+            Self::InternalReqwestError
+            | Self::InternalHmacError
+            | Self::InternalTokenEncodeError
+            | Self::InternalJsonParseError
+            | Self::InternalUrlParseError
+            | Self::InternalDateFormatParseError
+            | Self::InternalWebSocketError => ResponseErrorCategory::Internal,
+            Self::UnexpectedError => ResponseErrorCategory::Other,
+        }
+    }
+
+    /// Whether the same request may succeed when sent again
+    pub fn is_retryable(&self) -> bool {
+        self.category() == ResponseErrorCategory::Retryable
+    }
+
+    /// Whether the credentials are invalid, expired or lack permission
+    pub fn is_auth_failure(&self) -> bool {
+        self.category() == ResponseErrorCategory::Auth
+    }
+
+    /// Whether parameters of the request are invalid
+    pub fn is_validation_failure(&self) -> bool {
+        self.category() == ResponseErrorCategory::Validation
+    }
+
+    /// Whether the balance is not enough for the request
+    pub fn is_balance_failure(&self) -> bool {
+        self.category() == ResponseErrorCategory::Balance
+    }
+
+    /// Whether the requested item does not exist
+    pub fn is_not_found(&self) -> bool {
+        self.category() == ResponseErrorCategory::NotFound
+    }
+}
+
 /// Derived Response error data
+///
+/// Requests failed before a response arrived (connection, signing, ...) carry no `status`.
 #[derive(Deserialize, Debug)]
 pub struct ResponseError {
     pub state: ResponseErrorState,
     pub error: ResponseErrorBody,
+    /// HTTP status code of the response
+    #[serde(skip)]
+    pub status: Option<StatusCode>,
+    /// Raw body of the response
+    #[serde(skip)]
+    pub body: Option<String>,
+    /// Remaining request quota reported by the response
+    #[serde(skip)]
+    pub remaining_req: Option<RemainingReq>,
+}
+
+impl ResponseError {
+    pub fn new(
+        state: ResponseErrorState,
+        name: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            state,
+            error: ResponseErrorBody {
+                name: name.into(),
+                message: message.into(),
+            },
+            status: None,
+            body: None,
+            remaining_req: None,
+        }
+    }
 }
 
+impl ResponseError {
+    /// Get category of the error, taking the HTTP status into account
+    ///
+    /// `429 Too Many Requests` and `5xx` responses are retryable whatever their body says.
+    pub fn category(&self) -> ResponseErrorCategory {
+        match self.status {
+            Some(status) if status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error() => {
+                ResponseErrorCategory::Retryable
+            }
+            _ => self.state.category(),
+        }
+    }
+
+    /// Whether the same request may succeed when sent again
+    pub fn is_retryable(&self) -> bool {
+        self.category() == ResponseErrorCategory::Retryable
+    }
+
+    /// Whether the credentials are invalid, expired or lack permission
+    pub fn is_auth_failure(&self) -> bool {
+        self.category() == ResponseErrorCategory::Auth
+    }
+
+    /// Whether parameters of the request are invalid
+    pub fn is_validation_failure(&self) -> bool {
+        self.category() == ResponseErrorCategory::Validation
+    }
+
+    /// Whether the balance is not enough for the request
+    pub fn is_balance_failure(&self) -> bool {
+        self.category() == ResponseErrorCategory::Balance
+    }
+
+    /// Whether the requested item does not exist
+    pub fn is_not_found(&self) -> bool {
+        self.category() == ResponseErrorCategory::NotFound
+    }
+}
+
+impl Display for ResponseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.error.name, self.error.message)?;
+
+        if let Some(status) = self.status {
+            write!(f, " (HTTP {status})")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ResponseError {}
+
 /// Original error data structure
 #[derive(Deserialize, Debug)]
 pub struct ResponseErrorSource {
@@ -138,80 +335,253 @@ pub struct ResponseErrorSource {
 }
 
 /// Error body
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct ResponseErrorBody {
     pub name: String,
     pub message: String,
 }
 
 pub fn response_error(e: ResponseErrorSource) -> ResponseError {
-    ResponseError {
-        state: ResponseErrorState::from(e.error.name.as_str()),
-        error: ResponseErrorBody {
-            name: e.error.name,
-            message: e.error.message,
-        },
+    ResponseError::new(
+        ResponseErrorState::from(e.error.name.as_str()),
+        e.error.name,
+        e.error.message,
+    )
+}
+
+/// Build an error from a response whose status is not successful
+///
+/// Bodies which are not an Upbit error object, like the HTML page of a 502, are kept in `body`.
+pub fn response_error_from_status(
+    status: StatusCode,
+    body: String,
+    remaining_req: Option<RemainingReq>,
+) -> ResponseError {
+    let mut error = match serde_json::from_str::<ResponseErrorSource>(&body) {
+        Ok(source) => response_error(source),
+        Err(_) if status == StatusCode::TOO_MANY_REQUESTS => ResponseError::new(
+            ResponseErrorState::TooManyRequests,
+            "too_many_requests",
+            status.canonical_reason().unwrap_or("too many requests"),
+        ),
+        Err(_) if status.is_server_error() => ResponseError::new(
+            ResponseErrorState::ServerError,
+            "server_error",
+            status.canonical_reason().unwrap_or("server error"),
+        ),
+        Err(_) => ResponseError::new(
+            ResponseErrorState::UnexpectedError,
+            "unexpected_error",
+            status.canonical_reason().unwrap_or("unexpected error"),
+        ),
+    };
+
+    error.status = Some(status);
+    error.body = Some(body);
+    error.remaining_req = remaining_req;
+    error
+}
+
+/// Read the body of `res`, failing by its HTTP status
+pub async fn response_text(res: Response) -> Result<String, ResponseError> {
+    let status = res.status();
+    let remaining_req = RemainingReq::from_response(&res);
+    let body = res.text().await.map_err(|e| {
+        let mut error = response_error_from_reqwest(e);
+        error.status = Some(status);
+        error.remaining_req = remaining_req.clone();
+        error
+    })?;
+
+    if status.is_success() {
+        Ok(body)
+    } else {
+        Err(response_error_from_status(status, body, remaining_req))
     }
 }
 
 pub fn response_error_from_json(e: Error) -> ResponseError {
-    ResponseError {
-        state: ResponseErrorState::InternalJsonParseError,
-        error: ResponseErrorBody {
-            name: "internal_json_parse_error".to_owned(),
-            message: e.to_string(),
-        },
-    }
+    ResponseError::new(
+        ResponseErrorState::InternalJsonParseError,
+        "internal_json_parse_error".to_owned(),
+        e.to_string(),
+    )
 }
 
 pub fn response_error_from_reqwest(e: reqwest::Error) -> ResponseError {
-    ResponseError {
-        state: ResponseErrorState::InternalReqwestError,
-        error: ResponseErrorBody {
-            name: "internal_reqwest_error".to_owned(),
-            message: e.to_string(),
-        },
-    }
+    ResponseError::new(
+        ResponseErrorState::InternalReqwestError,
+        "internal_reqwest_error".to_owned(),
+        e.to_string(),
+    )
+}
+
+pub fn response_error_invalid_parameter(error: impl std::fmt::Display) -> ResponseError {
+    ResponseError::new(
+        ResponseErrorState::InvalidParameter,
+        "invalid_parameter",
+        error.to_string(),
+    )
 }
 
 pub fn response_error_internal_hmac_error(error: impl std::fmt::Display) -> ResponseError {
-    ResponseError {
-        state: ResponseErrorState::InternalHmacError,
-        error: ResponseErrorBody {
-            name: "internal_hmac_error".to_owned(),
-            message: error.to_string(),
-        },
-    }
+    ResponseError::new(
+        ResponseErrorState::InternalHmacError,
+        "internal_hmac_error".to_owned(),
+        error.to_string(),
+    )
 }
 
 pub fn response_error_internal_token_encode_error(error: impl std::fmt::Display) -> ResponseError {
-    ResponseError {
-        state: ResponseErrorState::InternalTokenEncodeError,
-        error: ResponseErrorBody {
-            name: "internal_token_encode_error".to_owned(),
-            message: error.to_string(),
-        },
-    }
+    ResponseError::new(
+        ResponseErrorState::InternalTokenEncodeError,
+        "internal_token_encode_error".to_owned(),
+        error.to_string(),
+    )
 }
 
 pub fn response_error_internal_url_parse_error(error: impl std::fmt::Display) -> ResponseError {
-    ResponseError {
-        state: ResponseErrorState::InternalUrlParseError,
-        error: ResponseErrorBody {
-            name: "internal_url_parse_error".to_owned(),
-            message: error.to_string(),
-        },
-    }
+    ResponseError::new(
+        ResponseErrorState::InternalUrlParseError,
+        "internal_url_parse_error".to_owned(),
+        error.to_string(),
+    )
 }
 
 pub fn response_error_internal_date_format_parse_error(
     error: impl std::fmt::Display,
 ) -> ResponseError {
-    ResponseError {
-        state: ResponseErrorState::InternalDateFormatParseError,
-        error: ResponseErrorBody {
-            name: "internal_date_format_parse_error".to_owned(),
-            message: error.to_string(),
-        },
+    ResponseError::new(
+        ResponseErrorState::InternalDateFormatParseError,
+        "internal_date_format_parse_error".to_owned(),
+        error.to_string(),
+    )
+}
+
+pub fn response_error_internal_websocket_error(error: impl std::fmt::Display) -> ResponseError {
+    ResponseError::new(
+        ResponseErrorState::InternalWebSocketError,
+        "internal_websocket_error",
+        error.to_string(),
+    )
+}
+
+pub fn response_error_internal_json_parse_error(error: impl std::fmt::Display) -> ResponseError {
+    ResponseError::new(
+        ResponseErrorState::InternalJsonParseError,
+        "internal_json_parse_error",
+        error.to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::client::UpbitClient;
+    use crate::retry::RetryPolicy;
+
+    async fn client_responding(template: ResponseTemplate) -> (MockServer, UpbitClient) {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/market/all"))
+            .respond_with(template)
+            .mount(&server)
+            .await;
+
+        let client = UpbitClient::new("access_key", "secret_key")
+            .with_server_url(&server.uri())
+            .with_retry_policy(RetryPolicy::none());
+
+        (server, client)
+    }
+
+    #[tokio::test]
+    async fn test_error_from_non_json_body() {
+        let (_server, client) = client_responding(
+            ResponseTemplate::new(502).set_body_string("<html>Bad Gateway</html>"),
+        )
+        .await;
+
+        let error = client.get_market_state(false).await.unwrap_err();
+
+        assert_eq!(error.state, ResponseErrorState::ServerError);
+        assert_eq!(error.status, Some(StatusCode::BAD_GATEWAY));
+        assert_eq!(error.body.as_deref(), Some("<html>Bad Gateway</html>"));
+        assert_eq!(
+            error.to_string(),
+            "server_error: Bad Gateway (HTTP 502 Bad Gateway)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_error_from_upbit_error_body() {
+        let (_server, client) = client_responding(
+            ResponseTemplate::new(400)
+                .insert_header("Remaining-Req", "group=market; min=573; sec=9")
+                .set_body_string(
+                    r#"{"error":{"name":"invalid_parameter","message":"잘못된 파라미터"}}"#,
+                ),
+        )
+        .await;
+
+        let error = client.get_market_state(false).await.unwrap_err();
+
+        assert_eq!(error.state, ResponseErrorState::InvalidParameter);
+        assert_eq!(error.error.name, "invalid_parameter");
+        assert_eq!(error.status, Some(StatusCode::BAD_REQUEST));
+        assert_eq!(error.remaining_req.map(|x| x.sec), Some(9));
+    }
+
+    #[tokio::test]
+    async fn test_success_body_containing_error() {
+        let (_server, client) = client_responding(ResponseTemplate::new(200).set_body_string(
+            r#"[{"market":"KRW-ERROR","korean_name":"에러","english_name":"error","market_warning":"NONE","market_event":{"warning":false,"caution":{"PRICE_FLUCTUATIONS":false,"TRADING_VOLUME_SOARING":false,"DEPOSIT_AMOUNT_SOARING":false,"GLOBAL_PRICE_DIFFERENCES":false,"CONCENTRATION_OF_SMALL_ACCOUNTS":false}}}]"#,
+        ))
+        .await;
+
+        assert!(client.get_market_state(true).await.is_ok());
+    }
+
+    #[test]
+    fn test_error_category() {
+        assert!(ResponseErrorState::from("nonce_used").is_retryable());
+        assert!(ResponseErrorState::from("too_many_requests").is_retryable());
+        assert!(ResponseErrorState::from("expired_access_key").is_auth_failure());
+        assert!(ResponseErrorState::from("two_factor_auth_required").is_auth_failure());
+        assert!(ResponseErrorState::from("withdraw_decimal_place_limit").is_validation_failure());
+        assert!(ResponseErrorState::from("invalid_funds").is_balance_failure());
+        assert!(ResponseErrorState::from("insufficient_funds_bid").is_balance_failure());
+        assert!(ResponseErrorState::from("order_not_found").is_not_found());
+        assert_eq!(
+            ResponseErrorState::from("withdraw_address_not_registerd"),
+            ResponseErrorState::WithdrawAddressNotRegisterd
+        );
+        assert_eq!(
+            ResponseErrorState::from("unheard_of").category(),
+            ResponseErrorCategory::Other
+        );
+    }
+
+    #[tokio::test]
+    async fn test_error_category_by_status() {
+        let (_server, client) = client_responding(ResponseTemplate::new(429)).await;
+
+        let error = client.get_market_state(false).await.unwrap_err();
+
+        assert_eq!(error.state, ResponseErrorState::TooManyRequests);
+        assert!(error.is_retryable());
+
+        let (_server, client) = client_responding(ResponseTemplate::new(503).set_body_string(
+            r#"{"error":{"name":"validation_error","message":"잘못된 API 요청입니다"}}"#,
+        ))
+        .await;
+
+        let error = client.get_market_state(false).await.unwrap_err();
+
+        assert!(error.state.is_validation_failure());
+        assert!(error.is_retryable());
     }
 }
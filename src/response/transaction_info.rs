@@ -1,3 +1,4 @@
+use crate::number::Number;
 use serde::Deserialize;
 
 use crate::{
@@ -19,9 +20,9 @@ pub struct TransactionInfoDerived {
     pub state: DepositState,
     pub created_at: chrono::NaiveDateTime,
     pub done_at: Option<chrono::NaiveDateTime>,
-    pub amount: f64,
-    pub fee: f64,
-    // pub krw_amount: f64,
+    pub amount: Number,
+    pub fee: Number,
+    // pub krw_amount: Number,
     pub transaction_type: TransactionType,
 }
 
@@ -45,7 +46,7 @@ pub struct TransactionInfoDerivedSource {
 impl TransactionInfoDerivedSource {
     /// Convert [String] type value into [TransactionType]
     pub fn r#type(&self) -> TransferType {
-        self.r#type.as_str().into()
+        TransferType::parse_or_unknown(&self.r#type)
     }
     /// Get uuid
     pub fn uuid(&self) -> String {
@@ -65,7 +66,7 @@ impl TransactionInfoDerivedSource {
     }
     /// Convert [String] state value into [DepositState]
     pub fn state(&self) -> DepositState {
-        self.state.as_str().into()
+        DepositState::parse_or_unknown(&self.state)
     }
     /// Convert [String] created_at value into [chrono::NaiveDateTime]
     pub fn created_at(&self) -> chrono::NaiveDateTime {
@@ -79,19 +80,19 @@ impl TransactionInfoDerivedSource {
         )
         .ok()
     }
-    /// Convert [String] amount value into [f64]
-    pub fn amount(&self) -> f64 {
-        self.amount.parse().unwrap()
+    /// Convert [String] amount value into [Number]
+    pub fn amount(&self) -> Number {
+        crate::number::from_wire(&self.amount).unwrap()
     }
-    /// Convert [String] fee value into [f64]
-    pub fn fee(&self) -> f64 {
-        self.fee.parse().unwrap()
+    /// Convert [String] fee value into [Number]
+    pub fn fee(&self) -> Number {
+        crate::number::from_wire(&self.fee).unwrap()
     }
-    /// Convert [String] krw_amount value into [f64]
-    // pub fn krw_amount(&self) -> f64 { self.krw_amount.parse().unwrap() }
+    /// Convert [String] krw_amount value into [Number]
+    // pub fn krw_amount(&self) -> Number { self.krw_amount.parse().unwrap() }
     /// Convert [String] transaction_type value into [WithdrawType]
     pub fn transaction_type(&self) -> TransactionType {
-        self.transaction_type.as_str().into()
+        TransactionType::parse_or_unknown(&self.transaction_type)
     }
 }
 
@@ -106,8 +107,8 @@ pub struct TransactionInfo {
     pub state: DepositState,
     pub created_at: chrono::NaiveDateTime,
     pub done_at: Option<chrono::NaiveDateTime>,
-    pub amount: f64,
-    pub fee: f64,
+    pub amount: Number,
+    pub fee: Number,
     pub transaction_type: TransactionType,
 
     pub holder: Option<String>,
@@ -158,7 +159,7 @@ pub struct TransactionInfoSource {
 impl TransactionInfoSource {
     /// Convert [String] type value into [TransactionType]
     pub fn r#type(&self) -> TransferType {
-        self.r#type.as_str().into()
+        TransferType::parse_or_unknown(&self.r#type)
     }
     /// Get uuid
     pub fn uuid(&self) -> String {
@@ -178,7 +179,7 @@ impl TransactionInfoSource {
     }
     /// Convert [String] state value into [DepositState]
     pub fn state(&self) -> DepositState {
-        self.state.as_str().into()
+        DepositState::parse_or_unknown(&self.state)
     }
     /// Convert [String] created_at value into [chrono::NaiveDateTime]
     pub fn created_at(&self) -> chrono::NaiveDateTime {
@@ -192,17 +193,17 @@ impl TransactionInfoSource {
         )
         .ok()
     }
-    /// Convert [String] amount value into [f64]
-    pub fn amount(&self) -> f64 {
-        self.amount.parse().unwrap()
+    /// Convert [String] amount value into [Number]
+    pub fn amount(&self) -> Number {
+        crate::number::from_wire(&self.amount).unwrap()
     }
-    /// Convert [String] fee value into [f64]
-    pub fn fee(&self) -> f64 {
-        self.fee.parse().unwrap()
+    /// Convert [String] fee value into [Number]
+    pub fn fee(&self) -> Number {
+        crate::number::from_wire(&self.fee).unwrap()
     }
     /// Convert [String] transaction_type value into [WithdrawType]
     pub fn transaction_type(&self) -> TransactionType {
-        self.transaction_type.as_str().into()
+        TransactionType::parse_or_unknown(&self.transaction_type)
     }
     pub fn holder(&self) -> Option<String> {
         self.holder.clone()
@@ -260,7 +261,7 @@ pub struct MemberLevel {
 #[derive(Debug)]
 pub struct WithdrawCurrency {
     pub code: String,
-    pub withdraw_fee: f64,
+    pub withdraw_fee: Number,
     pub is_coin: bool,
     pub wallet_state: String,
     pub wallet_support: Vec<String>,
@@ -280,8 +281,8 @@ impl WithdrawCurrencySource {
     pub fn code(&self) -> String {
         self.code.clone()
     }
-    pub fn withdraw_fee(&self) -> f64 {
-        self.withdraw_fee.parse().unwrap()
+    pub fn withdraw_fee(&self) -> Number {
+        crate::number::from_wire(&self.withdraw_fee).unwrap()
     }
     pub fn is_coin(&self) -> bool {
         self.is_coin
@@ -298,16 +299,16 @@ impl WithdrawCurrencySource {
 #[derive(Debug)]
 pub struct WithdrawLimit {
     pub currency: String,
-    pub minimum: Option<f64>,
+    pub minimum: Option<Number>,
     #[deprecated(since = "1.7.3", note = "Use remaining_daily_fiat instead")]
-    pub onetime: Option<f64>,
+    pub onetime: Option<Number>,
     #[deprecated(since = "1.7.3", note = "Use remaining_daily_fiat instead")]
-    pub daily: Option<f64>,
+    pub daily: Option<Number>,
     #[deprecated(since = "1.7.3", note = "Use remaining_daily_fiat instead")]
-    pub remaining_daily: f64,
+    pub remaining_daily: Number,
     #[deprecated(since = "1.7.3", note = "Use remaining_daily_fiat instead")]
-    pub remaining_daily_krw: f64,
-    pub remaining_daily_fiat: f64,
+    pub remaining_daily_krw: Number,
+    pub remaining_daily_fiat: Number,
     pub fixed: Option<i32>,
     pub can_withdraw: bool,
 }
@@ -334,31 +335,37 @@ impl WithdrawLimitSource {
     pub fn currency(&self) -> String {
         self.currency.clone()
     }
-    pub fn minimum(&self) -> Option<f64> {
-        self.minimum.clone().map(|x| x.parse::<f64>().unwrap())
+    pub fn minimum(&self) -> Option<Number> {
+        self.minimum
+            .as_deref()
+            .map(|x| crate::number::from_wire(x).unwrap())
     }
     #[allow(deprecated)]
     #[deprecated(since = "1.7.3", note = "Use remaining_daily_fiat instead")]
-    pub fn onetime(&self) -> Option<f64> {
-        self.onetime.clone().map(|x| x.parse::<f64>().unwrap())
+    pub fn onetime(&self) -> Option<Number> {
+        self.onetime
+            .as_deref()
+            .map(|x| crate::number::from_wire(x).unwrap())
     }
     #[allow(deprecated)]
     #[deprecated(since = "1.7.3", note = "Use remaining_daily_fiat instead")]
-    pub fn daily(&self) -> Option<f64> {
-        self.daily.as_ref().and_then(|x| x.parse::<f64>().ok())
+    pub fn daily(&self) -> Option<Number> {
+        self.daily
+            .as_ref()
+            .and_then(|x| crate::number::from_wire(x).ok())
     }
     #[allow(deprecated)]
     #[deprecated(since = "1.7.3", note = "Use remaining_daily_fiat instead")]
-    pub fn remaining_daily(&self) -> f64 {
-        self.remaining_daily.parse().unwrap()
+    pub fn remaining_daily(&self) -> Number {
+        crate::number::from_wire(&self.remaining_daily).unwrap()
     }
     #[allow(deprecated)]
     #[deprecated(since = "1.7.3", note = "Use remaining_daily_fiat instead")]
-    pub fn remaining_daily_krw(&self) -> f64 {
-        self.remaining_daily_krw.parse().unwrap()
+    pub fn remaining_daily_krw(&self) -> Number {
+        crate::number::from_wire(&self.remaining_daily_krw).unwrap()
     }
-    pub fn remaining_daily_fiat(&self) -> f64 {
-        self.remaining_daily_fiat.parse().unwrap()
+    pub fn remaining_daily_fiat(&self) -> Number {
+        crate::number::from_wire(&self.remaining_daily_fiat).unwrap()
     }
     pub fn fixed(&self) -> Option<i32> {
         self.fixed
@@ -0,0 +1,206 @@
+use std::error::Error;
+use std::io::ErrorKind;
+use std::time::Duration;
+
+use reqwest::{Method, StatusCode};
+
+/// Policy for resending failed requests
+///
+/// A request is retried on `429 Too Many Requests`, `5xx` responses and connection failures
+/// (refused, reset, timed out), waiting an exponentially growing delay with full jitter between
+/// attempts.
+///
+/// Only idempotent `GET` requests are retried by default. Order placement, cancellation and
+/// withdrawals could be submitted twice when a response is lost, so they are retried only when
+/// [`RetryPolicy::with_non_idempotent`] is enabled.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+///
+/// use upbit::{RetryPolicy, UpbitClient};
+///
+/// let policy = RetryPolicy::default()
+///     .with_max_attempts(5)
+///     .with_base_delay(Duration::from_millis(200));
+///
+/// let client = UpbitClient::new("access_key", "secret_key").with_retry_policy(policy);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// maximum number of attempts including the first one. 1 disables retrying
+    pub max_attempts: u32,
+    /// delay before the second attempt. doubled on each following attempt
+    pub base_delay: Duration,
+    /// upper bound of the delay between attempts
+    pub max_delay: Duration,
+    /// randomize each delay between zero and the computed backoff
+    pub jitter: bool,
+    /// also retry requests which are not idempotent, such as placing orders or withdrawals
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
+            jitter: true,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy sending every request exactly once
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Opt in to retrying order placement, cancellation and withdrawals
+    pub fn with_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    /// Whether a request with `method` may be sent more than once
+    pub fn allows(&self, method: &Method) -> bool {
+        self.max_attempts > 1
+            && (self.retry_non_idempotent || matches!(*method, Method::GET | Method::HEAD))
+    }
+
+    /// Whether a response with `status` is worth retrying
+    pub fn is_retryable_status(status: StatusCode) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+    }
+
+    /// Whether a transport error is worth retrying
+    ///
+    /// Connection failures, timeouts and connections reset by the peer are retried. Other
+    /// errors such as building a request or decoding a body fail the same way every time.
+    pub fn is_retryable_error(error: &reqwest::Error) -> bool {
+        error.is_connect() || error.is_timeout() || is_connection_reset(error)
+    }
+
+    /// Get delay to wait after the failed attempt number `attempt`, starting from 1
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+
+        if self.jitter {
+            backoff.mul_f64(rand::random::<f64>())
+        } else {
+            backoff
+        }
+    }
+}
+
+/// Whether any source of `error` is an I/O error of a connection closed by the peer
+fn is_connection_reset(error: &reqwest::Error) -> bool {
+    let mut source = error.source();
+
+    while let Some(error) = source {
+        if let Some(error) = error.downcast_ref::<std::io::Error>() {
+            if matches!(
+                error.kind(),
+                ErrorKind::ConnectionReset | ErrorKind::ConnectionAborted | ErrorKind::BrokenPipe
+            ) {
+                return true;
+            }
+        }
+        source = error.source();
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay() {
+        let policy = RetryPolicy::default()
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(350))
+            .with_jitter(false);
+
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(200));
+        assert_eq!(policy.delay(3), Duration::from_millis(350));
+        assert_eq!(policy.delay(40), Duration::from_millis(350));
+
+        let policy = policy.with_jitter(true);
+        for attempt in 1..10 {
+            assert!(policy.delay(attempt) <= Duration::from_millis(350));
+        }
+
+        // jittered delays of the same attempt are spread instead of repeating one value
+        let delays = (0..20)
+            .map(|_| policy.delay(3))
+            .collect::<std::collections::HashSet<_>>();
+        assert!(delays.len() > 1);
+    }
+
+    #[tokio::test]
+    async fn test_is_retryable_error() {
+        let client = reqwest::Client::new();
+
+        let refused = client.get("http://127.0.0.1:1").send().await.unwrap_err();
+        assert!(RetryPolicy::is_retryable_error(&refused));
+
+        let invalid = client.get("http://").send().await.unwrap_err();
+        assert!(!RetryPolicy::is_retryable_error(&invalid));
+    }
+
+    #[test]
+    fn test_allows() {
+        let policy = RetryPolicy::default();
+
+        assert!(policy.allows(&Method::GET));
+        assert!(!policy.allows(&Method::POST));
+        assert!(!policy.allows(&Method::DELETE));
+        assert!(policy
+            .clone()
+            .with_non_idempotent(true)
+            .allows(&Method::POST));
+        assert!(!RetryPolicy::none().allows(&Method::GET));
+    }
+
+    #[test]
+    fn test_is_retryable_status() {
+        assert!(RetryPolicy::is_retryable_status(
+            StatusCode::TOO_MANY_REQUESTS
+        ));
+        assert!(RetryPolicy::is_retryable_status(StatusCode::BAD_GATEWAY));
+        assert!(!RetryPolicy::is_retryable_status(StatusCode::BAD_REQUEST));
+        assert!(!RetryPolicy::is_retryable_status(StatusCode::UNAUTHORIZED));
+    }
+}
@@ -0,0 +1,98 @@
+/// Implement `sqlx::Type`, `Encode` and `Decode` for an enum stored as its wire text
+///
+/// The enum is encoded with `Display` and decoded with `parse_or_unknown`, so values unknown
+/// to this version of the crate survive a round trip. It is bound to the same database type
+/// `derive(sqlx::Type)` with `type_name` gave it (ex. a postgres enum `order_side`), and text
+/// columns are accepted as well.
+macro_rules! impl_sqlx_type {
+    ($t:ty, $type_name:literal) => {
+        #[cfg(feature = "sqlx-type")]
+        const _: () = {
+            /// Carries the database type of `$t`, which can't derive it for its `Unknown` variant
+            #[derive(sqlx::Type)]
+            #[sqlx(type_name = $type_name)]
+            #[allow(dead_code)]
+            enum SqlType {
+                Unknown,
+            }
+
+            impl<DB: sqlx::Database> sqlx::Type<DB> for $t
+            where
+                SqlType: sqlx::Type<DB>,
+                String: sqlx::Type<DB>,
+            {
+                fn type_info() -> DB::TypeInfo {
+                    <SqlType as sqlx::Type<DB>>::type_info()
+                }
+
+                fn compatible(ty: &DB::TypeInfo) -> bool {
+                    <SqlType as sqlx::Type<DB>>::compatible(ty)
+                        || <String as sqlx::Type<DB>>::compatible(ty)
+                }
+            }
+        };
+
+        #[cfg(feature = "sqlx-type")]
+        impl<'q, DB: sqlx::Database> sqlx::Encode<'q, DB> for $t
+        where
+            String: sqlx::Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as sqlx::Database>::ArgumentBuffer<'q>,
+            ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+                <String as sqlx::Encode<'q, DB>>::encode(self.to_string(), buf)
+            }
+        }
+
+        #[cfg(feature = "sqlx-type")]
+        impl<'r, DB: sqlx::Database> sqlx::Decode<'r, DB> for $t
+        where
+            &'r str: sqlx::Decode<'r, DB>,
+        {
+            fn decode(
+                value: <DB as sqlx::Database>::ValueRef<'r>,
+            ) -> Result<Self, sqlx::error::BoxDynError> {
+                let value = <&'r str as sqlx::Decode<'r, DB>>::decode(value)?;
+
+                Ok(Self::parse_or_unknown(value))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_sqlx_type;
+
+#[cfg(all(test, feature = "sqlx-type"))]
+mod tests {
+    use sqlx::postgres::{PgTypeInfo, Postgres};
+    use sqlx::{Type, TypeInfo};
+
+    use crate::api_exchange::{OrderCondition, OrderSide, OrderType};
+
+    #[test]
+    fn test_sqlx_type_name() {
+        assert_eq!(
+            <OrderSide as Type<Postgres>>::type_info().name(),
+            "order_side"
+        );
+        assert_eq!(
+            <OrderType as Type<Postgres>>::type_info().name(),
+            "order_type"
+        );
+        assert_eq!(
+            <OrderCondition as Type<Postgres>>::type_info().name(),
+            "order_condition"
+        );
+
+        assert!(<OrderSide as Type<Postgres>>::compatible(
+            &PgTypeInfo::with_name("order_side")
+        ));
+        assert!(<OrderSide as Type<Postgres>>::compatible(
+            &<String as Type<Postgres>>::type_info()
+        ));
+        assert!(!<OrderSide as Type<Postgres>>::compatible(
+            &PgTypeInfo::with_name("order_type")
+        ));
+    }
+}
@@ -0,0 +1,390 @@
+use std::fmt::Display;
+
+use crate::number::{self, Number};
+use crate::response::OrderChance;
+
+/// Decimal as (mantissa, scale), meaning `mantissa * 10^-scale`
+type Unit = (u32, u32);
+
+/// Tick size table of KRW markets as (lower bound of price, tick size)
+const KRW_TICK_SIZES: [(Unit, Unit); 13] = [
+    ((2_000_000, 0), (1000, 0)),
+    ((1_000_000, 0), (500, 0)),
+    ((500_000, 0), (100, 0)),
+    ((100_000, 0), (50, 0)),
+    ((10_000, 0), (10, 0)),
+    ((1000, 0), (1, 0)),
+    ((100, 0), (1, 1)),
+    ((10, 0), (1, 2)),
+    ((1, 0), (1, 3)),
+    ((1, 1), (1, 4)),
+    ((1, 2), (1, 5)),
+    ((1, 3), (1, 6)),
+    ((1, 4), (1, 7)),
+];
+
+/// Tick size table of USDT markets
+const USDT_TICK_SIZES: [(Unit, Unit); 6] = [
+    ((10, 0), (1, 2)),
+    ((1, 0), (1, 3)),
+    ((1, 1), (1, 4)),
+    ((1, 2), (1, 5)),
+    ((1, 3), (1, 6)),
+    ((1, 4), (1, 7)),
+];
+
+/// Smallest tick size of every market. BTC markets use it regardless of the price
+const MIN_TICK_SIZE: Unit = (1, 8);
+
+/// Direction to move a price onto a valid tick
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// largest valid price not above the price
+    Down,
+    /// smallest valid price not below the price
+    Up,
+    /// closest valid price. ties go up
+    Nearest,
+}
+
+/// Reason a price can't be ordered
+#[derive(Debug, Clone, PartialEq)]
+pub enum TickSizeError {
+    /// price is zero or negative
+    NotPositive(Number),
+    /// price is not a multiple of the tick size
+    OffTick {
+        price: Number,
+        tick_size: Number,
+        /// closest valid price below
+        lower: Number,
+        /// closest valid price above
+        upper: Number,
+    },
+    /// quote currency of the market has no known tick table and no price unit was given
+    UnknownMarket(String),
+    /// `price_unit` is not a plain positive decimal
+    InvalidPriceUnit(String),
+}
+
+impl Display for TickSizeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotPositive(price) => write!(f, "price must be positive: {price}"),
+            Self::OffTick {
+                price,
+                tick_size,
+                lower,
+                upper,
+            } => write!(
+                f,
+                "price {price} is not a multiple of tick size {tick_size}. nearest valid prices are {lower} and {upper}"
+            ),
+            Self::UnknownMarket(market_id) => write!(f, "unknown tick size table of market: {market_id}"),
+            Self::InvalidPriceUnit(price_unit) => write!(f, "invalid price unit: {price_unit}"),
+        }
+    }
+}
+
+impl std::error::Error for TickSizeError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TickTable {
+    Krw,
+    Usdt,
+    Btc,
+    Fixed(Unit),
+    Unknown(String),
+}
+
+/// Tick size rules of a market
+///
+/// The tick size depends on the quote currency of the market (KRW, BTC, USDT) and on the price.
+/// Some KRW coins are traded with a different unit; pass the `price_unit` of [`OrderChance`] for
+/// them, which takes precedence over the table.
+///
+/// # Example
+/// ```
+/// use upbit::{Number, TickSize};
+///
+/// let tick_size = TickSize::for_market("KRW-ETH");
+///
+/// assert_eq!(tick_size.round_down(Number::from(1_435_085)), Ok(Number::from(1_435_000)));
+/// assert_eq!(tick_size.round_up(Number::from(1_435_085)), Ok(Number::from(1_435_500)));
+/// assert!(tick_size.validate(Number::from(1_435_085)).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TickSize {
+    table: TickTable,
+}
+
+impl TickSize {
+    /// Tick size rules of `market_id` such as `KRW-BTC`, `BTC-ETH` or `USDT-BTC`
+    pub fn for_market(market_id: &str) -> Self {
+        let quote = market_id.split('-').next().unwrap_or_default();
+        let table = match quote {
+            "KRW" => TickTable::Krw,
+            "USDT" => TickTable::Usdt,
+            "BTC" => TickTable::Btc,
+            _ => TickTable::Unknown(market_id.to_owned()),
+        };
+
+        Self { table }
+    }
+
+    /// Tick size rules from the market and `price_unit` of an order chance
+    pub fn from_order_chance(order_chance: &OrderChance) -> Result<Self, TickSizeError> {
+        let tick_size = Self::for_market(&order_chance.market.id);
+
+        match &order_chance.market.bid.price_unit {
+            Some(price_unit) => tick_size.with_price_unit(price_unit),
+            None => Ok(tick_size),
+        }
+    }
+
+    /// Use a fixed `price_unit` (ex. `"0.1"`) instead of the table of the market
+    pub fn with_price_unit(mut self, price_unit: &str) -> Result<Self, TickSizeError> {
+        self.table = TickTable::Fixed(parse_unit(price_unit)?);
+        Ok(self)
+    }
+
+    /// Get tick size at `price`
+    pub fn tick_size(&self, price: Number) -> Result<Number, TickSizeError> {
+        self.unit(price)
+            .map(|(mantissa, scale)| number::from_parts(mantissa, scale))
+    }
+
+    /// Move `price` onto a valid tick in direction of `rounding`
+    pub fn round(&self, price: Number, rounding: Rounding) -> Result<Number, TickSizeError> {
+        if price <= Number::from(0u8) {
+            return Err(TickSizeError::NotPositive(price));
+        }
+
+        let unit = self.unit(price)?;
+        let (lower, upper) = neighbours(price, unit);
+        let rounded = match rounding {
+            Rounding::Down => lower,
+            Rounding::Up => upper,
+            Rounding::Nearest if upper - price <= price - lower => upper,
+            Rounding::Nearest => lower,
+        };
+
+        // rounding up can cross into a band with a coarser tick, such as 999_999 -> 1_000_000
+        match self.unit(rounded)? {
+            next_unit if next_unit == unit => Ok(rounded),
+            next_unit => Ok(neighbours(rounded, next_unit).1),
+        }
+    }
+
+    /// Largest valid price not above `price`
+    pub fn round_down(&self, price: Number) -> Result<Number, TickSizeError> {
+        self.round(price, Rounding::Down)
+    }
+
+    /// Smallest valid price not below `price`
+    pub fn round_up(&self, price: Number) -> Result<Number, TickSizeError> {
+        self.round(price, Rounding::Up)
+    }
+
+    /// Closest valid price to `price`
+    pub fn round_nearest(&self, price: Number) -> Result<Number, TickSizeError> {
+        self.round(price, Rounding::Nearest)
+    }
+
+    /// Check `price` can be ordered as is, telling why not otherwise
+    pub fn validate(&self, price: Number) -> Result<(), TickSizeError> {
+        if price <= Number::from(0u8) {
+            return Err(TickSizeError::NotPositive(price));
+        }
+
+        let unit = self.unit(price)?;
+        let (lower, upper) = neighbours(price, unit);
+
+        // with f64 a price a hair off the tick snaps onto it, but the price as given is sent
+        if lower == upper && lower == price {
+            Ok(())
+        } else {
+            Err(TickSizeError::OffTick {
+                price,
+                tick_size: number::from_parts(unit.0, unit.1),
+                lower: self.round_down(price)?,
+                upper: self.round_up(price)?,
+            })
+        }
+    }
+
+    fn unit(&self, price: Number) -> Result<Unit, TickSizeError> {
+        let table: &[(Unit, Unit)] = match &self.table {
+            TickTable::Krw => &KRW_TICK_SIZES,
+            TickTable::Usdt => &USDT_TICK_SIZES,
+            TickTable::Btc => &[],
+            TickTable::Fixed(unit) => return Ok(*unit),
+            TickTable::Unknown(market_id) => {
+                return Err(TickSizeError::UnknownMarket(market_id.to_owned()))
+            }
+        };
+
+        Ok(table
+            .iter()
+            .find(|((mantissa, scale), _)| price >= number::from_parts(*mantissa, *scale))
+            .map(|(_, unit)| *unit)
+            .unwrap_or(MIN_TICK_SIZE))
+    }
+}
+
+/// Get the valid prices just below and above `price`. equal when `price` is on a tick
+fn neighbours(price: Number, (mantissa, scale): Unit) -> (Number, Number) {
+    let (lower, upper) = step_bounds(price / number::from_parts(mantissa, scale));
+
+    // multiply by the mantissa and divide by a power of ten, which keeps f64 closest to the decimal
+    let to_price = |steps: Number| steps * Number::from(mantissa) / Number::from(10u32.pow(scale));
+
+    (to_price(lower), to_price(upper))
+}
+
+/// Get the whole numbers of ticks just below and above `steps`
+///
+/// The representation error of f64 is absorbed, ex. 123.4 / 0.1 = 1233.9999999999998.
+#[cfg(not(feature = "rust_decimal"))]
+fn step_bounds(steps: Number) -> (Number, Number) {
+    let nearest = steps.round();
+
+    if (steps - nearest).abs() <= number::from_parts(1, 6) {
+        (nearest, nearest)
+    } else {
+        (steps.floor(), steps.ceil())
+    }
+}
+
+/// Get the whole numbers of ticks just below and above `steps`
+#[cfg(feature = "rust_decimal")]
+fn step_bounds(steps: Number) -> (Number, Number) {
+    (steps.floor(), steps.ceil())
+}
+
+fn parse_unit(price_unit: &str) -> Result<Unit, TickSizeError> {
+    let invalid = || TickSizeError::InvalidPriceUnit(price_unit.to_owned());
+    let (integer, fraction) = price_unit
+        .trim()
+        .split_once('.')
+        .unwrap_or((price_unit.trim(), ""));
+    let fraction = fraction.trim_end_matches('0');
+
+    if integer.is_empty() && fraction.is_empty()
+        || !integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    let mantissa = format!("{integer}{fraction}")
+        .parse::<u32>()
+        .map_err(|_| invalid())?;
+    let scale = fraction.len() as u32;
+
+    if mantissa == 0 || scale > 9 {
+        return Err(invalid());
+    }
+
+    Ok((mantissa, scale))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn n(value: &str) -> Number {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_krw_rounding() {
+        let tick_size = TickSize::for_market("KRW-ETH");
+
+        assert_eq!(tick_size.round_down(n("1435085")), Ok(n("1435000")));
+        assert_eq!(tick_size.round_up(n("1435085")), Ok(n("1435500")));
+        assert_eq!(tick_size.round_nearest(n("1435085")), Ok(n("1435000")));
+        assert_eq!(tick_size.round_nearest(n("1435250")), Ok(n("1435500")));
+        assert_eq!(tick_size.round_down(n("123.45")), Ok(n("123.4")));
+        assert_eq!(tick_size.round_up(n("123.41")), Ok(n("123.5")));
+        assert_eq!(tick_size.round_up(n("999999")), Ok(n("1000000")));
+        assert_eq!(tick_size.round_up(n("1999999")), Ok(n("2000000")));
+    }
+
+    #[test]
+    fn test_other_markets() {
+        assert_eq!(
+            TickSize::for_market("BTC-ETH").round_down(n("0.052312345")),
+            Ok(n("0.05231234"))
+        );
+        assert_eq!(
+            TickSize::for_market("USDT-BTC").round_down(n("64123.456")),
+            Ok(n("64123.45"))
+        );
+        assert_eq!(
+            TickSize::for_market("ETH-XYZ").round_down(n("1")),
+            Err(TickSizeError::UnknownMarket("ETH-XYZ".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_price_unit() {
+        let tick_size = TickSize::for_market("KRW-XYZ")
+            .with_price_unit("1")
+            .unwrap();
+
+        assert_eq!(tick_size.round_down(n("123.45")), Ok(n("123")));
+        assert!(TickSize::for_market("KRW-XYZ")
+            .with_price_unit("0")
+            .is_err());
+        assert!(TickSize::for_market("KRW-XYZ")
+            .with_price_unit("abc")
+            .is_err());
+        assert_eq!(parse_unit("0.050"), Ok((5, 2)));
+    }
+
+    #[test]
+    fn test_validate() {
+        let tick_size = TickSize::for_market("KRW-ETH");
+
+        assert_eq!(tick_size.validate(n("1435000")), Ok(()));
+        assert_eq!(tick_size.validate(n("123.4")), Ok(()));
+        assert_eq!(
+            tick_size.validate(n("1435085")),
+            Err(TickSizeError::OffTick {
+                price: n("1435085"),
+                tick_size: n("500"),
+                lower: n("1435000"),
+                upper: n("1435500"),
+            })
+        );
+        assert_eq!(
+            tick_size.validate(n("0")),
+            Err(TickSizeError::NotPositive(n("0")))
+        );
+    }
+
+    #[test]
+    fn test_validate_price_close_to_tick() {
+        let tick_size = TickSize::for_market("KRW-ETH");
+        let error = tick_size.validate(n("1000.0000005"));
+
+        // f64 snaps to the tick the price is a hair off, decimals are exact
+        #[cfg(not(feature = "rust_decimal"))]
+        let (lower, upper) = (n("1000"), n("1000"));
+        #[cfg(feature = "rust_decimal")]
+        let (lower, upper) = (n("1000"), n("1001"));
+
+        assert_eq!(
+            error,
+            Err(TickSizeError::OffTick {
+                price: n("1000.0000005"),
+                tick_size: n("1"),
+                lower,
+                upper,
+            })
+        );
+    }
+}
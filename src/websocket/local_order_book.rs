@@ -0,0 +1,395 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::api_exchange::OrderSide;
+use crate::api_quotation::OrderBookInfo;
+use crate::number::{self, Number};
+
+use super::WebSocketMessage;
+
+/// Price and total size of a level of an order book
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceLevel {
+    pub price: Number,
+    pub size: Number,
+}
+
+/// Order book of a market, with both sides sorted from the best price
+///
+/// `side` parameters name the side of the book to read: [`OrderSide::Ask`] for the asks, which a
+/// buyer takes, and [`OrderSide::Bid`] for the bids, which a seller takes.
+#[derive(Debug, Clone)]
+pub struct OrderBook {
+    pub market: String,
+    /// server time of the snapshot in milliseconds
+    pub timestamp: i64,
+    /// when the snapshot was received
+    pub received_at: Instant,
+    /// price unit the levels are aggregated by. 0 when not aggregated
+    pub level: Number,
+    /// ascending by price
+    pub asks: Vec<PriceLevel>,
+    /// descending by price
+    pub bids: Vec<PriceLevel>,
+}
+
+impl From<OrderBookInfo> for OrderBook {
+    fn from(orderbook: OrderBookInfo) -> Self {
+        let (mut asks, mut bids): (Vec<_>, Vec<_>) = orderbook
+            .orderbook_units
+            .into_iter()
+            .map(|unit| {
+                (
+                    PriceLevel {
+                        price: unit.ask_price,
+                        size: unit.ask_size,
+                    },
+                    PriceLevel {
+                        price: unit.bid_price,
+                        size: unit.bid_size,
+                    },
+                )
+            })
+            .unzip();
+
+        asks.retain(|x| x.size > Number::default());
+        bids.retain(|x| x.size > Number::default());
+        asks.sort_by(|a, b| compare(a.price, b.price));
+        bids.sort_by(|a, b| compare(b.price, a.price));
+
+        Self {
+            market: orderbook.market,
+            timestamp: orderbook.timestamp,
+            received_at: Instant::now(),
+            level: orderbook.level,
+            asks,
+            bids,
+        }
+    }
+}
+
+impl OrderBook {
+    /// Get levels of `side`, best price first
+    pub fn levels(&self, side: &OrderSide) -> &[PriceLevel] {
+        match side {
+            OrderSide::Ask => &self.asks,
+            OrderSide::Bid => &self.bids,
+            OrderSide::Unknown(_) => &[],
+        }
+    }
+
+    /// Get the lowest ask
+    pub fn best_ask(&self) -> Option<&PriceLevel> {
+        self.asks.first()
+    }
+
+    /// Get the highest bid
+    pub fn best_bid(&self) -> Option<&PriceLevel> {
+        self.bids.first()
+    }
+
+    /// Get best ask minus best bid
+    pub fn spread(&self) -> Option<Number> {
+        Some(self.best_ask()?.price - self.best_bid()?.price)
+    }
+
+    /// Get the average of best ask and best bid
+    pub fn mid_price(&self) -> Option<Number> {
+        Some((self.best_ask()?.price + self.best_bid()?.price) / Number::from(2u8))
+    }
+
+    /// Get the best `levels` levels of `side`, or fewer if the book is shallower
+    pub fn depth(&self, side: &OrderSide, levels: usize) -> &[PriceLevel] {
+        let x = self.levels(side);
+
+        &x[..levels.min(x.len())]
+    }
+
+    /// Get total size of `side` from the best price up to and including `price`
+    pub fn cumulative_size(&self, side: &OrderSide, price: Number) -> Number {
+        self.levels(side)
+            .iter()
+            .take_while(|x| match side {
+                OrderSide::Ask => x.price <= price,
+                _ => x.price >= price,
+            })
+            .fold(Number::default(), |acc, x| acc + x.size)
+    }
+
+    /// Get average price of filling `volume` against `side`, walking from the best price
+    ///
+    /// `None` when `volume` is not positive or exceeds the size of the book.
+    pub fn vwap(&self, side: &OrderSide, volume: Number) -> Option<Number> {
+        if volume <= Number::default() {
+            return None;
+        }
+
+        let mut remaining = volume;
+        let mut funds = Number::default();
+
+        for x in self.levels(side) {
+            let size = if x.size < remaining {
+                x.size
+            } else {
+                remaining
+            };
+
+            funds += x.price * size;
+            remaining -= size;
+
+            if remaining <= Number::default() {
+                return Some(funds / volume);
+            }
+        }
+
+        None
+    }
+
+    /// Get the book with prices grouped by the price unit `level`
+    ///
+    /// Asks are grouped up and bids down to a multiple of `level`, so a group never looks better
+    /// than its orders. A `level` not positive returns the book as is.
+    pub fn aggregate(&self, level: Number) -> Self {
+        if level <= Number::default() {
+            return self.clone();
+        }
+
+        Self {
+            level,
+            asks: group(&self.asks, level, true),
+            bids: group(&self.bids, level, false),
+            ..self.clone()
+        }
+    }
+
+    /// Get time since the snapshot was received
+    pub fn age(&self) -> Duration {
+        self.received_at.elapsed()
+    }
+
+    /// Whether the snapshot was received more than `max_age` ago
+    pub fn is_stale(&self, max_age: Duration) -> bool {
+        self.age() > max_age
+    }
+}
+
+/// Latest order books of several markets, kept from orderbook messages
+///
+/// Upbit sends the whole visible book in every orderbook message, so each message replaces the
+/// book of its market. A message older than the book held is ignored.
+///
+/// # Example
+/// ```no_run
+/// use futures_util::StreamExt;
+/// use upbit::api_exchange::OrderSide;
+/// use upbit::websocket::{self, LocalOrderBook, Subscription};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut books = LocalOrderBook::new();
+/// let mut socket = websocket::subscribe(&[Subscription::orderbook(&["KRW-BTC"])]).await?;
+///
+/// while let Some(Ok(message)) = socket.next().await {
+///     if let Some(book) = books.update(&message) {
+///         println!("{:?} {:?}", book.spread(), book.vwap(&OrderSide::Ask, "0.5".parse()?));
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LocalOrderBook {
+    books: HashMap<String, OrderBook>,
+}
+
+impl LocalOrderBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply an orderbook message, ignoring any other message
+    ///
+    /// Returns the book of the market of the message.
+    pub fn update(&mut self, message: &WebSocketMessage) -> Option<&OrderBook> {
+        match message {
+            WebSocketMessage::Orderbook { orderbook, .. } => Some(self.insert(orderbook.clone())),
+            _ => None,
+        }
+    }
+
+    /// Apply a snapshot such as the one of [`crate::api_quotation::get_order_book_info`]
+    ///
+    /// Returns the book of the market of the snapshot.
+    pub fn insert(&mut self, orderbook: OrderBookInfo) -> &OrderBook {
+        let book = OrderBook::from(orderbook);
+
+        let existing = self
+            .books
+            .entry(book.market.to_owned())
+            .or_insert_with(|| book.clone());
+
+        if existing.timestamp <= book.timestamp {
+            *existing = book;
+        }
+
+        existing
+    }
+
+    /// Get the book of `market_id`
+    pub fn get(&self, market_id: &str) -> Option<&OrderBook> {
+        self.books.get(market_id)
+    }
+
+    /// Stop keeping the book of `market_id`
+    pub fn remove(&mut self, market_id: &str) -> Option<OrderBook> {
+        self.books.remove(market_id)
+    }
+
+    /// Get markets with a book
+    pub fn markets(&self) -> impl Iterator<Item = &str> {
+        self.books.keys().map(String::as_str)
+    }
+
+    /// Get markets whose book was received more than `max_age` ago
+    pub fn stale_markets(&self, max_age: Duration) -> Vec<&str> {
+        self.books
+            .values()
+            .filter(|x| x.is_stale(max_age))
+            .map(|x| x.market.as_str())
+            .collect()
+    }
+}
+
+fn compare(a: Number, b: Number) -> Ordering {
+    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+}
+
+/// Merge `levels` into groups of `level`, keeping their order
+fn group(levels: &[PriceLevel], level: Number, is_up: bool) -> Vec<PriceLevel> {
+    let mut grouped: Vec<PriceLevel> = Vec::new();
+
+    for x in levels {
+        let steps = x.price / level;
+        let nearest = steps.round();
+        // absorb the representation error of f64, ex. 0.3 / 0.1 = 2.9999999999999996
+        let steps = if (steps - nearest).abs() <= number::from_parts(1, 6) {
+            nearest
+        } else if is_up {
+            steps.ceil()
+        } else {
+            steps.floor()
+        };
+        let price = steps * level;
+
+        match grouped.last_mut() {
+            Some(last) if last.price == price => last.size += x.size,
+            _ => grouped.push(PriceLevel {
+                price,
+                size: x.size,
+            }),
+        }
+    }
+
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn n(value: &str) -> Number {
+        value.parse().unwrap()
+    }
+
+    fn orderbook(timestamp: i64) -> OrderBookInfo {
+        serde_json::from_str(&format!(
+            r#"{{"market":"KRW-BTC","timestamp":{timestamp},"total_ask_size":1.6,"total_bid_size":1.7,"orderbook_units":[
+                {{"ask_price":100010000,"bid_price":100000000,"ask_size":0.5,"bid_size":0.2}},
+                {{"ask_price":100020000,"bid_price":99995000,"ask_size":0.3,"bid_size":1.0}},
+                {{"ask_price":100035000,"bid_price":99980000,"ask_size":0.8,"bid_size":0.5}}
+            ],"level":0}}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_order_book_queries() {
+        let book = OrderBook::from(orderbook(1676965262177));
+
+        assert_eq!(book.best_ask().unwrap().price, n("100010000"));
+        assert_eq!(book.best_bid().unwrap().price, n("100000000"));
+        assert_eq!(book.spread(), Some(n("10000")));
+        assert_eq!(book.mid_price(), Some(n("100005000")));
+        assert_eq!(book.depth(&OrderSide::Bid, 2).len(), 2);
+        assert_eq!(book.depth(&OrderSide::Bid, 10).len(), 3);
+        assert_eq!(
+            book.cumulative_size(&OrderSide::Ask, n("100020000")),
+            n("0.8")
+        );
+        assert_eq!(
+            book.cumulative_size(&OrderSide::Bid, n("99990000")),
+            n("1.2")
+        );
+        // 0.5 at 100,010,000, 0.3 at 100,020,000 and 0.2 at 100,035,000
+        assert_eq!(
+            book.vwap(&OrderSide::Ask, n("1")).unwrap().round(),
+            n("100018000")
+        );
+        assert_eq!(book.vwap(&OrderSide::Ask, n("2")), None);
+        assert_eq!(book.vwap(&OrderSide::Ask, n("0")), None);
+    }
+
+    #[test]
+    fn test_order_book_aggregate() {
+        let book = OrderBook::from(orderbook(1676965262177)).aggregate(n("50000"));
+
+        assert_eq!(book.level, n("50000"));
+        assert_eq!(
+            book.asks,
+            vec![PriceLevel {
+                price: n("100050000"),
+                size: n("1.6"),
+            }]
+        );
+        assert_eq!(
+            book.bids,
+            vec![
+                PriceLevel {
+                    price: n("100000000"),
+                    size: n("0.2"),
+                },
+                PriceLevel {
+                    price: n("99950000"),
+                    size: n("1.5"),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_local_order_book_update() {
+        let mut books = LocalOrderBook::new();
+        let message = WebSocketMessage::Orderbook {
+            orderbook: orderbook(1676965262177),
+            stream_type: super::super::StreamType::Realtime,
+        };
+
+        assert!(books.update(&WebSocketMessage::Reconnected).is_none());
+        assert_eq!(books.update(&message).unwrap().timestamp, 1676965262177);
+        // an older snapshot arriving late doesn't replace the book
+        assert_eq!(
+            books.insert(orderbook(1676965262000)).timestamp,
+            1676965262177
+        );
+        assert_eq!(books.markets().collect::<Vec<_>>(), ["KRW-BTC"]);
+
+        tokio::time::advance(Duration::from_secs(5)).await;
+        assert_eq!(books.stale_markets(Duration::from_secs(3)), ["KRW-BTC"]);
+
+        books.insert(orderbook(1676965263000));
+        assert!(books.stale_markets(Duration::from_secs(3)).is_empty());
+        assert!(books.get("KRW-ETH").is_none());
+    }
+}
@@ -0,0 +1,219 @@
+use serde::Deserialize;
+
+use crate::api_quotation::order_book::OrderBookUnit;
+use crate::api_quotation::{OrderBookInfo, SnapshotChangeType, TickerSnapshot, TradeRecent};
+use crate::number::Number;
+use crate::response::{ResponseError, ResponseErrorSource};
+
+use super::my_event::{MyAsset, MyAssetSource, MyOrder, MyOrderSource};
+use super::StreamType;
+
+/// Message received from a WebSocket subscription
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+pub enum WebSocketMessage {
+    /// 현재가
+    Ticker {
+        ticker: TickerSnapshot,
+        stream_type: StreamType,
+    },
+    /// 체결
+    Trade {
+        trade: TradeRecent,
+        stream_type: StreamType,
+    },
+    /// 호가
+    Orderbook {
+        orderbook: OrderBookInfo,
+        stream_type: StreamType,
+    },
+    /// 내 주문 및 체결
+    MyOrder {
+        order: MyOrder,
+        stream_type: StreamType,
+    },
+    /// 내 자산
+    MyAsset {
+        asset: MyAsset,
+        stream_type: StreamType,
+    },
+    /// connection of a [`super::WebSocketSession`] was lost and established again.
+    /// messages sent in between are missed
+    Reconnected,
+    /// message unknown to this version of the crate, as received
+    Unknown(String),
+}
+
+impl WebSocketMessage {
+    /// Parse a text or binary frame sent by the server
+    pub fn parse(text: &str) -> Result<Self, ResponseError> {
+        let value: serde_json::Value =
+            serde_json::from_str(text).map_err(crate::response::response_error_from_json)?;
+
+        if value.get("error").is_some() {
+            let error: ResponseErrorSource =
+                serde_json::from_value(value).map_err(crate::response::response_error_from_json)?;
+
+            return Err(crate::response::response_error(error));
+        }
+
+        let message = match value.get("type").and_then(|x| x.as_str()) {
+            Some("ticker") => serde_json::from_value(value).map(|x: TickerSource| x.into()),
+            Some("trade") => serde_json::from_value(value).map(|x: TradeSource| x.into()),
+            Some("orderbook") => serde_json::from_value(value).map(|x: OrderbookSource| x.into()),
+            Some("myOrder") => serde_json::from_value(value).map(|x: MyOrderSource| {
+                let stream_type = StreamType::parse_or_unknown(&x.stream_type);
+
+                Self::MyOrder {
+                    order: x.into(),
+                    stream_type,
+                }
+            }),
+            Some("myAsset") => {
+                serde_json::from_value(value).map(|x: MyAssetSource| Self::MyAsset {
+                    asset: x.asset,
+                    stream_type: StreamType::parse_or_unknown(&x.stream_type),
+                })
+            }
+            _ => Ok(Self::Unknown(text.to_owned())),
+        };
+
+        message.map_err(crate::response::response_error_from_json)
+    }
+}
+
+/// Raw ticker message from serialized data
+#[derive(Deserialize)]
+pub struct TickerSource {
+    code: String,
+    opening_price: Number,
+    high_price: Number,
+    low_price: Number,
+    trade_price: Number,
+    prev_closing_price: Number,
+    change: String,
+    change_price: Number,
+    signed_change_price: Number,
+    change_rate: Number,
+    signed_change_rate: Number,
+    trade_volume: Number,
+    acc_trade_volume: Number,
+    acc_trade_volume_24h: Number,
+    acc_trade_price: Number,
+    acc_trade_price_24h: Number,
+    trade_date: String,
+    trade_time: String,
+    trade_timestamp: i64,
+    highest_52_week_price: Number,
+    highest_52_week_date: String,
+    lowest_52_week_price: Number,
+    lowest_52_week_date: String,
+    timestamp: i64,
+    stream_type: String,
+}
+
+impl From<TickerSource> for WebSocketMessage {
+    fn from(x: TickerSource) -> Self {
+        // the stream sends UTC only. KST is derived as in the REST ticker
+        let trade_time_kst = chrono::DateTime::from_timestamp_millis(x.trade_timestamp)
+            .unwrap_or_default()
+            .with_timezone(&chrono::FixedOffset::east_opt(9 * 3600).unwrap());
+
+        Self::Ticker {
+            ticker: TickerSnapshot {
+                market: x.code,
+                trade_date: x.trade_date,
+                trade_time: x.trade_time,
+                trade_date_kst: trade_time_kst.format("%Y%m%d").to_string(),
+                trade_time_kst: trade_time_kst.format("%H%M%S").to_string(),
+                trade_timestamp: x.trade_timestamp,
+                opening_price: x.opening_price,
+                high_price: x.high_price,
+                low_price: x.low_price,
+                trade_price: x.trade_price,
+                prev_closing_price: x.prev_closing_price,
+                change: SnapshotChangeType::parse_or_unknown(&x.change),
+                change_price: x.change_price,
+                change_rate: x.change_rate,
+                signed_change_price: x.signed_change_price,
+                signed_change_rate: x.signed_change_rate,
+                trade_volume: x.trade_volume,
+                acc_trade_price: x.acc_trade_price,
+                acc_trade_price_24h: x.acc_trade_price_24h,
+                acc_trade_volume: x.acc_trade_volume,
+                acc_trade_volume_24h: x.acc_trade_volume_24h,
+                highest_52_week_price: x.highest_52_week_price,
+                highest_52_week_date: x.highest_52_week_date,
+                lowest_52_week_price: x.lowest_52_week_price,
+                lowest_52_week_date: x.lowest_52_week_date,
+                timestamp: x.timestamp,
+            },
+            stream_type: StreamType::parse_or_unknown(&x.stream_type),
+        }
+    }
+}
+
+/// Raw trade message from serialized data
+#[derive(Deserialize)]
+pub struct TradeSource {
+    code: String,
+    trade_price: Number,
+    trade_volume: Number,
+    ask_bid: String,
+    prev_closing_price: Number,
+    change_price: Number,
+    trade_date: String,
+    trade_time: String,
+    trade_timestamp: i64,
+    sequential_id: i64,
+    stream_type: String,
+}
+
+impl From<TradeSource> for WebSocketMessage {
+    fn from(x: TradeSource) -> Self {
+        Self::Trade {
+            trade: TradeRecent {
+                market: x.code,
+                trade_date_utc: x.trade_date,
+                trade_time_utc: x.trade_time,
+                timestamp: x.trade_timestamp,
+                trade_price: x.trade_price,
+                trade_volume: x.trade_volume,
+                prev_closing_price: x.prev_closing_price,
+                change_price: x.change_price,
+                ask_bid: x.ask_bid,
+                sequential_id: x.sequential_id,
+            },
+            stream_type: StreamType::parse_or_unknown(&x.stream_type),
+        }
+    }
+}
+
+/// Raw orderbook message from serialized data
+#[derive(Deserialize)]
+pub struct OrderbookSource {
+    code: String,
+    timestamp: i64,
+    total_ask_size: Number,
+    total_bid_size: Number,
+    #[serde(default)]
+    level: Number,
+    orderbook_units: Vec<OrderBookUnit>,
+    stream_type: String,
+}
+
+impl From<OrderbookSource> for WebSocketMessage {
+    fn from(x: OrderbookSource) -> Self {
+        Self::Orderbook {
+            orderbook: OrderBookInfo {
+                market: x.code,
+                timestamp: x.timestamp,
+                total_ask_size: x.total_ask_size,
+                total_bid_size: x.total_bid_size,
+                level: x.level,
+                orderbook_units: x.orderbook_units,
+            },
+            stream_type: StreamType::parse_or_unknown(&x.stream_type),
+        }
+    }
+}
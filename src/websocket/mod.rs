@@ -0,0 +1,333 @@
+pub mod local_order_book;
+pub mod message;
+pub mod my_event;
+pub mod session;
+pub mod stream;
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub use local_order_book::{LocalOrderBook, OrderBook, PriceLevel};
+pub use message::WebSocketMessage;
+pub use my_event::{BalanceDelta, MyAsset, MyAssetBalance, MyOrder};
+pub use session::{SessionConfig, WebSocketSession};
+pub use stream::UpbitWebSocket;
+
+use crate::client::UpbitClient;
+use crate::constant::ParseEnumError;
+use crate::number::Number;
+use crate::response::ResponseError;
+
+/// Kind of data to subscribe
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SubscriptionType {
+    /// 현재가
+    Ticker,
+    /// 체결
+    Trade,
+    /// 호가
+    Orderbook,
+    /// 내 주문 및 체결. requires the keys of the client
+    MyOrder,
+    /// 내 자산. requires the keys of the client
+    MyAsset,
+}
+
+impl Display for SubscriptionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubscriptionType::Ticker => write!(f, "ticker"),
+            SubscriptionType::Trade => write!(f, "trade"),
+            SubscriptionType::Orderbook => write!(f, "orderbook"),
+            SubscriptionType::MyOrder => write!(f, "myOrder"),
+            SubscriptionType::MyAsset => write!(f, "myAsset"),
+        }
+    }
+}
+
+impl SubscriptionType {
+    /// Whether the data belongs to the account and needs an authenticated connection
+    pub fn is_private(&self) -> bool {
+        matches!(self, Self::MyOrder | Self::MyAsset)
+    }
+}
+
+/// Whether a message is a snapshot or a real-time update
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamType {
+    /// 스냅샷
+    Snapshot,
+    /// 실시간
+    Realtime,
+    /// value unknown to this version of the crate
+    Unknown(String),
+}
+
+impl Display for StreamType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamType::Snapshot => write!(f, "SNAPSHOT"),
+            StreamType::Realtime => write!(f, "REALTIME"),
+            StreamType::Unknown(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl FromStr for StreamType {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "SNAPSHOT" => Ok(Self::Snapshot),
+            "REALTIME" => Ok(Self::Realtime),
+            a => Err(ParseEnumError::new("StreamType", a)),
+        }
+    }
+}
+
+impl TryFrom<&str> for StreamType {
+    type Error = ParseEnumError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl StreamType {
+    /// Parse `value`, keeping values unknown to this version of the crate as [`StreamType::Unknown`]
+    pub fn parse_or_unknown(value: &str) -> Self {
+        value
+            .parse()
+            .unwrap_or_else(|_| Self::Unknown(value.to_owned()))
+    }
+}
+
+/// Data type and market codes to receive
+///
+/// # Example
+/// ```
+/// use upbit::websocket::Subscription;
+///
+/// let subscriptions = [
+///     Subscription::ticker(&["KRW-BTC", "KRW-ETH"]),
+///     Subscription::orderbook(&["KRW-BTC"]).with_only_realtime(true),
+/// ];
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Subscription {
+    /// kind of data
+    pub kind: SubscriptionType,
+    /// market codes. ex) KRW-BTC. empty for every market of [`SubscriptionType::MyOrder`]
+    pub codes: Vec<String>,
+    /// receive the snapshot only
+    pub is_only_snapshot: bool,
+    /// receive real-time updates only
+    pub is_only_realtime: bool,
+    /// price unit to aggregate orderbook units by. KRW markets only
+    pub level: Option<Number>,
+}
+
+impl Subscription {
+    /// Subscribe `kind` of `codes`
+    pub fn new(kind: SubscriptionType, codes: &[&str]) -> Self {
+        Self {
+            kind,
+            codes: codes.iter().map(|code| code.to_uppercase()).collect(),
+            is_only_snapshot: false,
+            is_only_realtime: false,
+            level: None,
+        }
+    }
+
+    /// Subscribe tickers of `codes`
+    pub fn ticker(codes: &[&str]) -> Self {
+        Self::new(SubscriptionType::Ticker, codes)
+    }
+
+    /// Subscribe trades of `codes`
+    pub fn trade(codes: &[&str]) -> Self {
+        Self::new(SubscriptionType::Trade, codes)
+    }
+
+    /// Subscribe orderbooks of `codes`
+    pub fn orderbook(codes: &[&str]) -> Self {
+        Self::new(SubscriptionType::Orderbook, codes)
+    }
+
+    /// Subscribe own orders and trades of `codes`, or of every market if empty
+    pub fn my_order(codes: &[&str]) -> Self {
+        Self::new(SubscriptionType::MyOrder, codes)
+    }
+
+    /// Subscribe own asset changes
+    pub fn my_asset() -> Self {
+        Self::new(SubscriptionType::MyAsset, &[])
+    }
+
+    pub fn with_only_snapshot(mut self, is_only_snapshot: bool) -> Self {
+        self.is_only_snapshot = is_only_snapshot;
+        self
+    }
+
+    pub fn with_only_realtime(mut self, is_only_realtime: bool) -> Self {
+        self.is_only_realtime = is_only_realtime;
+        self
+    }
+
+    /// Aggregate orderbook units by the price unit `level`. ex) 10000 groups KRW-BTC prices by 10,000 KRW
+    pub fn with_level(mut self, level: Number) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        let mut value = serde_json::json!({ "type": self.kind.to_string() });
+
+        if !self.codes.is_empty() {
+            value["codes"] = self.codes.clone().into();
+        }
+        if self.is_only_snapshot {
+            value["is_only_snapshot"] = true.into();
+        }
+        if self.is_only_realtime {
+            value["is_only_realtime"] = true.into();
+        }
+        if let Some(level) = self.level {
+            // a json number, as Decimal would serialize into a string
+            value["level"] =
+                serde_json::from_str(&crate::number::to_wire(level)).unwrap_or_default();
+        }
+
+        value
+    }
+}
+
+/// Build the request frame of `subscriptions`
+///
+/// ex) `[{"ticket":"..."},{"type":"ticker","codes":["KRW-BTC"]},{"format":"DEFAULT"}]`
+pub(crate) fn request_frame(ticket: &str, subscriptions: &[Subscription]) -> String {
+    let frame = std::iter::once(serde_json::json!({ "ticket": ticket }))
+        .chain(subscriptions.iter().map(Subscription::to_json))
+        .chain(std::iter::once(serde_json::json!({ "format": "DEFAULT" })))
+        .collect::<Vec<_>>();
+
+    serde_json::Value::Array(frame).to_string()
+}
+
+/// 실시간 시세를 구독한다. (Subscribe real-time quotations.)
+///
+/// # Example
+/// ```no_run
+/// use futures_util::StreamExt;
+/// use upbit::websocket::{self, Subscription, WebSocketMessage};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut socket = websocket::subscribe(&[Subscription::ticker(&["KRW-BTC"])]).await?;
+///
+/// while let Some(message) = socket.next().await {
+///     if let Ok(WebSocketMessage::Ticker { ticker, .. }) = message {
+///         println!("{} {}", ticker.market, ticker.trade_price);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+/// - parameters
+/// > `subscriptions` kinds and market codes to receive <br>
+/// # Request Frame
+/// ```json
+/// [
+///   { "ticket": "b8f5a3c2-0d1e-4f5a-9c6b-7e8d9f0a1b2c" },
+///   { "type": "ticker", "codes": ["KRW-BTC"] },
+///   { "format": "DEFAULT" }
+/// ]
+/// ```
+/// # Message
+/// | type      | message                                             |
+/// |:----------|:----------------------------------------------------|
+/// | ticker    | [`WebSocketMessage::Ticker`] in layout of [`crate::api_quotation::TickerSnapshot`] |
+/// | trade     | [`WebSocketMessage::Trade`] in layout of [`crate::api_quotation::TradeRecent`] |
+/// | orderbook | [`WebSocketMessage::Orderbook`] in layout of [`crate::api_quotation::OrderBookInfo`] |
+/// | myOrder   | [`WebSocketMessage::MyOrder`] convertible into [`crate::response::OrderInfo`] |
+/// | myAsset   | [`WebSocketMessage::MyAsset`] applicable to [`crate::response::AccountsInfo`] |
+///
+/// `myOrder` and `myAsset` are sent over the private endpoint (`{websocket_url}/private`),
+/// authenticated with the keys registered by [`crate::set_access_key`] and [`crate::set_secret_key`].
+/// Subscribe them on a connection separate from public data.
+pub async fn subscribe(subscriptions: &[Subscription]) -> Result<UpbitWebSocket, ResponseError> {
+    UpbitClient::default().subscribe(subscriptions).await
+}
+
+/// 재연결되는 실시간 구독을 연다. (Open a real-time subscription which reconnects by itself.)
+///
+/// # Example
+/// ```no_run
+/// use futures_util::StreamExt;
+/// use upbit::websocket::{self, SessionConfig, Subscription, SubscriptionType, WebSocketMessage};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut session = websocket::open_session(
+///     &[Subscription::ticker(&["KRW-BTC"])],
+///     SessionConfig::default(),
+/// )
+/// .await?;
+///
+/// session.subscribe(Subscription::orderbook(&["KRW-BTC"]));
+/// session.unsubscribe(SubscriptionType::Ticker, &["KRW-BTC"]);
+///
+/// while let Some(message) = session.next().await {
+///     match message {
+///         Ok(WebSocketMessage::Orderbook { orderbook, .. }) => println!("{}", orderbook.market),
+///         Ok(WebSocketMessage::Reconnected) => println!("messages may have been missed"),
+///         Err(e) => println!("{e:?}"),
+///         _ => {}
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+/// - parameters
+/// > `subscriptions` kinds and market codes to receive <br>
+/// > `config` ping interval, stale timeout and reconnection backoff <br>
+/// # Behavior
+/// | event                          | session                                                    |
+/// |:-------------------------------|:-----------------------------------------------------------|
+/// | every `ping_interval`          | sends a ping frame                                         |
+/// | no frame for `stale_timeout`   | drops the connection and reconnects                        |
+/// | connection closed or failed    | yields the error, reconnects with `reconnect_policy`       |
+/// | reconnected                    | resends the current subscriptions, yields [`WebSocketMessage::Reconnected`] |
+/// | `reconnect_policy` exhausted   | ends the stream                                            |
+///
+/// Subscriptions changed with [`WebSocketSession::subscribe`], [`WebSocketSession::unsubscribe`]
+/// and [`WebSocketSession::set_subscriptions`] are sent on the open connection.
+pub async fn open_session(
+    subscriptions: &[Subscription],
+    config: SessionConfig,
+) -> Result<WebSocketSession, ResponseError> {
+    UpbitClient::default()
+        .open_session(subscriptions, config)
+        .await
+}
+
+impl UpbitClient {
+    /// 실시간 시세를 구독한다. (Subscribe real-time quotations.)
+    ///
+    /// See [`subscribe`].
+    pub async fn subscribe(
+        &self,
+        subscriptions: &[Subscription],
+    ) -> Result<UpbitWebSocket, ResponseError> {
+        UpbitWebSocket::connect(self, subscriptions).await
+    }
+
+    /// 재연결되는 실시간 구독을 연다. (Open a real-time subscription which reconnects by itself.)
+    ///
+    /// See [`open_session`].
+    pub async fn open_session(
+        &self,
+        subscriptions: &[Subscription],
+        config: SessionConfig,
+    ) -> Result<WebSocketSession, ResponseError> {
+        WebSocketSession::connect(self, subscriptions, config).await
+    }
+}
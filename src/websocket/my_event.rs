@@ -0,0 +1,298 @@
+use serde::Deserialize;
+
+use crate::api_exchange::{OrderCondition, OrderSide, OrderState, OrderType};
+use crate::number::Number;
+use crate::response::{AccountsInfo, OrderInfo};
+
+/// Order or trade event of the account
+#[derive(Debug, Clone)]
+pub struct MyOrder {
+    pub market: String,
+    pub uuid: String,
+    pub side: OrderSide,
+    pub ord_type: OrderType,
+    /// [`OrderState::Trade`] when the event reports a fill
+    pub state: OrderState,
+    pub trade_uuid: Option<String>,
+    pub price: Option<Number>,
+    pub avg_price: Option<Number>,
+    pub volume: Option<Number>,
+    pub remaining_volume: Option<Number>,
+    pub executed_volume: Number,
+    pub trades_count: i64,
+    pub reserved_fee: Number,
+    pub remaining_fee: Number,
+    pub paid_fee: Number,
+    pub locked: Number,
+    pub executed_funds: Number,
+    pub time_in_force: Option<OrderCondition>,
+    pub trade_fee: Option<Number>,
+    pub is_maker: Option<bool>,
+    pub identifier: Option<String>,
+    pub trade_timestamp: Option<i64>,
+    pub order_timestamp: i64,
+    pub timestamp: i64,
+}
+
+impl MyOrder {
+    /// Get the order as returned by the order APIs
+    ///
+    /// A fill ([`OrderState::Trade`]) leaves the order [`OrderState::Wait`]ing; a separate
+    /// [`OrderState::Done`] event follows once nothing remains.
+    pub fn to_order_info(&self) -> OrderInfo {
+        let state = match self.state {
+            OrderState::Trade => OrderState::Wait,
+            ref state => state.clone(),
+        };
+
+        OrderInfo {
+            uuid: self.uuid.to_owned(),
+            side: self.side.clone(),
+            ord_type: self.ord_type.clone(),
+            price: self.price,
+            state,
+            market: self.market.to_owned(),
+            created_at: kst_from_timestamp_millis(self.order_timestamp),
+            volume: self.volume.unwrap_or(self.executed_volume),
+            remaining_volume: self.remaining_volume.unwrap_or_default(),
+            reserved_fee: self.reserved_fee,
+            remaining_fee: self.remaining_fee,
+            paid_fee: self.paid_fee,
+            locked: self.locked,
+            executed_volume: self.executed_volume,
+            executed_funds: Some(self.executed_funds),
+            trades_count: self.trades_count,
+            time_in_force: self.time_in_force.clone(),
+        }
+    }
+}
+
+impl From<&MyOrder> for OrderInfo {
+    fn from(my_order: &MyOrder) -> Self {
+        my_order.to_order_info()
+    }
+}
+
+/// Raw MyOrder event from serialized data
+#[derive(Deserialize)]
+pub struct MyOrderSource {
+    code: String,
+    uuid: String,
+    ask_bid: String,
+    order_type: String,
+    state: String,
+    trade_uuid: Option<String>,
+    price: Option<Number>,
+    avg_price: Option<Number>,
+    volume: Option<Number>,
+    remaining_volume: Option<Number>,
+    executed_volume: Number,
+    trades_count: i64,
+    reserved_fee: Number,
+    remaining_fee: Number,
+    paid_fee: Number,
+    locked: Number,
+    executed_funds: Number,
+    time_in_force: Option<String>,
+    trade_fee: Option<Number>,
+    is_maker: Option<bool>,
+    identifier: Option<String>,
+    trade_timestamp: Option<i64>,
+    order_timestamp: i64,
+    timestamp: i64,
+    pub(crate) stream_type: String,
+}
+
+impl From<MyOrderSource> for MyOrder {
+    fn from(x: MyOrderSource) -> Self {
+        Self {
+            market: x.code,
+            uuid: x.uuid,
+            side: OrderSide::parse_or_unknown(&x.ask_bid.to_lowercase()),
+            ord_type: OrderType::parse_or_unknown(&x.order_type),
+            state: OrderState::parse_or_unknown(&x.state),
+            trade_uuid: x.trade_uuid,
+            price: x.price,
+            avg_price: x.avg_price,
+            volume: x.volume,
+            remaining_volume: x.remaining_volume,
+            executed_volume: x.executed_volume,
+            trades_count: x.trades_count,
+            reserved_fee: x.reserved_fee,
+            remaining_fee: x.remaining_fee,
+            paid_fee: x.paid_fee,
+            locked: x.locked,
+            executed_funds: x.executed_funds,
+            time_in_force: x
+                .time_in_force
+                .as_deref()
+                .map(OrderCondition::parse_or_unknown),
+            trade_fee: x.trade_fee,
+            is_maker: x.is_maker,
+            identifier: x.identifier,
+            trade_timestamp: x.trade_timestamp,
+            order_timestamp: x.order_timestamp,
+            timestamp: x.timestamp,
+        }
+    }
+}
+
+/// Asset change event of the account
+#[derive(Deserialize, Debug, Clone)]
+pub struct MyAsset {
+    pub asset_uuid: String,
+    /// currencies whose balance changed, with their new balance
+    pub assets: Vec<MyAssetBalance>,
+    pub asset_timestamp: i64,
+    pub timestamp: i64,
+}
+
+/// New balance of a currency in [`MyAsset`]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct MyAssetBalance {
+    pub currency: String,
+    pub balance: Number,
+    pub locked: Number,
+}
+
+/// Change of a currency between [`AccountsInfo`] and a [`MyAsset`] event
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceDelta {
+    pub currency: String,
+    /// new balance minus previous balance
+    pub balance: Number,
+    /// new locked amount minus previous locked amount
+    pub locked: Number,
+}
+
+impl MyAsset {
+    /// Get changes against `accounts`. currencies missing from `accounts` count from zero
+    pub fn deltas(&self, accounts: &[AccountsInfo]) -> Vec<BalanceDelta> {
+        self.assets
+            .iter()
+            .map(|asset| {
+                let previous = accounts.iter().find(|x| x.currency == asset.currency);
+
+                BalanceDelta {
+                    currency: asset.currency.to_owned(),
+                    balance: asset.balance - previous.map(|x| x.balance).unwrap_or_default(),
+                    locked: asset.locked - previous.map(|x| x.locked).unwrap_or_default(),
+                }
+            })
+            .collect()
+    }
+
+    /// Update `accounts` to the balances of the event, adding currencies not held before
+    pub fn apply(&self, accounts: &mut Vec<AccountsInfo>) {
+        for asset in &self.assets {
+            match accounts.iter_mut().find(|x| x.currency == asset.currency) {
+                Some(account) => {
+                    account.balance = asset.balance;
+                    account.locked = asset.locked;
+                }
+                None => accounts.push(AccountsInfo {
+                    currency: asset.currency.to_owned(),
+                    balance: asset.balance,
+                    locked: asset.locked,
+                    avg_buy_price: Number::default(),
+                    avg_buy_price_modified: false,
+                    unit_currency: "KRW".to_owned(),
+                }),
+            }
+        }
+    }
+}
+
+/// Raw MyAsset event from serialized data
+#[derive(Deserialize)]
+pub struct MyAssetSource {
+    #[serde(flatten)]
+    pub(crate) asset: MyAsset,
+    pub(crate) stream_type: String,
+}
+
+fn kst_from_timestamp_millis(timestamp: i64) -> chrono::NaiveDateTime {
+    chrono::DateTime::from_timestamp_millis(timestamp)
+        .unwrap_or_default()
+        .with_timezone(&chrono::FixedOffset::east_opt(9 * 3600).unwrap())
+        .naive_local()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn n(value: &str) -> Number {
+        value.parse().unwrap()
+    }
+
+    fn account(currency: &str, balance: &str, locked: &str) -> AccountsInfo {
+        AccountsInfo {
+            currency: currency.to_owned(),
+            balance: n(balance),
+            locked: n(locked),
+            avg_buy_price: n("0"),
+            avg_buy_price_modified: false,
+            unit_currency: "KRW".to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_my_asset_deltas_and_apply() {
+        let asset = MyAsset {
+            asset_uuid: "e635f223-1609-4969-8fb6-4376937baad6".to_owned(),
+            assets: vec![
+                MyAssetBalance {
+                    currency: "KRW".to_owned(),
+                    balance: n("900"),
+                    locked: n("100"),
+                },
+                MyAssetBalance {
+                    currency: "BTC".to_owned(),
+                    balance: n("0.5"),
+                    locked: n("0"),
+                },
+            ],
+            asset_timestamp: 1710146517259,
+            timestamp: 1710146517267,
+        };
+        let mut accounts = vec![account("KRW", "1000", "0")];
+
+        assert_eq!(
+            asset.deltas(&accounts),
+            vec![
+                BalanceDelta {
+                    currency: "KRW".to_owned(),
+                    balance: n("-100"),
+                    locked: n("100"),
+                },
+                BalanceDelta {
+                    currency: "BTC".to_owned(),
+                    balance: n("0.5"),
+                    locked: n("0"),
+                },
+            ]
+        );
+
+        asset.apply(&mut accounts);
+
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts[0].balance, n("900"));
+        assert_eq!(accounts[1].currency, "BTC");
+    }
+
+    #[test]
+    fn test_my_order_to_order_info() {
+        let source: MyOrderSource = serde_json::from_str(
+            r#"{"type":"myOrder","code":"KRW-BTC","uuid":"ac2dc2a3-fce9-40a2-a4f6-5987c25c438f","ask_bid":"ASK","order_type":"market","state":"trade","trade_uuid":null,"price":null,"avg_price":null,"volume":null,"remaining_volume":null,"executed_volume":0.5,"trades_count":1,"reserved_fee":0,"remaining_fee":0,"paid_fee":10,"locked":0,"executed_funds":20000,"time_in_force":null,"trade_fee":10,"is_maker":false,"identifier":null,"trade_timestamp":1710751590421,"order_timestamp":1710751590000,"timestamp":1710751597500,"stream_type":"REALTIME"}"#,
+        )
+        .unwrap();
+        let order_info = MyOrder::from(source).to_order_info();
+
+        assert_eq!(order_info.state, OrderState::Wait);
+        assert_eq!(order_info.side, OrderSide::Ask);
+        assert_eq!(order_info.volume, n("0.5"));
+        assert_eq!(order_info.executed_funds, Some(n("20000")));
+        assert_eq!(order_info.created_at.to_string(), "2024-03-18 17:46:30");
+    }
+}
@@ -0,0 +1,510 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_util::{Stream, StreamExt};
+use tokio::sync::mpsc;
+use tokio::time::MissedTickBehavior;
+
+use crate::client::UpbitClient;
+use crate::response::{ResponseError, ResponseErrorState};
+use crate::retry::RetryPolicy;
+
+use super::{Subscription, SubscriptionType, UpbitWebSocket, WebSocketMessage};
+
+/// Number of messages buffered while the consumer is not polling the session
+const EVENT_BUFFER: usize = 1024;
+
+type Event = Result<WebSocketMessage, ResponseError>;
+
+/// Keep-alive and reconnection settings of a [`WebSocketSession`]
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+///
+/// use upbit::websocket::SessionConfig;
+/// use upbit::RetryPolicy;
+///
+/// let config = SessionConfig::default()
+///     .with_ping_interval(Duration::from_secs(10))
+///     .with_stale_timeout(Duration::from_secs(30))
+///     .with_reconnect_policy(RetryPolicy::default().with_max_attempts(10));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SessionConfig {
+    /// interval of ping frames, which is also how often staleness is checked
+    pub ping_interval: Duration,
+    /// reconnect when no frame, including pongs, arrived for this long
+    pub stale_timeout: Duration,
+    /// backoff between reconnection attempts. `max_attempts` counts consecutive failed
+    /// attempts before the session gives up and ends
+    pub reconnect_policy: RetryPolicy,
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            ping_interval: Duration::from_secs(30),
+            stale_timeout: Duration::from_secs(60),
+            reconnect_policy: RetryPolicy::default()
+                .with_max_attempts(u32::MAX)
+                .with_base_delay(Duration::from_millis(500))
+                .with_max_delay(Duration::from_secs(30)),
+        }
+    }
+}
+
+impl SessionConfig {
+    pub fn with_ping_interval(mut self, ping_interval: Duration) -> Self {
+        self.ping_interval = ping_interval;
+        self
+    }
+
+    pub fn with_stale_timeout(mut self, stale_timeout: Duration) -> Self {
+        self.stale_timeout = stale_timeout;
+        self
+    }
+
+    pub fn with_reconnect_policy(mut self, reconnect_policy: RetryPolicy) -> Self {
+        self.reconnect_policy = reconnect_policy;
+        self
+    }
+}
+
+enum Command {
+    Replace(Vec<Subscription>),
+    Close,
+}
+
+/// WebSocket subscription which survives dropped connections
+///
+/// A background task owns the connection. It pings the server, reconnects with backoff when
+/// the connection is closed, fails or goes stale, and sends the current subscriptions again
+/// on the new connection. [`WebSocketMessage::Reconnected`] is yielded after each reconnection,
+/// as messages sent while disconnected are missed. Failed attempts are yielded as errors.
+///
+/// The stream ends when the session is closed or gives up reconnecting.
+///
+/// # Example
+/// ```no_run
+/// use futures_util::StreamExt;
+/// use upbit::websocket::{SessionConfig, Subscription, WebSocketMessage};
+/// use upbit::UpbitClient;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = UpbitClient::new("access_key", "secret_key");
+/// let mut session = client
+///     .open_session(&[Subscription::ticker(&["KRW-BTC"])], SessionConfig::default())
+///     .await?;
+///
+/// session.subscribe(Subscription::ticker(&["KRW-ETH"]));
+///
+/// while let Some(message) = session.next().await {
+///     match message {
+///         Ok(WebSocketMessage::Ticker { ticker, .. }) => println!("{}", ticker.market),
+///         Ok(WebSocketMessage::Reconnected) => println!("may have missed messages"),
+///         _ => {}
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct WebSocketSession {
+    subscriptions: Vec<Subscription>,
+    commands: mpsc::UnboundedSender<Command>,
+    events: mpsc::Receiver<Event>,
+}
+
+impl WebSocketSession {
+    /// Connect with `subscriptions` and keep the connection alive in the background
+    ///
+    /// Fails if the first connection can't be established.
+    /// Whether the session is private is decided by `subscriptions`, as in [`UpbitWebSocket::connect`].
+    pub async fn connect(
+        client: &UpbitClient,
+        subscriptions: &[Subscription],
+        config: SessionConfig,
+    ) -> Result<Self, ResponseError> {
+        let socket = UpbitWebSocket::connect(client, subscriptions).await?;
+        let (commands, command_receiver) = mpsc::unbounded_channel();
+        let (event_sender, events) = mpsc::channel(EVENT_BUFFER);
+        let worker = Worker {
+            client: client.clone(),
+            subscriptions: subscriptions.to_vec(),
+            config,
+            commands: command_receiver,
+            events: event_sender,
+        };
+
+        tokio::spawn(worker.run(socket));
+
+        Ok(Self {
+            subscriptions: subscriptions.to_vec(),
+            commands,
+            events,
+        })
+    }
+
+    /// Get the current subscriptions
+    pub fn subscriptions(&self) -> &[Subscription] {
+        &self.subscriptions
+    }
+
+    /// Add `subscription` without reconnecting
+    ///
+    /// Codes are merged into the subscription of the same kind and options, if any.
+    pub fn subscribe(&mut self, subscription: Subscription) {
+        let existing = self.subscriptions.iter_mut().find(|x| {
+            x.kind == subscription.kind
+                && x.is_only_snapshot == subscription.is_only_snapshot
+                && x.is_only_realtime == subscription.is_only_realtime
+                && x.level == subscription.level
+        });
+
+        match existing {
+            // empty codes of myOrder mean every market, which already covers any code
+            Some(existing) if existing.codes.is_empty() => {}
+            Some(existing) if subscription.codes.is_empty() => existing.codes.clear(),
+            Some(existing) => {
+                for code in subscription.codes {
+                    if !existing.codes.contains(&code) {
+                        existing.codes.push(code);
+                    }
+                }
+            }
+            None => self.subscriptions.push(subscription),
+        }
+
+        self.send_subscriptions();
+    }
+
+    /// Remove `codes` from subscriptions of `kind` without reconnecting. empty `codes` removes `kind` entirely
+    pub fn unsubscribe(&mut self, kind: SubscriptionType, codes: &[&str]) {
+        let codes = codes
+            .iter()
+            .map(|code| code.to_uppercase())
+            .collect::<Vec<_>>();
+
+        self.subscriptions.retain_mut(|x| {
+            if x.kind != kind {
+                return true;
+            }
+            if codes.is_empty() || x.codes.is_empty() {
+                return false;
+            }
+
+            x.codes.retain(|code| !codes.contains(code));
+            !x.codes.is_empty()
+        });
+
+        self.send_subscriptions();
+    }
+
+    /// Replace every subscription without reconnecting
+    pub fn set_subscriptions(&mut self, subscriptions: &[Subscription]) {
+        self.subscriptions = subscriptions.to_vec();
+        self.send_subscriptions();
+    }
+
+    /// Close the connection. the stream ends after the messages already received
+    pub fn close(&self) {
+        let _ = self.commands.send(Command::Close);
+    }
+
+    fn send_subscriptions(&self) {
+        // the worker is gone once the stream ended, leaving nothing to update
+        let _ = self
+            .commands
+            .send(Command::Replace(self.subscriptions.clone()));
+    }
+}
+
+impl Stream for WebSocketSession {
+    type Item = Event;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.events.poll_recv(cx)
+    }
+}
+
+/// How a connection ended
+enum Disconnect {
+    /// closed by the session or the consumer is gone
+    Closed,
+    /// lost, and worth reconnecting
+    Lost,
+}
+
+struct Worker {
+    client: UpbitClient,
+    subscriptions: Vec<Subscription>,
+    config: SessionConfig,
+    commands: mpsc::UnboundedReceiver<Command>,
+    events: mpsc::Sender<Event>,
+}
+
+impl Worker {
+    async fn run(mut self, mut socket: UpbitWebSocket) {
+        loop {
+            if let Disconnect::Closed = self.serve(&mut socket).await {
+                let _ = socket.close().await;
+                return;
+            }
+
+            socket = match self.reconnect().await {
+                Some(socket) => socket,
+                None => return,
+            };
+
+            if self
+                .events
+                .send(Ok(WebSocketMessage::Reconnected))
+                .await
+                .is_err()
+            {
+                return;
+            }
+        }
+    }
+
+    /// Forward messages of `socket` until it is closed or lost
+    async fn serve(&mut self, socket: &mut UpbitWebSocket) -> Disconnect {
+        let mut ping = tokio::time::interval(self.config.ping_interval);
+        ping.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        // the first tick completes immediately
+        ping.tick().await;
+
+        loop {
+            tokio::select! {
+                command = self.commands.recv() => match command {
+                    Some(Command::Replace(subscriptions)) => {
+                        self.subscriptions = subscriptions;
+
+                        if let Err(e) = socket.send_subscriptions(&self.subscriptions).await {
+                            return self.lost(e).await;
+                        }
+                    }
+                    Some(Command::Close) | None => return Disconnect::Closed,
+                },
+                message = socket.next() => match message {
+                    Some(Err(e)) if e.state == ResponseErrorState::InternalWebSocketError => {
+                        return self.lost(e).await;
+                    }
+                    Some(message) => {
+                        if self.events.send(message).await.is_err() {
+                            return Disconnect::Closed;
+                        }
+                    }
+                    None => return Disconnect::Lost,
+                },
+                _ = ping.tick() => {
+                    if socket.last_seen().elapsed() >= self.config.stale_timeout {
+                        let e = crate::response::response_error_internal_websocket_error(format!(
+                            "no frame received for {:?}",
+                            self.config.stale_timeout
+                        ));
+
+                        return self.lost(e).await;
+                    }
+                    if let Err(e) = socket.ping().await {
+                        return self.lost(e).await;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Connect again with backoff, applying subscription changes made in the meantime
+    async fn reconnect(&mut self) -> Option<UpbitWebSocket> {
+        let policy = self.config.reconnect_policy.clone();
+
+        for attempt in 1..=policy.max_attempts {
+            let delay = tokio::time::sleep(policy.delay(attempt));
+            tokio::pin!(delay);
+
+            loop {
+                tokio::select! {
+                    _ = &mut delay => break,
+                    command = self.commands.recv() => match command {
+                        Some(Command::Replace(subscriptions)) => self.subscriptions = subscriptions,
+                        Some(Command::Close) | None => return None,
+                    },
+                }
+            }
+
+            // a handshake hanging as long as the stale timeout counts as a failed attempt
+            let connect = UpbitWebSocket::connect(&self.client, &self.subscriptions);
+            let e = match tokio::time::timeout(self.config.stale_timeout, connect).await {
+                Ok(Ok(socket)) => return Some(socket),
+                Ok(Err(e)) => e,
+                Err(e) => crate::response::response_error_internal_websocket_error(e),
+            };
+
+            if self.events.send(Err(e)).await.is_err() {
+                return None;
+            }
+        }
+
+        None
+    }
+
+    async fn lost(&self, error: ResponseError) -> Disconnect {
+        match self.events.send(Err(error)).await {
+            Ok(_) => Disconnect::Lost,
+            Err(_) => Disconnect::Closed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::SinkExt;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::Message;
+
+    use super::*;
+
+    const TICKER: &str = r#"{"type":"ticker","code":"KRW-BTC","opening_price":31883000,"high_price":32310000,"low_price":31855000,"trade_price":32287000,"prev_closing_price":31883000.00000000,"acc_trade_price":78039261076.51241000,"change":"RISE","change_price":404000.00000000,"signed_change_price":404000.00000000,"change_rate":0.0126713295,"signed_change_rate":0.0126713295,"ask_bid":"ASK","trade_volume":0.03103806,"acc_trade_volume":2429.58834336,"trade_date":"20230221","trade_time":"074102","trade_timestamp":1676965262139,"acc_ask_volume":1146.25573608,"acc_bid_volume":1283.33260728,"highest_52_week_price":57678000.00000000,"highest_52_week_date":"2022-03-28","lowest_52_week_price":20700000.00000000,"lowest_52_week_date":"2022-12-30","market_state":"ACTIVE","is_trading_suspended":false,"delisting_date":null,"market_warning":"NONE","timestamp":1676965262177,"acc_trade_price_24h":228827082483.70729000,"acc_trade_volume_24h":7158.80283560,"stream_type":"REALTIME"}"#;
+
+    fn config() -> SessionConfig {
+        SessionConfig::default()
+            .with_ping_interval(Duration::from_millis(50))
+            .with_stale_timeout(Duration::from_millis(200))
+            .with_reconnect_policy(
+                RetryPolicy::default()
+                    .with_max_attempts(3)
+                    .with_base_delay(Duration::from_millis(10))
+                    .with_jitter(false),
+            )
+    }
+
+    async fn next_request(
+        socket: &mut tokio_tungstenite::WebSocketStream<tokio::net::TcpStream>,
+    ) -> serde_json::Value {
+        loop {
+            match socket.next().await.unwrap().unwrap() {
+                Message::Text(text) => return serde_json::from_str(&text).unwrap(),
+                _ => continue,
+            }
+        }
+    }
+
+    async fn next_message(session: &mut WebSocketSession) -> WebSocketMessage {
+        loop {
+            match session.next().await.unwrap() {
+                Ok(message) => return message,
+                Err(e) => assert_eq!(e.state, ResponseErrorState::InternalWebSocketError),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_session_replays_subscriptions_after_drop() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let first = next_request(&mut socket).await;
+
+            socket.send(Message::binary(TICKER)).await.unwrap();
+            let updated = next_request(&mut socket).await;
+            // drop without a close frame, as a reset connection would
+            drop(socket);
+
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let replayed = next_request(&mut socket).await;
+
+            socket.send(Message::binary(TICKER)).await.unwrap();
+            socket.close(None).await.unwrap();
+
+            (first, updated, replayed)
+        });
+
+        let client = UpbitClient::new("access_key", "secret_key")
+            .with_websocket_url(&format!("ws://{address}"));
+        let mut session = client
+            .open_session(&[Subscription::ticker(&["KRW-BTC"])], config())
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            next_message(&mut session).await,
+            WebSocketMessage::Ticker { .. }
+        ));
+
+        session.subscribe(Subscription::ticker(&["krw-eth"]));
+        session.subscribe(Subscription::trade(&["KRW-BTC"]));
+        session.unsubscribe(SubscriptionType::Trade, &["KRW-BTC"]);
+        assert_eq!(session.subscriptions()[0].codes, ["KRW-BTC", "KRW-ETH"]);
+        assert_eq!(session.subscriptions().len(), 1);
+
+        assert!(matches!(
+            next_message(&mut session).await,
+            WebSocketMessage::Reconnected
+        ));
+        assert!(matches!(
+            next_message(&mut session).await,
+            WebSocketMessage::Ticker { .. }
+        ));
+        session.close();
+
+        let (first, updated, replayed) = server.await.unwrap();
+        assert_eq!(first[1]["codes"], serde_json::json!(["KRW-BTC"]));
+        assert_eq!(
+            updated[1]["codes"],
+            serde_json::json!(["KRW-BTC", "KRW-ETH"])
+        );
+        assert_eq!(replayed[1], updated[1]);
+        assert_eq!(replayed.as_array().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_session_reconnects_when_stale() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            next_request(&mut socket).await;
+
+            // stop reading, so pings are never answered
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut fresh = tokio_tungstenite::accept_async(stream).await.unwrap();
+            next_request(&mut fresh).await;
+            drop(socket);
+
+            // answer pings until the session closes the connection
+            while let Some(Ok(message)) = fresh.next().await {
+                if message.is_close() {
+                    break;
+                }
+            }
+        });
+
+        let client = UpbitClient::new("access_key", "secret_key")
+            .with_websocket_url(&format!("ws://{address}"));
+        let mut session = client
+            .open_session(&[Subscription::ticker(&["KRW-BTC"])], config())
+            .await
+            .unwrap();
+
+        let e = session.next().await.unwrap().unwrap_err();
+        assert_eq!(e.state, ResponseErrorState::InternalWebSocketError);
+        assert!(matches!(
+            session.next().await.unwrap(),
+            Ok(WebSocketMessage::Reconnected)
+        ));
+
+        // pongs of the fresh connection keep it from going stale
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        session.close();
+        assert!(session.next().await.is_none());
+
+        server.await.unwrap();
+    }
+}
@@ -0,0 +1,295 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::{SinkExt, Stream, StreamExt};
+use tokio::net::TcpStream;
+use tokio::time::Instant;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::header::AUTHORIZATION;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use crate::client::UpbitClient;
+use crate::response::ResponseError;
+
+use super::{request_frame, Subscription, WebSocketMessage};
+
+/// Stream of messages of a WebSocket subscription
+///
+/// Yields [`WebSocketMessage`]s until the server closes the connection.
+pub struct UpbitWebSocket {
+    socket: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    last_seen: Instant,
+}
+
+impl UpbitWebSocket {
+    /// Connect to the WebSocket server of `client` and send the request frame of `subscriptions`
+    ///
+    /// Private subscriptions connect to the `/private` endpoint, signed with the keys of `client`.
+    pub async fn connect(
+        client: &UpbitClient,
+        subscriptions: &[Subscription],
+    ) -> Result<Self, ResponseError> {
+        let is_private = subscriptions.iter().any(|x| x.kind.is_private());
+        let url = if is_private {
+            format!("{}/private", client.websocket_url.trim_end_matches('/'))
+        } else {
+            client.websocket_url.to_owned()
+        };
+        let mut request = url
+            .into_client_request()
+            .map_err(crate::response::response_error_internal_websocket_error)?;
+
+        if is_private {
+            let token_string = client.set_token()?;
+            let token_header = HeaderValue::from_str(&token_string)
+                .map_err(crate::response::response_error_internal_token_encode_error)?;
+
+            request.headers_mut().insert(AUTHORIZATION, token_header);
+        }
+
+        let (socket, _) = tokio_tungstenite::connect_async(request)
+            .await
+            .map_err(crate::response::response_error_internal_websocket_error)?;
+        let mut socket = Self {
+            socket,
+            last_seen: Instant::now(),
+        };
+
+        socket.send_subscriptions(subscriptions).await?;
+        Ok(socket)
+    }
+
+    /// Replace the subscriptions of the connection with `subscriptions`
+    pub async fn send_subscriptions(
+        &mut self,
+        subscriptions: &[Subscription],
+    ) -> Result<(), ResponseError> {
+        let ticket = uuid::Uuid::new_v4().to_string();
+
+        self.socket
+            .send(Message::text(request_frame(&ticket, subscriptions)))
+            .await
+            .map_err(crate::response::response_error_internal_websocket_error)
+    }
+
+    /// Send a ping frame. the server answers with a pong, which updates [`Self::last_seen`]
+    pub async fn ping(&mut self) -> Result<(), ResponseError> {
+        self.socket
+            .send(Message::Ping(Default::default()))
+            .await
+            .map_err(crate::response::response_error_internal_websocket_error)
+    }
+
+    /// Get when the last frame of any kind was received
+    pub fn last_seen(&self) -> Instant {
+        self.last_seen
+    }
+
+    /// Close the connection
+    pub async fn close(mut self) -> Result<(), ResponseError> {
+        self.socket
+            .close(None)
+            .await
+            .map_err(crate::response::response_error_internal_websocket_error)
+    }
+}
+
+impl Stream for UpbitWebSocket {
+    type Item = Result<WebSocketMessage, ResponseError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let message = match self.socket.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(message))) => {
+                    self.last_seen = Instant::now();
+                    message
+                }
+                Poll::Ready(Some(Err(e))) => {
+                    return Poll::Ready(Some(Err(
+                        crate::response::response_error_internal_websocket_error(e),
+                    )))
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            // the server sends json in binary frames. control frames are answered by tungstenite
+            let text = match &message {
+                Message::Text(text) => text.as_str(),
+                Message::Binary(bytes) => match std::str::from_utf8(bytes) {
+                    Ok(text) => text,
+                    Err(e) => {
+                        return Poll::Ready(Some(Err(
+                            crate::response::response_error_internal_websocket_error(e),
+                        )))
+                    }
+                },
+                _ => continue,
+            };
+
+            return Poll::Ready(Some(WebSocketMessage::parse(text)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::number::Number;
+    use crate::websocket::StreamType;
+
+    const TICKER: &str = r#"{"type":"ticker","code":"KRW-BTC","opening_price":31883000,"high_price":32310000,"low_price":31855000,"trade_price":32287000,"prev_closing_price":31883000.00000000,"acc_trade_price":78039261076.51241000,"change":"RISE","change_price":404000.00000000,"signed_change_price":404000.00000000,"change_rate":0.0126713295,"signed_change_rate":0.0126713295,"ask_bid":"ASK","trade_volume":0.03103806,"acc_trade_volume":2429.58834336,"trade_date":"20230221","trade_time":"074102","trade_timestamp":1676965262139,"acc_ask_volume":1146.25573608,"acc_bid_volume":1283.33260728,"highest_52_week_price":57678000.00000000,"highest_52_week_date":"2022-03-28","lowest_52_week_price":20700000.00000000,"lowest_52_week_date":"2022-12-30","market_state":"ACTIVE","is_trading_suspended":false,"delisting_date":null,"market_warning":"NONE","timestamp":1676965262177,"acc_trade_price_24h":228827082483.70729000,"acc_trade_volume_24h":7158.80283560,"stream_type":"REALTIME"}"#;
+    const TRADE: &str = r#"{"type":"trade","code":"KRW-BTC","timestamp":1676965262139,"trade_date":"2023-02-21","trade_time":"07:41:02","trade_timestamp":1676965262139,"trade_price":32287000.00000000,"trade_volume":0.03103806,"ask_bid":"ASK","prev_closing_price":31883000.00000000,"change":"RISE","change_price":404000.00000000,"sequential_id":1676965262139000,"stream_type":"SNAPSHOT"}"#;
+    const ORDERBOOK: &str = r#"{"type":"orderbook","code":"KRW-BTC","timestamp":1676965262177,"total_ask_size":4.79158413,"total_bid_size":2.65609625,"orderbook_units":[{"ask_price":32290000.0,"bid_price":32287000.0,"ask_size":0.60526139,"bid_size":0.12052762}],"stream_type":"REALTIME","level":0}"#;
+
+    #[tokio::test]
+    async fn test_subscribe_with_local_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+            let request = socket.next().await.unwrap().unwrap().into_text().unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+
+            for message in [TICKER, TRADE, ORDERBOOK] {
+                socket.send(Message::binary(message)).await.unwrap();
+            }
+            socket
+                .send(Message::text(
+                    r#"{"error":{"name":"WRONG_FORMAT","message":"잘못된 요청입니다."}}"#,
+                ))
+                .await
+                .unwrap();
+            socket.close(None).await.unwrap();
+
+            request
+        });
+
+        let client = UpbitClient::new("access_key", "secret_key")
+            .with_websocket_url(&format!("ws://{address}"));
+        let mut socket = client
+            .subscribe(&[
+                Subscription::ticker(&["krw-btc"]),
+                Subscription::trade(&["KRW-BTC"]).with_only_snapshot(true),
+                Subscription::orderbook(&["KRW-BTC"]).with_level(Number::from(10000)),
+            ])
+            .await
+            .unwrap();
+
+        match socket.next().await.unwrap().unwrap() {
+            WebSocketMessage::Ticker {
+                ticker,
+                stream_type,
+            } => {
+                assert_eq!(ticker.market, "KRW-BTC");
+                assert_eq!(ticker.trade_time_kst, "164102");
+                assert_eq!(stream_type, StreamType::Realtime);
+            }
+            message => panic!("unexpected message: {message:?}"),
+        }
+        match socket.next().await.unwrap().unwrap() {
+            WebSocketMessage::Trade { trade, stream_type } => {
+                assert_eq!(trade.sequential_id, 1676965262139000);
+                assert_eq!(stream_type, StreamType::Snapshot);
+            }
+            message => panic!("unexpected message: {message:?}"),
+        }
+        match socket.next().await.unwrap().unwrap() {
+            WebSocketMessage::Orderbook { orderbook, .. } => {
+                assert_eq!(orderbook.orderbook_units.len(), 1);
+            }
+            message => panic!("unexpected message: {message:?}"),
+        }
+        assert_eq!(
+            socket.next().await.unwrap().unwrap_err().error.name,
+            "WRONG_FORMAT"
+        );
+        assert!(socket.next().await.is_none());
+
+        let request = server.await.unwrap();
+        assert_eq!(request[1]["type"], "ticker");
+        assert_eq!(request[1]["codes"][0], "KRW-BTC");
+        assert_eq!(request[2]["is_only_snapshot"], true);
+        assert_eq!(request[3]["level"], 10000);
+        assert_eq!(request[4]["format"], "DEFAULT");
+    }
+
+    #[tokio::test]
+    #[allow(clippy::result_large_err)]
+    async fn test_subscribe_private_with_local_server() {
+        use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+
+        const MY_ORDER: &str = r#"{"type":"myOrder","code":"KRW-BTC","uuid":"ac2dc2a3-fce9-40a2-a4f6-5987c25c438f","ask_bid":"BID","order_type":"limit","state":"trade","trade_uuid":"68315169-fba4-4175-ade3-aff14a616657","price":0.001453,"avg_price":0.00145372,"volume":30925891.29839369,"remaining_volume":29968038.09235948,"executed_volume":30925891.29839369,"trades_count":1,"reserved_fee":44.23943970238218,"remaining_fee":21.77177967409916,"paid_fee":22.467660028283017,"locked":43565.49112892,"executed_funds":44935.32005656603,"time_in_force":null,"trade_fee":22.467660028283017,"is_maker":true,"identifier":"test-1","smp_type":null,"prevented_volume":0,"prevented_locked":0,"trade_timestamp":1710751590421,"order_timestamp":1710751590000,"timestamp":1710751597500,"stream_type":"REALTIME"}"#;
+        const MY_ASSET: &str = r#"{"type":"myAsset","asset_uuid":"e635f223-1609-4969-8fb6-4376937baad6","assets":[{"currency":"KRW","balance":1386929.37231066,"locked":10329.670127489}],"asset_timestamp":1710146517259,"timestamp":1710146517267,"stream_type":"REALTIME"}"#;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut handshake = None;
+            let mut socket = tokio_tungstenite::accept_hdr_async(
+                stream,
+                |request: &Request, response: Response| {
+                    handshake = Some((
+                        request.uri().path().to_owned(),
+                        request.headers().get(AUTHORIZATION).cloned(),
+                    ));
+                    Ok(response)
+                },
+            )
+            .await
+            .unwrap();
+
+            let request = socket.next().await.unwrap().unwrap().into_text().unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+
+            for message in [MY_ORDER, MY_ASSET] {
+                socket.send(Message::binary(message)).await.unwrap();
+            }
+            socket.close(None).await.unwrap();
+
+            (handshake.unwrap(), request)
+        });
+
+        let client = UpbitClient::new("access_key", "secret_key")
+            .with_websocket_url(&format!("ws://{address}"));
+        let mut socket = client
+            .subscribe(&[Subscription::my_order(&[]), Subscription::my_asset()])
+            .await
+            .unwrap();
+
+        match socket.next().await.unwrap().unwrap() {
+            WebSocketMessage::MyOrder { order, .. } => {
+                assert_eq!(order.state, crate::api_exchange::OrderState::Trade);
+                assert_eq!(order.side, crate::api_exchange::OrderSide::Bid);
+                assert_eq!(order.identifier.as_deref(), Some("test-1"));
+            }
+            message => panic!("unexpected message: {message:?}"),
+        }
+        match socket.next().await.unwrap().unwrap() {
+            WebSocketMessage::MyAsset { asset, .. } => {
+                assert_eq!(asset.assets[0].currency, "KRW");
+            }
+            message => panic!("unexpected message: {message:?}"),
+        }
+        assert!(socket.next().await.is_none());
+
+        let ((path, authorization), request) = server.await.unwrap();
+        assert_eq!(path, "/private");
+        assert!(authorization
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .starts_with("Bearer "));
+        assert_eq!(request[1], serde_json::json!({ "type": "myOrder" }));
+        assert_eq!(request[2], serde_json::json!({ "type": "myAsset" }));
+    }
+}
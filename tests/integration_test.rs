@@ -1,4 +1,3 @@
-use tokio;
 use upbit::{
     self,
     api_deposit::DepositState,
@@ -6,6 +5,7 @@ use upbit::{
     api_quotation::CandleMinute,
     api_withdraw::WithdrawState,
     constant::{OrderBy, TransactionType},
+    Number,
 };
 
 #[tokio::test]
@@ -36,8 +36,8 @@ async fn test_order_bid_by_price() {
     let order_bid = upbit::api_exchange::order_by_price(
         "KRW-ETH",
         OrderSide::Bid,
-        5000.0,
-        1_435_085.0,
+        Number::from(5000),
+        Number::from(1_435_085),
         OrderType::Limit,
         None,
     )
@@ -54,8 +54,8 @@ async fn test_order_ask_by_price() {
     let order_ask = upbit::api_exchange::order_by_price(
         "KRW-ETH",
         OrderSide::Ask,
-        5000.0,
-        10_435_085.0,
+        Number::from(5000),
+        Number::from(10_435_085),
         OrderType::Limit,
         None,
     )
@@ -221,7 +221,7 @@ async fn test_get_ticker_snapshot() {
 
     let list_bunch = upbit::api_quotation::get_ticker_snapshot(&["KRW-BTC", "KRW-ETH"]).await;
 
-    assert!(list_bunch.is_ok());
+    assert_eq!(list_bunch.map(|x| x.len()).ok(), Some(2));
 }
 
 #[tokio::test]
@@ -273,7 +273,7 @@ async fn test_withdraw_coin() {
     let info = upbit::api_withdraw::withdraw_coin(
         "ETH",
         "ETH",
-        0.02,
+        "0.02".parse().unwrap(),
         "0x40268F1e99F76b658c6D52d89166EE289EfC225d",
         None,
         TransactionType::Default,